@@ -0,0 +1,106 @@
+//!
+//! The contract ABI utilities.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// Computes the 4-byte method selectors of the functions declared in the ABI.
+///
+/// Returns the map of canonical function signatures to their hexadecimal selectors, which has
+/// the same layout as the `solc` method identifiers output.
+///
+pub fn selectors(abi: &serde_json::Value) -> BTreeMap<String, String> {
+    let mut selectors = BTreeMap::new();
+
+    let entries = match abi.as_array() {
+        Some(entries) => entries,
+        None => return selectors,
+    };
+
+    for entry in entries.iter() {
+        if entry.get("type").and_then(serde_json::Value::as_str) != Some("function") {
+            continue;
+        }
+        let name = match entry.get("name").and_then(serde_json::Value::as_str) {
+            Some(name) => name,
+            None => continue,
+        };
+
+        let signature = format!("{}({})", name, canonical_parameters(entry.get("inputs")));
+        let hash = compiler_llvm_context::keccak256(signature.as_bytes());
+        selectors.insert(signature, hash[..compiler_common::SIZE_X32 * 2].to_owned());
+    }
+
+    selectors
+}
+
+///
+/// Returns the comma-separated canonical types of the ABI parameter list.
+///
+fn canonical_parameters(parameters: Option<&serde_json::Value>) -> String {
+    parameters
+        .and_then(serde_json::Value::as_array)
+        .map(|parameters| {
+            parameters
+                .iter()
+                .map(canonical_type)
+                .collect::<Vec<String>>()
+                .join(",")
+        })
+        .unwrap_or_default()
+}
+
+///
+/// Returns the canonical type of the ABI parameter, expanding the tuples into their components.
+///
+fn canonical_type(parameter: &serde_json::Value) -> String {
+    let r#type = parameter
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or_default();
+
+    match r#type.strip_prefix("tuple") {
+        Some(array_suffix) => format!(
+            "({}){}",
+            canonical_parameters(parameter.get("components")),
+            array_suffix
+        ),
+        None => r#type.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn selectors_transfer() {
+        let abi = serde_json::json!([
+            {
+                "type": "function",
+                "name": "transfer",
+                "inputs": [
+                    { "name": "to", "type": "address" },
+                    { "name": "amount", "type": "uint256" }
+                ],
+                "outputs": [
+                    { "name": "", "type": "bool" }
+                ],
+                "stateMutability": "nonpayable"
+            },
+            {
+                "type": "event",
+                "name": "Transfer",
+                "inputs": []
+            }
+        ]);
+
+        let selectors = super::selectors(&abi);
+        assert_eq!(selectors.len(), 1);
+        assert_eq!(
+            selectors
+                .get("transfer(address,uint256)")
+                .map(String::as_str),
+            Some("a9059cbb")
+        );
+    }
+}
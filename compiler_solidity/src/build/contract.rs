@@ -2,11 +2,20 @@
 //! The Solidity contract build.
 //!
 
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::Write;
+use std::path::Component;
 use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
 
+use crate::build::coverage::Coverage;
+use crate::build::ir_stats::IRStats;
 use crate::solc::combined_json::contract::Contract as CombinedJsonContract;
+use crate::solc::source_map::kind::Kind as SourceMapKind;
+use crate::solc::source_map::SourceMap;
+use crate::solc::standard_json::output::contract::evm::bytecode::Bytecode as StandardJsonOutputContractEVMBytecode;
 use crate::solc::standard_json::output::contract::evm::EVM as StandardJsonOutputContractEVM;
 use crate::solc::standard_json::output::contract::Contract as StandardJsonOutputContract;
 
@@ -23,6 +32,18 @@ pub struct Contract {
     pub build: compiler_llvm_context::Build,
     /// The ABI specification JSON.
     pub abi: Option<serde_json::Value>,
+    /// The compilation pass timings.
+    pub pass_timings: Vec<(String, Duration)>,
+    /// The deploy and runtime code source maps, if requested.
+    pub source_maps: Option<(SourceMap, SourceMap)>,
+    /// The LLVM bitcode of the module before optimization, if requested.
+    pub llvm_bitcode: Option<Vec<u8>>,
+    /// The Yul built-in functions and EVM legacy assembly instructions lowered, if requested.
+    pub coverage: Option<Coverage>,
+    /// The LLVM IR statistics of the module before optimization, if requested.
+    pub ir_stats: Option<IRStats>,
+    /// The byte offsets of the unlinked library placeholder addresses, keyed by the library path.
+    pub link_references: BTreeMap<String, Vec<usize>>,
 }
 
 impl Contract {
@@ -40,12 +61,84 @@ impl Contract {
             identifier,
             build,
             abi,
+            pass_timings: Vec::new(),
+            source_maps: None,
+            llvm_bitcode: None,
+            coverage: None,
+            ir_stats: None,
+            link_references: BTreeMap::new(),
         }
     }
 
+    ///
+    /// Returns the contract bytecode.
+    ///
+    pub fn bytecode(&self) -> &[u8] {
+        self.build.bytecode.as_slice()
+    }
+
+    ///
+    /// Returns the contract bytecode hash, which is used by `create2` and in the factory
+    /// dependencies.
+    ///
+    /// The zkEVM deploy and runtime code are compiled into a single bytecode, so there is no
+    /// separate deploy code hash, and this hash changes if either of the codes does.
+    ///
+    pub fn bytecode_hash(&self) -> &str {
+        self.build.hash.as_str()
+    }
+
+    ///
+    /// Returns the contract ABI specification, if it is available.
+    ///
+    pub fn abi(&self) -> Option<&serde_json::Value> {
+        self.abi.as_ref()
+    }
+
+    ///
+    /// Returns the contract method selectors computed from the ABI, if it is available.
+    ///
+    pub fn hashes(&self) -> Option<BTreeMap<String, String>> {
+        self.abi.as_ref().map(crate::abi::selectors)
+    }
+
+    ///
+    /// Returns the bytecode lines printed by the `--bin`, `--bin-deploy` and `--bin-runtime`
+    /// options, one per enabled flag.
+    ///
+    /// The zkEVM deploy and runtime code are compiled into a single bytecode, where the
+    /// constructor is selected by the call flags, so the deploy and runtime lines contain the
+    /// same bytecode and only differ in their labels.
+    ///
+    pub fn binary_output(
+        &self,
+        output_binary: bool,
+        output_deploy_binary: bool,
+        output_runtime_binary: bool,
+    ) -> String {
+        let bytecode = hex::encode(self.build.bytecode.as_slice());
+
+        let mut output = String::new();
+        for (is_enabled, label) in [
+            (output_binary, "bytecode"),
+            (output_deploy_binary, "deploy bytecode"),
+            (output_runtime_binary, "runtime bytecode"),
+        ] {
+            if is_enabled {
+                output.push_str(
+                    format!("Contract `{}` {}: 0x{}\n", self.path, label, bytecode).as_str(),
+                );
+            }
+        }
+        output
+    }
+
     ///
     /// Writes the contract text assembly and bytecode to files.
     ///
+    /// If `mirror_source_paths` is set, the files are written to a subdirectory mirroring the
+    /// contract source path, e.g. `src/Token.sol:Token` is written to `src/Token.sol/Token.*`.
+    ///
     pub fn write_to_directory(
         self,
         path: &Path,
@@ -53,8 +146,20 @@ impl Contract {
         output_binary: bool,
         output_abi: bool,
         overwrite: bool,
+        mirror_source_paths: bool,
     ) -> anyhow::Result<()> {
-        let file_name = Self::short_path(self.path.as_str());
+        let (path, file_name) = if mirror_source_paths {
+            let (directory, file_name) = Self::mirrored_path(path, self.path.as_str());
+            std::fs::create_dir_all(&directory).map_err(|error| {
+                anyhow::anyhow!("Directory {:?} creating error: {}", directory, error)
+            })?;
+            (directory, file_name)
+        } else {
+            (
+                path.to_owned(),
+                Self::short_path(self.path.as_str()).to_owned(),
+            )
+        };
 
         if output_assembly {
             let file_name = format!(
@@ -104,6 +209,28 @@ impl Contract {
             }
         }
 
+        if let Some(llvm_bitcode) = self.llvm_bitcode {
+            let file_name = format!("{}.{}", file_name, crate::r#const::EXTENSION_LLVM_BITCODE);
+            let mut file_path = path.to_owned();
+            file_path.push(file_name);
+
+            if file_path.exists() && !overwrite {
+                eprintln!(
+                    "Refusing to overwrite an existing file {:?} (use --overwrite to force).",
+                    file_path
+                );
+            } else {
+                File::create(&file_path)
+                    .map_err(|error| {
+                        anyhow::anyhow!("File {:?} creating error: {}", file_path, error)
+                    })?
+                    .write_all(llvm_bitcode.as_slice())
+                    .map_err(|error| {
+                        anyhow::anyhow!("File {:?} writing error: {}", file_path, error)
+                    })?;
+            }
+        }
+
         if let Some(abi) = self.abi {
             if output_abi {
                 let file_name = format!("{}.{}", file_name, compiler_common::EXTENSION_ABI);
@@ -158,6 +285,20 @@ impl Contract {
 
         combined_json_contract.abi = self.abi;
         combined_json_contract.factory_deps = Some(self.build.factory_dependencies);
+        if let Some((deploy, runtime)) = self.source_maps {
+            let (srcmap, srcmap_runtime) = match deploy.kind {
+                SourceMapKind::Yul => (
+                    &mut combined_json_contract.yul_srcmap,
+                    &mut combined_json_contract.yul_srcmap_runtime,
+                ),
+                SourceMapKind::EVMLegacyAssembly => (
+                    &mut combined_json_contract.asm_srcmap,
+                    &mut combined_json_contract.asm_srcmap_runtime,
+                ),
+            };
+            *srcmap = Some(deploy.to_string());
+            *srcmap_runtime = Some(runtime.to_string());
+        }
 
         Ok(())
     }
@@ -173,14 +314,69 @@ impl Contract {
 
         standard_json_contract.ir_optimized = None;
         standard_json_contract.abi = self.abi;
-        standard_json_contract.evm =
-            Some(StandardJsonOutputContractEVM::new_zkevm_bytecode(bytecode));
+        let mut evm = StandardJsonOutputContractEVM::new_zkevm_bytecode(bytecode.clone());
+        if let Some(bytecode) = evm.bytecode.as_mut() {
+            bytecode.set_link_references(&self.link_references);
+        }
+        if let Some((deploy, runtime)) = self.source_maps {
+            if let Some(bytecode) = evm.bytecode.as_mut() {
+                bytecode.set_source_map(&deploy);
+            }
+            let mut deployed_bytecode = StandardJsonOutputContractEVMBytecode::new(bytecode);
+            deployed_bytecode.set_source_map(&runtime);
+            deployed_bytecode.set_link_references(&self.link_references);
+            evm.deployed_bytecode = Some(deployed_bytecode);
+        }
+        standard_json_contract.evm = Some(evm);
         standard_json_contract.factory_dependencies = Some(self.build.factory_dependencies);
         standard_json_contract.hash = Some(self.build.hash);
 
         Ok(())
     }
 
+    ///
+    /// Checks whether the contract bytecode is identical to the expected one.
+    ///
+    /// The zkEVM bytecode does not contain a metadata hash, so the whole bytecode is compared.
+    /// On mismatch, the error contains the offset of the first differing byte.
+    ///
+    pub fn verify(&self, expected: &[u8]) -> anyhow::Result<()> {
+        let bytecode = self.build.bytecode.as_slice();
+
+        let offset = match bytecode
+            .iter()
+            .zip(expected.iter())
+            .position(|(found, expected)| found != expected)
+        {
+            Some(offset) => offset,
+            None if bytecode.len() != expected.len() => bytecode.len().min(expected.len()),
+            None => return Ok(()),
+        };
+
+        let byte_to_string = |byte: Option<&u8>| match byte {
+            Some(byte) => format!("0x{:02x}", byte),
+            None => "the end of bytecode".to_owned(),
+        };
+        anyhow::bail!(
+            "Contract `{}` bytecode mismatch at offset {}: expected {}, found {}",
+            self.path,
+            offset,
+            byte_to_string(expected.get(offset)),
+            byte_to_string(bytecode.get(offset)),
+        );
+    }
+
+    ///
+    /// Whether the contract bytecode matches the bytecode deployed on-chain.
+    ///
+    /// Unlike EVM, zkEVM keeps the immutable values in a system contract storage instead of
+    /// patching them into the bytecode, so the deployed bytecode does not contain any immutable
+    /// placeholders and must be identical to the compiled one.
+    ///
+    pub fn matches_deployed(&self, onchain: &[u8]) -> bool {
+        self.verify(onchain).is_ok()
+    }
+
     ///
     /// Converts the full path to a short one.
     ///
@@ -189,4 +385,270 @@ impl Contract {
             .map(|last_slash| &path[last_slash + 1..])
             .unwrap_or_else(|| path)
     }
+
+    ///
+    /// Converts the full path to the output subdirectory mirroring the source path and the file
+    /// name without extension.
+    ///
+    /// The absolute source paths are made relative to the current directory if possible.
+    /// Otherwise, only their normal components are preserved to stay within the output directory.
+    ///
+    pub fn mirrored_path(output_directory: &Path, path: &str) -> (PathBuf, String) {
+        let (source_path, file_name) = match path.rsplit_once(':') {
+            Some((source_path, name)) => (Path::new(source_path), name.to_owned()),
+            None => (
+                Path::new(path).parent().unwrap_or(Path::new("")),
+                Self::short_path(path).to_owned(),
+            ),
+        };
+
+        let current_directory = std::env::current_dir().unwrap_or_default();
+        let source_path = source_path
+            .strip_prefix(current_directory.as_path())
+            .unwrap_or(source_path);
+
+        let mut directory = output_directory.to_owned();
+        directory.extend(
+            source_path
+                .components()
+                .filter(|component| matches!(component, Component::Normal(_))),
+        );
+        (directory, file_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::project::Project;
+
+    use super::Contract;
+
+    #[test]
+    fn verify_own_bytecode() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                mstore(0, 42)
+                return(0, 32)
+            }
+        }
+    }
+}
+    "#;
+
+        let build = Project::compile_test_yul(input).expect("Always valid");
+        let contract = build.contracts.get("Test").expect("Always exists");
+
+        let mut expected = contract.build.bytecode.clone();
+        assert!(contract.verify(expected.as_slice()).is_ok());
+
+        expected[0] ^= 0xff;
+        let error = contract
+            .verify(expected.as_slice())
+            .expect_err("Must be a mismatch");
+        assert!(error.to_string().contains("mismatch at offset 0"));
+    }
+
+    #[test]
+    fn bytecode_hash() {
+        let mut hashes = Vec::new();
+        for deploy_code in ["return(0, 0)", "revert(0, 0)"] {
+            let input = format!(
+                r#"
+object "Test" {{
+    code {{
+        {{
+            {}
+        }}
+    }}
+    object "Test_deployed" {{
+        code {{
+            {{
+                mstore(0, 42)
+                return(0, 32)
+            }}
+        }}
+    }}
+}}
+    "#,
+                deploy_code
+            );
+
+            let build = Project::compile_test_yul(input.as_str()).expect("Always valid");
+            let contract = build.contracts.get("Test").expect("Always exists");
+
+            let hash = contract.bytecode_hash();
+            assert!(!hash.is_empty());
+            hashes.push(hash.to_owned());
+        }
+        assert_ne!(hashes[0], hashes[1]);
+    }
+
+    #[test]
+    fn binary_output() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                mstore(0, 42)
+                return(0, 32)
+            }
+        }
+    }
+}
+    "#;
+
+        let build = Project::compile_test_yul(input).expect("Always valid");
+        let contract = build.contracts.get("Test").expect("Always exists");
+        let bytecode = hex::encode(contract.build.bytecode.as_slice());
+
+        let deploy = contract.binary_output(false, true, false);
+        assert_eq!(
+            deploy,
+            format!("Contract `Test` deploy bytecode: 0x{}\n", bytecode)
+        );
+
+        let runtime = contract.binary_output(false, false, true);
+        assert_eq!(
+            runtime,
+            format!("Contract `Test` runtime bytecode: 0x{}\n", bytecode)
+        );
+
+        assert!(contract.binary_output(false, false, false).is_empty());
+    }
+
+    #[test]
+    fn matches_deployed_with_immutables() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            setimmutable(0, "value", calldataload(0))
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                mstore(0, loadimmutable("value"))
+                return(0, 32)
+            }
+        }
+    }
+}
+    "#;
+
+        let build = Project::compile_test_yul(input).expect("Always valid");
+        let contract = build.contracts.get("Test").expect("Always exists");
+
+        let mut onchain = contract.build.bytecode.clone();
+        assert!(contract.matches_deployed(onchain.as_slice()));
+
+        onchain.push(0);
+        assert!(!contract.matches_deployed(onchain.as_slice()));
+    }
+
+    #[test]
+    fn mirrored_path() {
+        let (directory, file_name) =
+            Contract::mirrored_path(Path::new("out"), "src/nested/Token.sol:Token");
+        assert_eq!(directory, Path::new("out/src/nested/Token.sol"));
+        assert_eq!(file_name, "Token");
+
+        let (directory, _) = Contract::mirrored_path(Path::new("out"), "/../Token.sol:Token");
+        assert_eq!(directory, Path::new("out/Token.sol"));
+    }
+
+    #[test]
+    fn write_to_mirrored_directory() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut build = Project::compile_test_yul(input).expect("Always valid");
+        let mut contract = build.contracts.remove("Test").expect("Always exists");
+        contract.path = "src/nested/Token.sol:Token".to_owned();
+
+        let output_directory =
+            std::env::temp_dir().join(format!("zksolc-mirrored-{}", std::process::id()));
+        contract
+            .write_to_directory(output_directory.as_path(), false, true, false, true, true)
+            .expect("Always valid");
+
+        let mut file_path = output_directory.join("src/nested/Token.sol/Token");
+        file_path.set_extension(compiler_common::EXTENSION_ZKEVM_BINARY);
+        let is_written = file_path.exists();
+        std::fs::remove_dir_all(output_directory.as_path()).expect("Always valid");
+        assert!(is_written);
+    }
+
+    #[test]
+    fn write_llvm_bitcode() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut project = Project::try_from_test_yul(input, &semver::Version::new(0, 8, 17))
+            .expect("Always valid");
+        project.emit_llvm_bc = true;
+        let mut build = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid");
+        let contract = build.contracts.remove("Test").expect("Always exists");
+
+        let output_directory =
+            std::env::temp_dir().join(format!("zksolc-bitcode-{}", std::process::id()));
+        std::fs::create_dir_all(output_directory.as_path()).expect("Always valid");
+        contract
+            .write_to_directory(output_directory.as_path(), false, false, false, true, false)
+            .expect("Always valid");
+
+        let mut file_path = output_directory.join("Test");
+        file_path.set_extension(crate::r#const::EXTENSION_LLVM_BITCODE);
+        let bitcode = std::fs::read(file_path.as_path());
+        std::fs::remove_dir_all(output_directory.as_path()).expect("Always valid");
+        let bitcode = bitcode.expect("Always valid");
+        assert!(bitcode.starts_with(b"BC\xC0\xDE"));
+    }
 }
@@ -0,0 +1,109 @@
+//!
+//! The lowering coverage of a contract.
+//!
+
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
+thread_local! {
+    /// The stack of the coverages recorded on the current thread, where the last one is current.
+    static RECORDING: RefCell<Vec<Coverage>> = RefCell::new(Vec::new());
+}
+
+///
+/// The names of the Yul built-in functions and EVM legacy assembly instructions lowered to
+/// LLVM IR while compiling a contract.
+///
+/// The LLVM context does not carry the project settings, so the coverage is recorded for the
+/// duration of a contract lowering on its thread. The dependency contracts are compiled on the
+/// same thread, so the recordings are stacked.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct Coverage {
+    /// The Yul built-in functions, spelled as in the Yul source.
+    pub yul: BTreeSet<String>,
+    /// The EVM legacy assembly instructions, spelled as in the `solc` output.
+    pub evmla: BTreeSet<String>,
+}
+
+impl Coverage {
+    ///
+    /// Starts recording the coverage on this thread until the returned guard is finished or
+    /// dropped.
+    ///
+    pub fn record() -> Guard {
+        RECORDING.with(|recording| recording.borrow_mut().push(Self::default()));
+        Guard { is_finished: false }
+    }
+
+    ///
+    /// Records the lowered Yul built-in function, if the coverage is being recorded.
+    ///
+    pub fn record_yul(name: impl std::fmt::Display) {
+        Self::with_current(|coverage| {
+            coverage.yul.insert(name.to_string());
+        });
+    }
+
+    ///
+    /// Records the lowered EVM legacy assembly instruction, if the coverage is being recorded.
+    ///
+    pub fn record_evmla(name: impl std::fmt::Display) {
+        Self::with_current(|coverage| {
+            coverage.evmla.insert(name.to_string().trim().to_owned());
+        });
+    }
+
+    ///
+    /// Merges the `other` coverage into this one.
+    ///
+    pub fn extend(&mut self, other: &Self) {
+        self.yul.extend(other.yul.iter().cloned());
+        self.evmla.extend(other.evmla.iter().cloned());
+    }
+
+    ///
+    /// Calls `f` with the current coverage, if any.
+    ///
+    fn with_current<F>(f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        RECORDING.with(|recording| {
+            if let Some(coverage) = recording.borrow_mut().last_mut() {
+                f(coverage);
+            }
+        });
+    }
+}
+
+///
+/// Stops the recording started by `Coverage::record` on drop.
+///
+#[must_use = "The coverage is only recorded until the guard is dropped"]
+pub struct Guard {
+    /// Whether the recording has been taken with `finish`.
+    is_finished: bool,
+}
+
+impl Guard {
+    ///
+    /// Stops the recording and returns the recorded coverage.
+    ///
+    pub fn finish(mut self) -> Coverage {
+        self.is_finished = true;
+        RECORDING.with(|recording| recording.borrow_mut().pop().unwrap_or_default())
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if !self.is_finished {
+            RECORDING.with(|recording| {
+                recording.borrow_mut().pop();
+            });
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//!
+//! The LLVM IR statistics of a contract.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+///
+/// The LLVM IR statistics of a contract.
+///
+/// Collected right after the lowering, before the LLVM optimizer runs.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct IRStats {
+    /// The number of the instructions of each opcode, keyed by the lowercased opcode name.
+    pub instructions: BTreeMap<String, usize>,
+    /// The number of the basic blocks in all functions.
+    pub basic_blocks: usize,
+}
+
+impl IRStats {
+    ///
+    /// Counts the instructions and basic blocks of the functions defined in the `module`.
+    ///
+    pub fn new(module: &inkwell::module::Module) -> Self {
+        let mut stats = Self::default();
+        for function in module.get_functions() {
+            for block in function.get_basic_blocks() {
+                stats.basic_blocks += 1;
+
+                let mut instruction = block.get_first_instruction();
+                while let Some(current) = instruction {
+                    let opcode = format!("{:?}", current.get_opcode()).to_lowercase();
+                    *stats.instructions.entry(opcode).or_default() += 1;
+                    instruction = current.get_next_instruction();
+                }
+            }
+        }
+        stats
+    }
+
+    ///
+    /// Returns the number of the instructions with the lowercased `opcode` name.
+    ///
+    pub fn count(&self, opcode: &str) -> usize {
+        self.instructions.get(opcode).copied().unwrap_or_default()
+    }
+}
@@ -3,15 +3,20 @@
 //!
 
 pub mod contract;
+pub mod coverage;
+pub mod ir_stats;
 
 use std::collections::BTreeMap;
 use std::path::Path;
 
 use crate::solc::combined_json::CombinedJson;
+use crate::solc::standard_json::output::error::Error as StandardJsonOutputError;
 use crate::solc::standard_json::output::Output as StandardJsonOutput;
 use crate::solc::version::Version as SolcVersion;
 
 use self::contract::Contract;
+use self::coverage::Coverage;
+use self::ir_stats::IRStats;
 
 ///
 /// The Solidity project build.
@@ -20,9 +25,170 @@ use self::contract::Contract;
 pub struct Build {
     /// The contract data,
     pub contracts: BTreeMap<String, Contract>,
+    /// The errors of the contracts failed to compile, if the compilation is not halted on the
+    /// first error.
+    pub errors: BTreeMap<String, anyhow::Error>,
+    /// The warnings found while compiling the contracts.
+    pub warnings: Vec<StandardJsonOutputError>,
 }
 
 impl Build {
+    ///
+    /// Returns the iterator over the contracts in the deterministic order of their paths.
+    ///
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (&str, &Contract)> {
+        self.contracts
+            .iter()
+            .map(|(path, contract)| (path.as_str(), contract))
+    }
+
+    ///
+    /// Returns the contract at `path`, which is either the full contract path, or its suffix
+    /// starting after a `/`, e.g. `src/Test.sol:Test` for `/project/src/Test.sol:Test`.
+    ///
+    /// It is an error if the path does not match any contract, or matches several ones.
+    ///
+    pub fn find_contract(&self, path: &str) -> anyhow::Result<&Contract> {
+        let suffix = format!("/{}", path);
+        let matches: Vec<(&String, &Contract)> = self
+            .contracts
+            .iter()
+            .filter(|(contract_path, _contract)| {
+                contract_path.as_str() == path || contract_path.ends_with(suffix.as_str())
+            })
+            .collect();
+
+        match matches.as_slice() {
+            [] => anyhow::bail!("Contract `{}` not found in the project", path),
+            [(_path, contract)] => Ok(contract),
+            matches => anyhow::bail!(
+                "Contract `{}` is ambiguous, as it matches several contracts: {}",
+                path,
+                matches
+                    .iter()
+                    .map(|(path, _contract)| format!("`{}`", path))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    ///
+    /// Returns the report of the compilation pass timings of all contracts.
+    ///
+    pub fn pass_timings_report(&self) -> String {
+        let mut report = String::new();
+        for (path, contract) in self.contracts.iter() {
+            report.push_str(format!("Contract `{}` pass timings:\n", path).as_str());
+            for (pass, duration) in contract.pass_timings.iter() {
+                report.push_str(
+                    format!(
+                        "    {:<40}{:>12.3}ms\n",
+                        pass,
+                        duration.as_secs_f64() * 1000.0
+                    )
+                    .as_str(),
+                );
+            }
+        }
+        report
+    }
+
+    ///
+    /// Returns the JSON object with the sorted arrays of the Yul built-in functions and EVM
+    /// legacy assembly instructions lowered in all contracts, that is, `{"yul": [...],
+    /// "evmla": [...]}`.
+    ///
+    /// Only the contracts compiled with the coverage recording enabled are taken into account.
+    ///
+    pub fn coverage_json(&self) -> serde_json::Value {
+        let mut coverage = Coverage::default();
+        for contract_coverage in self
+            .contracts
+            .values()
+            .filter_map(|contract| contract.coverage.as_ref())
+        {
+            coverage.extend(contract_coverage);
+        }
+        serde_json::json!(coverage)
+    }
+
+    ///
+    /// Returns the JSON object with the LLVM IR statistics of the contracts, keyed by the
+    /// contract path.
+    ///
+    /// Only the contracts compiled with the statistics collection enabled are taken into account.
+    ///
+    pub fn ir_stats_json(&self) -> serde_json::Value {
+        let stats: BTreeMap<&str, &IRStats> = self
+            .contracts
+            .iter()
+            .filter_map(|(path, contract)| {
+                contract
+                    .ir_stats
+                    .as_ref()
+                    .map(|stats| (path.as_str(), stats))
+            })
+            .collect();
+        serde_json::json!(stats)
+    }
+
+    ///
+    /// Writes the lowered names returned by `coverage_json` to the file at `path`.
+    ///
+    pub fn write_coverage(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.coverage_json().to_string())
+            .map_err(|error| anyhow::anyhow!("Coverage file {:?} writing error: {}", path, error))
+    }
+
+    ///
+    /// Checks that the bytecode of every contract fits into `limit` bytes.
+    ///
+    /// The error lists all the contracts exceeding the limit along with their bytecode sizes.
+    ///
+    pub fn check_bytecode_size(&self, limit: usize) -> anyhow::Result<()> {
+        let violations: Vec<String> = self
+            .contracts
+            .iter()
+            .filter(|(_path, contract)| contract.build.bytecode.len() > limit)
+            .map(|(path, contract)| {
+                format!(
+                    "Contract `{}` bytecode size is {} bytes",
+                    path,
+                    contract.build.bytecode.len()
+                )
+            })
+            .collect();
+        if !violations.is_empty() {
+            anyhow::bail!(
+                "The bytecode size limit of {} bytes is exceeded:\n{}",
+                limit,
+                violations.join("\n")
+            );
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the `0x`-prefixed hexadecimal bytecode of every contract, keyed by the contract
+    /// path, e.g. for the deployment scripts.
+    ///
+    /// The zkEVM bytecode is the same for the deploy and runtime code, so there is one entry
+    /// per contract.
+    ///
+    pub fn bytecode_map(&self) -> BTreeMap<String, String> {
+        self.contracts
+            .iter()
+            .map(|(path, contract)| {
+                (
+                    path.to_owned(),
+                    format!("0x{}", hex::encode(contract.build.bytecode.as_slice())),
+                )
+            })
+            .collect()
+    }
+
     ///
     /// Writes all contracts to the specified directory.
     ///
@@ -33,6 +199,7 @@ impl Build {
         output_binary: bool,
         output_abi: bool,
         overwrite: bool,
+        mirror_source_paths: bool,
     ) -> anyhow::Result<()> {
         for (_path, contract) in self.contracts.into_iter() {
             contract.write_to_directory(
@@ -41,6 +208,7 @@ impl Build {
                 output_binary,
                 output_abi,
                 overwrite,
+                mirror_source_paths,
             )?;
         }
 
@@ -58,15 +226,7 @@ impl Build {
     ) -> anyhow::Result<()> {
         for (path, contract) in self.contracts.into_iter() {
             let combined_json_contract = combined_json
-                .contracts
-                .iter_mut()
-                .find_map(|(json_path, contract)| {
-                    if path.ends_with(json_path) {
-                        Some(contract)
-                    } else {
-                        None
-                    }
-                })
+                .contract_mut(path.as_str())
                 .ok_or_else(|| anyhow::anyhow!("Contract `{}` not found in the project", path))?;
 
             contract.write_to_combined_json(combined_json_contract)?;
@@ -87,6 +247,13 @@ impl Build {
         solc_version: &SolcVersion,
         zksolc_version: &semver::Version,
     ) -> anyhow::Result<()> {
+        if !self.warnings.is_empty() {
+            standard_json
+                .errors
+                .get_or_insert_with(Vec::new)
+                .extend(self.warnings.drain(..));
+        }
+
         let contracts = match standard_json.contracts.as_mut() {
             Some(contracts) => contracts,
             None => return Ok(()),
@@ -109,3 +276,410 @@ impl Build {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::project::Project;
+    use crate::solc::combined_json::CombinedJson;
+    use crate::solc::pipeline::Pipeline as SolcPipeline;
+    use crate::solc::standard_json::output::Output as SolcStandardJsonOutput;
+    use crate::solc::version::Version as SolcVersion;
+
+    fn test_build() -> super::Build {
+        let mut output: SolcStandardJsonOutput = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "/project/test.sol": {
+            "Small": {
+                "irOptimized": "object \"Small_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Small_1_deployed\" {\n        code {\n            {\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+            },
+            "Large": {
+                "irOptimized": "object \"Large_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Large_1_deployed\" {\n        code {\n            {\n                for { let i := 0 } lt(i, calldatasize()) { i := add(i, 32) } {\n                    sstore(i, add(calldataload(i), sload(i)))\n                    log1(i, 32, keccak256(i, 32))\n                }\n                mstore(0, mulmod(calldataload(0), calldataload(32), calldataload(64)))\n                return(0, 32)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+        let version = semver::Version::new(0, 8, 17);
+        output
+            .try_to_project(BTreeMap::new(), SolcPipeline::Yul, &version, &[])
+            .expect("Always valid")
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid")
+    }
+
+    fn coverage_build(pipeline: SolcPipeline, output: &str) -> super::Build {
+        let mut output: SolcStandardJsonOutput =
+            serde_json::from_str(output).expect("Always valid");
+        let version = semver::Version::new(0, 8, 17);
+        let mut project = output
+            .try_to_project(BTreeMap::new(), pipeline, &version, &[])
+            .expect("Always valid");
+        project.emit_coverage = true;
+
+        project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid")
+    }
+
+    #[test]
+    fn coverage_json_yul() {
+        let build = coverage_build(
+            SolcPipeline::Yul,
+            r#"{
+    "contracts": {
+        "/project/test.sol": {
+            "Small": {
+                "irOptimized": "object \"Small_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Small_1_deployed\" {\n        code {\n            {\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+            },
+            "Large": {
+                "irOptimized": "object \"Large_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Large_1_deployed\" {\n        code {\n            {\n                for { let i := 0 } lt(i, calldatasize()) { i := add(i, 32) } {\n                    sstore(i, add(calldataload(i), sload(i)))\n                    log1(i, 32, keccak256(i, 32))\n                }\n                mstore(0, double(mulmod(calldataload(0), calldataload(32), calldataload(64))))\n                return(0, 32)\n            }\n\n            function double(value) -> result {\n                result := shl(1, value)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#,
+        );
+
+        assert_eq!(
+            build.coverage_json(),
+            serde_json::json!({
+                "yul": [
+                    "add",
+                    "calldataload",
+                    "calldatasize",
+                    "keccak256",
+                    "log1",
+                    "lt",
+                    "mstore",
+                    "mulmod",
+                    "return",
+                    "shl",
+                    "sload",
+                    "sstore",
+                ],
+                "evmla": [],
+            })
+        );
+        let small = build.contracts["/project/test.sol:Small"]
+            .coverage
+            .as_ref()
+            .expect("Always exists");
+        assert_eq!(
+            small.yul.iter().map(String::as_str).collect::<Vec<&str>>(),
+            vec!["return"]
+        );
+        assert!(small.evmla.is_empty());
+    }
+
+    #[test]
+    fn coverage_json_evmla() {
+        let build = coverage_build(
+            SolcPipeline::EVM,
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Test": {
+                "evm": {
+                    "legacyAssembly": {
+                        ".code": [
+                            { "name": "PUSH", "value": "0" },
+                            { "name": "DUP1" },
+                            { "name": "RETURN" }
+                        ],
+                        ".data": {
+                            "0": {
+                                ".code": [
+                                    { "name": "PUSH", "value": "0" },
+                                    { "name": "CALLDATALOAD" },
+                                    { "name": "PUSH", "value": "0" },
+                                    { "name": "MSTORE" },
+                                    { "name": "PUSH", "value": "20" },
+                                    { "name": "PUSH", "value": "0" },
+                                    { "name": "RETURN" }
+                                ]
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}"#,
+        );
+
+        let coverage = build.contracts["test.sol:Test"]
+            .coverage
+            .as_ref()
+            .expect("Always exists");
+        assert!(coverage.yul.is_empty());
+        for name in ["CALLDATALOAD", "DUP1", "MSTORE", "PUSH", "RETURN"] {
+            assert!(coverage.evmla.contains(name), "`{}` is not recorded", name);
+        }
+        assert!(!coverage.evmla.contains("Tag"));
+    }
+
+    #[test]
+    fn coverage_disabled() {
+        let build = test_build();
+        assert!(build
+            .contracts
+            .values()
+            .all(|contract| contract.coverage.is_none()));
+        assert_eq!(
+            build.coverage_json(),
+            serde_json::json!({ "yul": [], "evmla": [] })
+        );
+    }
+
+    #[test]
+    fn bytecode_map() {
+        let build = test_build();
+
+        let bytecode_map = build.bytecode_map();
+        assert_eq!(
+            bytecode_map
+                .keys()
+                .map(String::as_str)
+                .collect::<Vec<&str>>(),
+            vec!["/project/test.sol:Large", "/project/test.sol:Small"]
+        );
+        for (path, bytecode) in bytecode_map.iter() {
+            let hexadecimal = bytecode.strip_prefix("0x").expect("Always exists");
+            assert_eq!(
+                hex::decode(hexadecimal).expect("Always valid"),
+                build.contracts[path].build.bytecode
+            );
+        }
+    }
+
+    #[test]
+    fn write_to_combined_json_two_contracts_in_file() {
+        let mut output: SolcStandardJsonOutput = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "/project/test.sol": {
+            "First": {
+                "irOptimized": "object \"First_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"First_1_deployed\" {\n        code {\n            {\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+            },
+            "Second": {
+                "irOptimized": "object \"Second_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Second_1_deployed\" {\n        code {\n            {\n                mstore(0, 42)\n                return(0, 32)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+        let version = semver::Version::new(0, 8, 17);
+        let project = output
+            .try_to_project(BTreeMap::new(), SolcPipeline::Yul, &version, &[])
+            .expect("Always valid");
+
+        let build = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid");
+        let bytecodes: BTreeMap<String, String> = build
+            .contracts
+            .iter()
+            .map(|(path, contract)| {
+                (
+                    path.to_owned(),
+                    hex::encode(contract.build.bytecode.as_slice()),
+                )
+            })
+            .collect();
+
+        let mut combined_json: CombinedJson = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol:First": { "bin": "" },
+        "test.sol:Second": { "bin": "" }
+    },
+    "version": "0.8.17"
+}"#,
+        )
+        .expect("Always valid");
+        build
+            .write_to_combined_json(
+                &mut combined_json,
+                &SolcVersion::new(version.to_string(), version.clone()),
+                &semver::Version::new(1, 2, 0),
+            )
+            .expect("Always valid");
+
+        for name in ["First", "Second"] {
+            assert_eq!(
+                combined_json.contracts[format!("test.sol:{}", name).as_str()].bin,
+                bytecodes
+                    .get(format!("/project/test.sol:{}", name).as_str())
+                    .cloned()
+            );
+        }
+    }
+
+    #[test]
+    fn pass_timings_report() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                function fib(n) -> r {
+                    r := n
+                    if gt(n, 1) {
+                        r := add(fib(sub(n, 1)), fib(sub(n, 2)))
+                    }
+                }
+                mstore(0, fib(calldataload(0)))
+                return(0, 32)
+            }
+        }
+    }
+}
+    "#;
+
+        let project = Project::try_from_test_yul(input, &semver::Version::new(0, 8, 17))
+            .expect("Always valid");
+        let build = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::cycles())
+            .expect("Always valid");
+
+        let report = build.pass_timings_report();
+        assert!(report.contains("Contract `Test` pass timings:"));
+        assert!(report.contains("LLVM optimization and code generation"));
+    }
+
+    #[test]
+    fn ir_stats_json() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                for { let i := 0 } lt(i, calldatasize()) { i := add(i, 32) } {
+                    sstore(i, calldataload(i))
+                }
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut project = Project::try_from_test_yul(input, &semver::Version::new(0, 8, 17))
+            .expect("Always valid");
+        project.emit_ir_stats = true;
+        let build = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid");
+
+        let stats = build.contracts["Test"]
+            .ir_stats
+            .as_ref()
+            .expect("Always exists");
+        assert!(stats.count("load") > 0);
+        assert!(stats.count("store") > 0);
+        assert!(stats.count("br") >= 3);
+        assert!(stats.basic_blocks >= 4);
+
+        let json = build.ir_stats_json();
+        assert_eq!(json["Test"]["basic_blocks"], stats.basic_blocks);
+        assert_eq!(json["Test"]["instructions"]["br"], stats.count("br"));
+    }
+
+    #[test]
+    fn check_bytecode_size() {
+        let build = test_build();
+        let small = build.contracts["/project/test.sol:Small"]
+            .build
+            .bytecode
+            .len();
+        let large = build.contracts["/project/test.sol:Large"]
+            .build
+            .bytecode
+            .len();
+        assert!(small < large);
+
+        build
+            .check_bytecode_size(large)
+            .expect("Must fit into the limit");
+
+        let error = build
+            .check_bytecode_size(small)
+            .expect_err("Must exceed the limit")
+            .to_string();
+        assert!(error.contains(
+            format!(
+                "Contract `/project/test.sol:Large` bytecode size is {} bytes",
+                large
+            )
+            .as_str()
+        ));
+        assert!(!error.contains("/project/test.sol:Small"));
+    }
+
+    #[test]
+    fn iter_sorted() {
+        let build = test_build();
+        let paths: Vec<&str> = build.iter_sorted().map(|(path, _contract)| path).collect();
+        assert_eq!(
+            paths,
+            vec!["/project/test.sol:Large", "/project/test.sol:Small"]
+        );
+
+        for (path, contract) in build.iter_sorted() {
+            assert_eq!(contract.path, path);
+            assert!(!contract.bytecode().is_empty());
+        }
+    }
+
+    #[test]
+    fn find_contract() {
+        let mut build = test_build();
+
+        for path in [
+            "/project/test.sol:Small",
+            "project/test.sol:Small",
+            "test.sol:Small",
+        ] {
+            assert_eq!(
+                build.find_contract(path).expect("Always exists").path,
+                "/project/test.sol:Small"
+            );
+        }
+        for path in ["t.sol:Small", "Small", "/test.sol:Small", "test.sol:Sma"] {
+            assert!(build
+                .find_contract(path)
+                .expect_err("Must be an error")
+                .to_string()
+                .contains("not found"));
+        }
+
+        let contract = build
+            .contracts
+            .remove("/project/test.sol:Large")
+            .expect("Always exists");
+        build
+            .contracts
+            .insert("/dependency/test.sol:Small".to_owned(), contract);
+        let error = build
+            .find_contract("test.sol:Small")
+            .expect_err("Must be an error")
+            .to_string();
+        assert!(error.contains("ambiguous"));
+        assert!(error.contains("`/dependency/test.sol:Small`, `/project/test.sol:Small`"));
+        assert!(build.find_contract("project/test.sol:Small").is_ok());
+    }
+}
@@ -15,3 +15,9 @@ pub const OFFSET_EMPTY_SLOT: usize = 3 * compiler_common::SIZE_FIELD;
 
 /// The non-reserved memory offset.
 pub const OFFSET_NON_RESERVED: usize = 4 * compiler_common::SIZE_FIELD;
+
+/// The constant `create` and `create2` input size, above which a warning is emitted.
+pub const CREATE_INPUT_SIZE_WARNING_THRESHOLD: usize = 1 << 20;
+
+/// The LLVM bitcode file extension.
+pub const EXTENSION_LLVM_BITCODE: &str = "bc";
@@ -11,8 +11,10 @@
 pub enum DumpFlag {
     /// Whether to dump the Yul code.
     Yul,
-    /// Whether to dump the Ethereal IR code.
-    EthIR,
+    /// Whether to dump the deploy code Ethereal IR.
+    EthIRDeploy,
+    /// Whether to dump the runtime code Ethereal IR.
+    EthIRRuntime,
     /// Whether to dump the EVM code.
     EVM,
     /// Whether to dump the LLVM IR code.
@@ -27,17 +29,21 @@ impl DumpFlag {
     ///
     pub fn from_booleans(
         yul: bool,
-        ethir: bool,
+        ethir_deploy: bool,
+        ethir_runtime: bool,
         evm: bool,
         llvm: bool,
         assembly: bool,
     ) -> Vec<Self> {
-        let mut vector = Vec::with_capacity(5);
+        let mut vector = Vec::with_capacity(6);
         if yul {
             vector.push(Self::Yul);
         }
-        if ethir {
-            vector.push(Self::EthIR);
+        if ethir_deploy {
+            vector.push(Self::EthIRDeploy);
+        }
+        if ethir_runtime {
+            vector.push(Self::EthIRRuntime);
         }
         if evm {
             vector.push(Self::EVM);
@@ -54,13 +60,34 @@ impl DumpFlag {
     ///
     /// A shortcut constructor for the context aggregator.
     ///
+    /// The context aggregator does not tell the deploy and runtime Ethereal IR apart, so both
+    /// are dumped if it is requested.
+    ///
     pub fn from_context(dump_flags: &[compiler_llvm_context::DumpFlag]) -> Vec<Self> {
+        let ethir = dump_flags.contains(&compiler_llvm_context::DumpFlag::EthIR);
         Self::from_booleans(
             dump_flags.contains(&compiler_llvm_context::DumpFlag::Yul),
-            dump_flags.contains(&compiler_llvm_context::DumpFlag::EthIR),
+            ethir,
+            ethir,
             dump_flags.contains(&compiler_llvm_context::DumpFlag::EVM),
             dump_flags.contains(&compiler_llvm_context::DumpFlag::LLVM),
             dump_flags.contains(&compiler_llvm_context::DumpFlag::Assembly),
         )
     }
+
+    ///
+    /// Returns the code type the Ethereal IR dump is restricted to.
+    ///
+    /// Returns `None` if the Ethereal IR of both code types or none of them is dumped.
+    ///
+    pub fn ethir_code_type(dump_flags: &[Self]) -> Option<compiler_llvm_context::CodeType> {
+        match (
+            dump_flags.contains(&Self::EthIRDeploy),
+            dump_flags.contains(&Self::EthIRRuntime),
+        ) {
+            (true, false) => Some(compiler_llvm_context::CodeType::Deploy),
+            (false, true) => Some(compiler_llvm_context::CodeType::Runtime),
+            _ => None,
+        }
+    }
 }
@@ -12,6 +12,9 @@ use std::collections::BTreeMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::solc::source_map::entry::jump::Jump as SourceMapJump;
+use crate::solc::source_map::entry::Entry as SourceMapEntry;
+
 use self::name::Name;
 
 ///
@@ -23,12 +26,30 @@ pub struct Instruction {
     pub name: Name,
     /// The optional value argument.
     pub value: Option<String>,
+    /// The source code file index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<isize>,
+    /// The source code location start offset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin: Option<isize>,
+    /// The source code location end offset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<isize>,
+    /// The jump type, which is `[in]` for function calls and `[out]` for function returns.
+    #[serde(rename = "jumpType", skip_serializing_if = "Option::is_none")]
+    pub jump_type: Option<String>,
+    /// The modifier depth.
+    #[serde(rename = "modifierDepth", skip_serializing_if = "Option::is_none")]
+    pub modifier_depth: Option<usize>,
 }
 
 impl Instruction {
     ///
     /// Returns the number of input stack arguments.
     ///
+    /// Only `ASSIGNIMMUTABLE` depends on the `solc` version, as it takes the immutables
+    /// offset argument since `solc` 0.8.0.
+    ///
     pub const fn input_size(&self, version: &semver::Version) -> usize {
         match self.name {
             Name::POP => 1,
@@ -86,6 +107,7 @@ impl Instruction {
             Name::CALLDATACOPY => 3,
             Name::CODECOPY => 3,
             Name::RETURNDATACOPY => 3,
+            Name::RETURNDATALOAD => 1,
             Name::EXTCODESIZE => 1,
             Name::EXTCODEHASH => 1,
 
@@ -221,6 +243,7 @@ impl Instruction {
             Name::CODESIZE => 1,
             Name::PUSHSIZE => 1,
             Name::RETURNDATASIZE => 1,
+            Name::RETURNDATALOAD => 1,
             Name::EXTCODESIZE => 1,
             Name::EXTCODEHASH => 1,
 
@@ -248,6 +271,7 @@ impl Instruction {
             Name::CHAINID => 1,
             Name::BLOCKHASH => 1,
             Name::DIFFICULTY => 1,
+            Name::PREVRANDAO => 1,
             Name::COINBASE => 1,
             Name::MSIZE => 1,
 
@@ -265,20 +289,29 @@ impl Instruction {
         instructions: &mut [Self],
         mapping: &BTreeMap<String, String>,
     ) -> anyhow::Result<()> {
-        for instruction in instructions.iter_mut() {
+        for (index, instruction) in instructions.iter_mut().enumerate() {
             match instruction {
                 Instruction {
                     name: Name::PUSH_ContractHash | Name::PUSH_ContractHashSize,
                     value: Some(value),
+                    ..
                 } => {
                     *value = mapping
                         .get(value.as_str())
                         .cloned()
                         .ok_or_else(|| anyhow::anyhow!("Alias `{}` data not found", value))?;
                 }
+                Instruction {
+                    name: name @ (Name::PUSH_ContractHash | Name::PUSH_ContractHashSize),
+                    value: None,
+                    ..
+                } => {
+                    anyhow::bail!("Instruction `{:?}` #{} value missing", name, index);
+                }
                 Instruction {
                     name: Name::PUSH_Data,
                     value: Some(value),
+                    ..
                 } => {
                     let mut key_extended =
                         "0".repeat(compiler_common::SIZE_FIELD * 2 - value.len());
@@ -302,8 +335,47 @@ impl Instruction {
         Self {
             name: Name::INVALID,
             value: None,
+            source: None,
+            begin: None,
+            end: None,
+            jump_type: None,
+            modifier_depth: None,
+        }
+    }
+
+    ///
+    /// Returns the source code location in the `solc` `start:length:source` format, if known.
+    ///
+    pub fn location(&self) -> Option<String> {
+        match (self.begin, self.end, self.source) {
+            (Some(begin), Some(end), Some(source)) => {
+                Some(format!("{}:{}:{}", begin, end - begin, source))
+            }
+            _ => None,
         }
     }
+
+    ///
+    /// Returns the `solc` source map entry of the instruction.
+    ///
+    pub fn source_map_entry(&self) -> SourceMapEntry {
+        let (start, length) = match (self.begin, self.end) {
+            (Some(begin), Some(end)) => (begin, end - begin),
+            _ => (-1, -1),
+        };
+        let jump = match self.jump_type.as_deref() {
+            Some("[in]") => SourceMapJump::In,
+            Some("[out]") => SourceMapJump::Out,
+            _ => SourceMapJump::Regular,
+        };
+        SourceMapEntry::new(
+            start,
+            length,
+            self.source.unwrap_or(-1),
+            jump,
+            self.modifier_depth.unwrap_or_default(),
+        )
+    }
 }
 
 impl std::fmt::Display for Instruction {
@@ -319,3 +391,29 @@ impl std::fmt::Display for Instruction {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::Instruction;
+
+    #[test]
+    fn error_contract_hash_value_missing() {
+        let mut instructions: Vec<Instruction> = serde_json::from_str(
+            r#"[
+    { "name": "PUSH", "value": "80" },
+    { "name": "PUSH [$]" }
+]"#,
+        )
+        .expect("Always valid");
+
+        let error =
+            Instruction::replace_data_aliases(instructions.as_mut_slice(), &BTreeMap::new())
+                .expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            "Instruction `PUSH_ContractHash` #1 value missing"
+        );
+    }
+}
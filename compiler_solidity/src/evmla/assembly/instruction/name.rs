@@ -267,6 +267,8 @@ pub enum Name {
     RETURNDATASIZE,
     /// The eponymous EVM instruction.
     RETURNDATACOPY,
+    /// The eponymous EVM instruction.
+    RETURNDATALOAD,
 
     /// The eponymous EVM instruction.
     RETURN,
@@ -337,6 +339,8 @@ pub enum Name {
     BLOCKHASH,
     /// The eponymous EVM instruction.
     DIFFICULTY,
+    /// The eponymous EVM instruction, replacing `DIFFICULTY` since the Paris upgrade.
+    PREVRANDAO,
     /// The eponymous EVM instruction.
     COINBASE,
     /// The eponymous EVM instruction.
@@ -354,7 +358,15 @@ pub enum Name {
 
 impl From<Name> for Instruction {
     fn from(name: Name) -> Self {
-        Self { name, value: None }
+        Self {
+            name,
+            value: None,
+            source: None,
+            begin: None,
+            end: None,
+            jump_type: None,
+            modifier_depth: None,
+        }
     }
 }
 
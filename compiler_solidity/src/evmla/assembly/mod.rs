@@ -39,6 +39,9 @@ pub struct Assembly {
     /// The factory dependency paths.
     #[serde(skip)]
     pub factory_dependencies: HashSet<String>,
+    /// The code type the Ethereal IR dump is restricted to, if only one is requested.
+    #[serde(skip)]
+    pub ethir_dump_code_type: Option<compiler_llvm_context::CodeType>,
 }
 
 impl Assembly {
@@ -237,7 +240,17 @@ where
         blocks.extend(runtime_code_blocks);
         let mut ethereal_ir = EtherealIR::new(context.evmla().version.to_owned(), blocks)?;
         if context.has_dump_flag(compiler_llvm_context::DumpFlag::EthIR) {
-            println!("Contract `{}` Ethereal IR:\n\n{}", full_path, ethereal_ir);
+            let code_type = match self.ethir_dump_code_type {
+                Some(compiler_llvm_context::CodeType::Deploy) => "deploy ",
+                Some(compiler_llvm_context::CodeType::Runtime) => "runtime ",
+                None => "",
+            };
+            println!(
+                "Contract `{}` {}Ethereal IR:\n\n{}",
+                full_path,
+                code_type,
+                ethereal_ir.to_string_filtered(self.ethir_dump_code_type)
+            );
         }
         ethereal_ir.declare(context)?;
         ethereal_ir.into_llvm(context)?;
@@ -6,9 +6,11 @@ pub mod stack;
 
 use inkwell::values::BasicValue;
 
+use crate::build::coverage::Coverage;
 use crate::evmla::assembly::instruction::codecopy;
 use crate::evmla::assembly::instruction::name::Name as InstructionName;
 use crate::evmla::assembly::instruction::Instruction;
+use crate::lowering::return_data;
 
 use self::stack::Stack;
 
@@ -37,6 +39,40 @@ impl Element {
         }
     }
 
+    ///
+    /// Returns the number of input stack arguments of the instruction.
+    ///
+    /// The element's own `solc` version is used everywhere, so the arity cannot diverge
+    /// between the LLVM IR generation and the stack rendering.
+    ///
+    pub fn input_size(&self) -> usize {
+        self.instruction.input_size(&self.solc_version)
+    }
+
+    ///
+    /// Takes the instruction value, returning an error naming the instruction if it is missing.
+    ///
+    fn take_value(&mut self) -> anyhow::Result<String> {
+        self.instruction.value.take().ok_or_else(|| {
+            anyhow::anyhow!("Instruction `{:?}` value missing", self.instruction.name)
+        })
+    }
+
+    ///
+    /// Returns the error of the instruction not supported in the runtime code, which is the same
+    /// as in the Yul pipeline.
+    ///
+    fn runtime_code_error(&self) -> anyhow::Error {
+        let message = format!(
+            "The `{:?}` instruction is not supported in the runtime code",
+            self.instruction.name
+        );
+        match self.instruction.location() {
+            Some(location) => anyhow::anyhow!("{} {}", location, message),
+            None => anyhow::anyhow!("{}", message),
+        }
+    }
+
     ///
     /// Pops the specified number of arguments, converted into their LLVM values.
     ///
@@ -47,7 +83,7 @@ impl Element {
     where
         D: compiler_llvm_context::Dependency,
     {
-        let input_size = self.instruction.input_size(&context.evmla().version);
+        let input_size = self.input_size();
         let mut arguments = Vec::with_capacity(input_size);
         for index in 0..input_size {
             let pointer = context.evmla().stack
@@ -70,7 +106,7 @@ impl Element {
     where
         D: compiler_llvm_context::Dependency,
     {
-        let input_size = self.instruction.input_size(&context.evmla().version);
+        let input_size = self.input_size();
         let mut arguments = Vec::with_capacity(input_size);
         for index in 0..input_size {
             let argument = context.evmla().stack
@@ -90,8 +126,11 @@ where
         mut self,
         context: &mut compiler_llvm_context::Context<'_, D>,
     ) -> anyhow::Result<()> {
-        let input_size = self.instruction.input_size(&context.evmla().version);
+        let input_size = self.input_size();
         let mut original = self.instruction.value.clone();
+        if self.instruction.name != InstructionName::Tag {
+            Coverage::record_evmla(&self.instruction.name);
+        }
 
         let value = match self.instruction.name {
             InstructionName::PUSH
@@ -126,35 +165,20 @@ where
             | InstructionName::PUSH29
             | InstructionName::PUSH30
             | InstructionName::PUSH31
-            | InstructionName::PUSH32 => crate::evmla::assembly::instruction::stack::push(
-                context,
-                self.instruction
-                    .value
-                    .ok_or_else(|| anyhow::anyhow!("Instruction value missing"))?,
-            ),
-            InstructionName::PUSH_Tag => crate::evmla::assembly::instruction::stack::push_tag(
-                context,
-                self.instruction
-                    .value
-                    .ok_or_else(|| anyhow::anyhow!("Instruction value missing"))?,
-            ),
-            InstructionName::PUSH_ContractHash => compiler_llvm_context::create::contract_hash(
-                context,
-                self.instruction
-                    .value
-                    .ok_or_else(|| anyhow::anyhow!("Instruction value missing"))?,
-            ),
-            InstructionName::PUSH_ContractHashSize => compiler_llvm_context::create::header_size(
-                context,
-                self.instruction
-                    .value
-                    .ok_or_else(|| anyhow::anyhow!("Instruction value missing"))?,
-            ),
+            | InstructionName::PUSH32 => {
+                crate::evmla::assembly::instruction::stack::push(context, self.take_value()?)
+            }
+            InstructionName::PUSH_Tag => {
+                crate::evmla::assembly::instruction::stack::push_tag(context, self.take_value()?)
+            }
+            InstructionName::PUSH_ContractHash => {
+                compiler_llvm_context::create::contract_hash(context, self.take_value()?)
+            }
+            InstructionName::PUSH_ContractHashSize => {
+                compiler_llvm_context::create::header_size(context, self.take_value()?)
+            }
             InstructionName::PUSHLIB => {
-                let path = self
-                    .instruction
-                    .value
-                    .ok_or_else(|| anyhow::anyhow!("Instruction value missing"))?;
+                let path = self.take_value()?;
 
                 Ok(Some(
                     context
@@ -163,10 +187,7 @@ where
                 ))
             }
             InstructionName::PUSH_Data => {
-                let value = self
-                    .instruction
-                    .value
-                    .ok_or_else(|| anyhow::anyhow!("Instruction value missing"))?;
+                let value = self.take_value()?;
 
                 if value.len() > compiler_common::SIZE_FIELD * 2 {
                     Ok(Some(context.field_const(0).as_basic_value_enum()))
@@ -660,10 +681,7 @@ where
                 )
             }
             InstructionName::PUSHIMMUTABLE => {
-                let key = self
-                    .instruction
-                    .value
-                    .ok_or_else(|| anyhow::anyhow!("Instruction value missing"))?;
+                let key = self.take_value()?;
 
                 let offset = context
                     .solidity_mut()
@@ -675,10 +693,7 @@ where
             InstructionName::ASSIGNIMMUTABLE => {
                 let mut arguments = self.pop_arguments_llvm(context);
 
-                let key = self
-                    .instruction
-                    .value
-                    .ok_or_else(|| anyhow::anyhow!("Instruction value missing"))?;
+                let key = self.take_value()?;
 
                 let offset = context.solidity_mut().allocate_immutable(key.as_str());
 
@@ -701,10 +716,14 @@ where
                     arguments[2].into_int_value(),
                 )
             }
-            InstructionName::CODESIZE => compiler_llvm_context::calldata::size(context),
+            InstructionName::CODESIZE => {
+                if let compiler_llvm_context::CodeType::Runtime = context.code_type() {
+                    return Err(self.runtime_code_error());
+                }
+                compiler_llvm_context::calldata::size(context)
+            }
             InstructionName::CODECOPY => {
-                let mut arguments =
-                    Vec::with_capacity(self.instruction.input_size(&self.solc_version));
+                let mut arguments = Vec::with_capacity(self.input_size());
                 let arguments_with_original = self.pop_arguments(context);
                 for (index, argument) in arguments_with_original.iter().enumerate() {
                     let pointer = argument.value.into_pointer_value();
@@ -744,13 +763,18 @@ where
                     }
                     Some(_source) => {
                         if let compiler_llvm_context::CodeType::Runtime = context.code_type() {
-                            anyhow::bail!(
-                                "The `CODECOPY` instruction is not supported in the runtime code"
-                            );
+                            return Err(self.runtime_code_error());
                         }
                         Ok(None)
                     }
 
+                    None if matches!(
+                        context.code_type(),
+                        compiler_llvm_context::CodeType::Runtime
+                    ) =>
+                    {
+                        Err(self.runtime_code_error())
+                    }
                     None => compiler_llvm_context::calldata::copy(
                         context,
                         arguments[0].into_int_value(),
@@ -770,6 +794,10 @@ where
                     arguments[2].into_int_value(),
                 )
             }
+            InstructionName::RETURNDATALOAD => {
+                let arguments = self.pop_arguments_llvm(context);
+                return_data::load(context, arguments[0].into_int_value())
+            }
             InstructionName::EXTCODESIZE => {
                 let arguments = self.pop_arguments_llvm(context);
                 compiler_llvm_context::ext_code::size(context, arguments[0].into_int_value())
@@ -1021,7 +1049,7 @@ where
 
                 compiler_llvm_context::contract_context::block_hash(context, index)
             }
-            InstructionName::DIFFICULTY => {
+            InstructionName::DIFFICULTY | InstructionName::PREVRANDAO => {
                 compiler_llvm_context::contract_context::difficulty(context)
             }
             InstructionName::COINBASE => compiler_llvm_context::contract_context::coinbase(context),
@@ -1054,7 +1082,7 @@ where
 
 impl std::fmt::Display for Element {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let input_size = self.instruction.input_size(&self.solc_version);
+        let input_size = self.input_size();
         let output_size = self.instruction.output_size();
 
         let mut stack = self.stack.to_owned();
@@ -1083,3 +1111,274 @@ impl std::fmt::Display for Element {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::evmla::assembly::instruction::Instruction;
+    use crate::solc::pipeline::Pipeline as SolcPipeline;
+    use crate::solc::standard_json::output::Output as SolcStandardJsonOutput;
+
+    use super::Element;
+
+    #[test]
+    fn input_size_version() {
+        let instruction: Instruction =
+            serde_json::from_str(r#"{ "name": "ASSIGNIMMUTABLE", "value": "value" }"#)
+                .expect("Always valid");
+        for (version, expected) in [
+            (semver::Version::new(0, 7, 6), 1),
+            (semver::Version::new(0, 8, 17), 2),
+        ] {
+            let element = Element::new(version.clone(), instruction.clone());
+            assert_eq!(element.input_size(), expected);
+            assert_eq!(element.input_size(), instruction.input_size(&version));
+        }
+    }
+
+    #[test]
+    fn error_runtime_codecopy() {
+        let mut output: SolcStandardJsonOutput = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Test": {
+                "evm": {
+                    "legacyAssembly": {
+                        ".code": [
+                            { "name": "PUSH", "value": "0" },
+                            { "name": "DUP1" },
+                            { "name": "RETURN" }
+                        ],
+                        ".data": {
+                            "0": {
+                                ".code": [
+                                    { "name": "PUSH", "value": "20" },
+                                    { "name": "CALLDATASIZE" },
+                                    { "name": "PUSH", "value": "0" },
+                                    { "name": "CODECOPY", "begin": 10, "end": 30, "source": 0 },
+                                    { "name": "STOP" }
+                                ]
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+        let project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::EVM,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let error = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect_err("Must be an error");
+        assert!(error
+            .to_string()
+            .contains("10:20:0 The `CODECOPY` instruction is not supported in the runtime code"));
+    }
+
+    #[test]
+    fn error_runtime_codesize() {
+        let mut output: SolcStandardJsonOutput = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Test": {
+                "evm": {
+                    "legacyAssembly": {
+                        ".code": [
+                            { "name": "PUSH", "value": "0" },
+                            { "name": "DUP1" },
+                            { "name": "RETURN" }
+                        ],
+                        ".data": {
+                            "0": {
+                                ".code": [
+                                    { "name": "CODESIZE", "begin": 10, "end": 30, "source": 0 },
+                                    { "name": "POP" },
+                                    { "name": "STOP" }
+                                ]
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+        let project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::EVM,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let error = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect_err("Must be an error");
+        assert!(error
+            .to_string()
+            .contains("10:20:0 The `CODESIZE` instruction is not supported in the runtime code"));
+    }
+
+    #[test]
+    fn prevrandao_lowered_as_difficulty() {
+        let bytecodes: Vec<Vec<u8>> = ["DIFFICULTY", "PREVRANDAO"]
+            .into_iter()
+            .map(|name| {
+                let mut output: SolcStandardJsonOutput = serde_json::from_str(
+                    format!(
+                        r#"{{
+    "contracts": {{
+        "test.sol": {{
+            "Test": {{
+                "evm": {{
+                    "legacyAssembly": {{
+                        ".code": [
+                            {{ "name": "PUSH", "value": "0" }},
+                            {{ "name": "DUP1" }},
+                            {{ "name": "RETURN" }}
+                        ],
+                        ".data": {{
+                            "0": {{
+                                ".code": [
+                                    {{ "name": "{}" }},
+                                    {{ "name": "PUSH", "value": "0" }},
+                                    {{ "name": "MSTORE" }},
+                                    {{ "name": "PUSH", "value": "20" }},
+                                    {{ "name": "PUSH", "value": "0" }},
+                                    {{ "name": "RETURN" }}
+                                ]
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+        }}
+    }}
+}}"#,
+                        name
+                    )
+                    .as_str(),
+                )
+                .expect("Always valid");
+                let project = output
+                    .try_to_project(
+                        BTreeMap::new(),
+                        SolcPipeline::EVM,
+                        &semver::Version::new(0, 8, 17),
+                        &[],
+                    )
+                    .expect("Always valid");
+
+                let mut build = project
+                    .compile_test(compiler_llvm_context::OptimizerSettings::none())
+                    .expect("Always valid");
+                build
+                    .contracts
+                    .remove("test.sol:Test")
+                    .expect("Always exists")
+                    .build
+                    .bytecode
+            })
+            .collect();
+        assert_eq!(bytecodes[0], bytecodes[1]);
+    }
+
+    fn runtime_bytecode(runtime: &str) -> Vec<u8> {
+        let mut output: SolcStandardJsonOutput = serde_json::from_str(
+            format!(
+                r#"{{
+    "contracts": {{
+        "test.sol": {{
+            "Test": {{
+                "evm": {{
+                    "legacyAssembly": {{
+                        ".code": [
+                            {{ "name": "PUSH", "value": "0" }},
+                            {{ "name": "DUP1" }},
+                            {{ "name": "RETURN" }}
+                        ],
+                        ".data": {{
+                            "0": {{
+                                ".code": [
+                                    {{ "name": "PUSH", "value": "2A" }},
+                                    {{ "name": "PUSH", "value": "0" }},
+                                    {{ "name": "MSTORE" }},
+                                    {{ "name": "PUSH", "value": "0" }},
+                                    {{ "name": "CALLDATALOAD" }},
+                                    {},
+                                    {{ "name": "PUSH", "value": "20" }},
+                                    {{ "name": "MSTORE" }},
+                                    {{ "name": "PUSH", "value": "40" }},
+                                    {{ "name": "PUSH", "value": "0" }},
+                                    {{ "name": "RETURN" }}
+                                ]
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+        }}
+    }}
+}}"#,
+                runtime
+            )
+            .as_str(),
+        )
+        .expect("Always valid");
+        let project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::EVM,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        project
+            .compile_test(compiler_llvm_context::OptimizerSettings::cycles())
+            .expect("Always valid")
+            .contracts
+            .remove("test.sol:Test")
+            .expect("Always exists")
+            .build
+            .bytecode
+    }
+
+    #[test]
+    fn ok_returndataload() {
+        let lowered = runtime_bytecode(r#"{ "name": "RETURNDATALOAD" }"#);
+
+        // The offset is on the top of the stack, and the loaded word replaces it.
+        let expanded = runtime_bytecode(
+            r#"{ "name": "PUSH", "value": "0" },
+                                    { "name": "MLOAD" },
+                                    { "name": "SWAP1" },
+                                    { "name": "PUSH", "value": "20" },
+                                    { "name": "SWAP1" },
+                                    { "name": "PUSH", "value": "0" },
+                                    { "name": "RETURNDATACOPY" },
+                                    { "name": "PUSH", "value": "0" },
+                                    { "name": "MLOAD" },
+                                    { "name": "SWAP1" },
+                                    { "name": "PUSH", "value": "0" },
+                                    { "name": "MSTORE" }"#,
+        );
+        assert_eq!(lowered, expanded);
+    }
+}
@@ -165,6 +165,7 @@ impl Function {
             Instruction {
                 name: InstructionName::PUSH_Tag,
                 value: Some(ref tag),
+                ..
             } => {
                 let tag: num::BigUint = tag.parse().expect("Always valid");
                 block_stack.push(Element::Tag(tag.bitand(num::BigUint::from(u64::MAX))));
@@ -219,6 +220,7 @@ impl Function {
             Instruction {
                 name: InstructionName::Tag,
                 value: Some(ref tag),
+                ..
             } => {
                 block_element.stack = block_stack.clone();
 
@@ -778,6 +780,55 @@ impl Function {
 
         self
     }
+
+    ///
+    /// Returns the string representation with the blocks of `code_type` only, or all blocks if
+    /// `code_type` is `None`.
+    ///
+    pub fn to_string_filtered(&self, code_type: Option<compiler_llvm_context::CodeType>) -> String {
+        let mut string = String::new();
+        self.write_filtered(&mut string, code_type)
+            .expect("Always valid");
+        string
+    }
+
+    ///
+    /// Writes the blocks of `code_type` only, or all blocks if `code_type` is `None`.
+    ///
+    fn write_filtered(
+        &self,
+        f: &mut dyn std::fmt::Write,
+        code_type: Option<compiler_llvm_context::CodeType>,
+    ) -> std::fmt::Result {
+        writeln!(f, "function main (max_sp = {}) {{", self.stack_size,)?;
+        for (key, blocks) in self.blocks.iter() {
+            if code_type.map_or(false, |code_type| key.code_type != code_type) {
+                continue;
+            }
+
+            for (index, block) in blocks.iter().enumerate() {
+                writeln!(
+                    f,
+                    "{:92}{}",
+                    format!(
+                        "block_{}/{}: {}",
+                        key,
+                        index,
+                        if block.predecessors.is_empty() {
+                            "".to_owned()
+                        } else {
+                            format!("(predecessors: {:?})", block.predecessors)
+                        }
+                    ),
+                    block.initial_stack,
+                )?;
+                write!(f, "{}", block)?;
+            }
+        }
+        writeln!(f, "}}")?;
+
+        Ok(())
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Function
@@ -890,29 +941,6 @@ where
 
 impl std::fmt::Display for Function {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "function main (max_sp = {}) {{", self.stack_size,)?;
-        for (key, blocks) in self.blocks.iter() {
-            for (index, block) in blocks.iter().enumerate() {
-                writeln!(
-                    f,
-                    "{:92}{}",
-                    format!(
-                        "block_{}/{}: {}",
-                        key,
-                        index,
-                        if block.predecessors.is_empty() {
-                            "".to_owned()
-                        } else {
-                            format!("(predecessors: {:?})", block.predecessors)
-                        }
-                    ),
-                    block.initial_stack,
-                )?;
-                write!(f, "{}", block)?;
-            }
-        }
-        writeln!(f, "}}")?;
-
-        Ok(())
+        self.write_filtered(f, None)
     }
 }
@@ -56,6 +56,14 @@ impl EtherealIR {
         })
     }
 
+    ///
+    /// Returns the string representation with the blocks of `code_type` only, or all blocks if
+    /// `code_type` is `None`.
+    ///
+    pub fn to_string_filtered(&self, code_type: Option<compiler_llvm_context::CodeType>) -> String {
+        format!("{}\n", self.function.to_string_filtered(code_type))
+    }
+
     ///
     /// Gets blocks for the specified type of the contract code.
     ///
@@ -110,3 +118,62 @@ impl std::fmt::Display for EtherealIR {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::evmla::assembly::instruction::Instruction;
+
+    use super::EtherealIR;
+
+    #[test]
+    fn runtime_only_string() {
+        let version = semver::Version::new(0, 8, 17);
+        let deploy: Vec<Instruction> = serde_json::from_str(
+            r#"[
+    { "name": "CALLVALUE" },
+    { "name": "PUSH", "value": "0" },
+    { "name": "RETURN" }
+]"#,
+        )
+        .expect("Always valid");
+        let runtime: Vec<Instruction> = serde_json::from_str(
+            r#"[
+    { "name": "CALLDATASIZE" },
+    { "name": "PUSH", "value": "0" },
+    { "name": "RETURN" }
+]"#,
+        )
+        .expect("Always valid");
+
+        let mut blocks = EtherealIR::get_blocks(
+            version.clone(),
+            compiler_llvm_context::CodeType::Deploy,
+            deploy.as_slice(),
+        )
+        .expect("Always valid");
+        blocks.extend(
+            EtherealIR::get_blocks(
+                version.clone(),
+                compiler_llvm_context::CodeType::Runtime,
+                runtime.as_slice(),
+            )
+            .expect("Always valid"),
+        );
+        let ethereal_ir = EtherealIR::new(version, blocks).expect("Always valid");
+
+        let runtime_only =
+            ethereal_ir.to_string_filtered(Some(compiler_llvm_context::CodeType::Runtime));
+        assert!(runtime_only.contains("CALLDATASIZE"));
+        assert!(!runtime_only.contains("CALLVALUE"));
+
+        let deploy_only =
+            ethereal_ir.to_string_filtered(Some(compiler_llvm_context::CodeType::Deploy));
+        assert!(deploy_only.contains("CALLVALUE"));
+        assert!(!deploy_only.contains("CALLDATASIZE"));
+
+        assert_eq!(
+            ethereal_ir.to_string_filtered(None),
+            ethereal_ir.to_string()
+        );
+    }
+}
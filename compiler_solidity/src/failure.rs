@@ -0,0 +1,188 @@
+//!
+//! The compiler failure category.
+//!
+
+use crate::yul::error::Error as YulError;
+
+///
+/// The compiler failure category, which determines the exit code of the binary.
+///
+/// The errors which are not tagged with a category, and do not contain a known error type
+/// in their chain, are reported with the generic failure exit code.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure {
+    /// The Yul, EVM legacy assembly, or JSON parsing failure, including the Yul semantic errors.
+    Parsing,
+    /// The LLVM IR generation or code generation failure.
+    LLVM,
+    /// The `solc` invocation failure.
+    Solc,
+    /// The file system or standard stream failure.
+    IO,
+}
+
+impl Failure {
+    /// The parsing failure exit code.
+    pub const EXIT_CODE_PARSING: i32 = 2;
+
+    /// The LLVM failure exit code.
+    pub const EXIT_CODE_LLVM: i32 = 3;
+
+    /// The `solc` invocation failure exit code.
+    pub const EXIT_CODE_SOLC: i32 = 4;
+
+    /// The I/O failure exit code.
+    pub const EXIT_CODE_IO: i32 = 5;
+
+    ///
+    /// Returns the exit code of the failure category.
+    ///
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Parsing => Self::EXIT_CODE_PARSING,
+            Self::LLVM => Self::EXIT_CODE_LLVM,
+            Self::Solc => Self::EXIT_CODE_SOLC,
+            Self::IO => Self::EXIT_CODE_IO,
+        }
+    }
+
+    ///
+    /// Tags the error with the failure category, leaving its message intact.
+    ///
+    pub fn wrap(self, error: anyhow::Error) -> anyhow::Error {
+        anyhow::Error::new(Tagged {
+            failure: self,
+            error,
+        })
+    }
+
+    ///
+    /// Tags the error with the category of its `cause`, or with `self` if the cause is not
+    /// categorized.
+    ///
+    /// It is used where the cause is formatted into a new error message, which drops the chain.
+    ///
+    pub fn wrap_caused(self, error: anyhow::Error, cause: &anyhow::Error) -> anyhow::Error {
+        Self::of(cause).unwrap_or(self).wrap(error)
+    }
+
+    ///
+    /// Returns the failure category of the error.
+    ///
+    /// The outermost tag takes precedence. The untagged errors caused by an I/O, JSON, or Yul
+    /// source error are categorized by the cause.
+    ///
+    pub fn of(error: &anyhow::Error) -> Option<Self> {
+        error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<Tagged>().map(|tagged| tagged.failure))
+            .or_else(|| {
+                error.chain().find_map(|cause| {
+                    if cause.is::<std::io::Error>() {
+                        Some(Self::IO)
+                    } else if cause.is::<serde_json::Error>() || cause.is::<YulError>() {
+                        Some(Self::Parsing)
+                    } else {
+                        None
+                    }
+                })
+            })
+    }
+}
+
+///
+/// The error tagged with its failure category.
+///
+#[derive(Debug)]
+struct Tagged {
+    /// The failure category.
+    failure: Failure,
+    /// The original error.
+    error: anyhow::Error,
+}
+
+impl std::fmt::Display for Tagged {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl std::error::Error for Tagged {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Failure;
+
+    #[test]
+    fn exit_codes() {
+        for (failure, exit_code) in [
+            (Failure::Parsing, Failure::EXIT_CODE_PARSING),
+            (Failure::LLVM, Failure::EXIT_CODE_LLVM),
+            (Failure::Solc, Failure::EXIT_CODE_SOLC),
+            (Failure::IO, Failure::EXIT_CODE_IO),
+        ] {
+            let error = failure.wrap(anyhow::anyhow!("Failed"));
+            assert_eq!(error.to_string(), "Failed");
+            assert_eq!(
+                Failure::of(&error).map(|failure| failure.exit_code()),
+                Some(exit_code)
+            );
+        }
+
+        let exit_codes = [
+            Failure::EXIT_CODE_PARSING,
+            Failure::EXIT_CODE_LLVM,
+            Failure::EXIT_CODE_SOLC,
+            Failure::EXIT_CODE_IO,
+        ];
+        assert!(!exit_codes.contains(&compiler_common::EXIT_CODE_SUCCESS));
+        assert!(!exit_codes.contains(&compiler_common::EXIT_CODE_FAILURE));
+    }
+
+    #[test]
+    fn untagged_causes() {
+        let error = anyhow::Error::from(std::io::Error::from(std::io::ErrorKind::NotFound));
+        assert_eq!(Failure::of(&error), Some(Failure::IO));
+
+        let error = anyhow::Error::from(
+            serde_json::from_str::<serde_json::Value>("{").expect_err("Always invalid"),
+        );
+        assert_eq!(Failure::of(&error), Some(Failure::Parsing));
+
+        let error = anyhow::Error::from(crate::yul::error::Error::UnknownBuiltin {
+            location: crate::yul::lexer::token::location::Location::new(1, 1),
+            name: "undeclared_function".to_owned(),
+            is_unsupported: false,
+        });
+        assert_eq!(Failure::of(&error), Some(Failure::Parsing));
+
+        assert_eq!(Failure::of(&anyhow::anyhow!("Failed")), None);
+    }
+
+    #[test]
+    fn caused_tag() {
+        let cause = Failure::Parsing.wrap(anyhow::anyhow!("Failed"));
+        let error = Failure::LLVM.wrap_caused(anyhow::anyhow!("Wrapped: {}", cause), &cause);
+        assert_eq!(Failure::of(&error), Some(Failure::Parsing));
+
+        let cause = anyhow::anyhow!("Failed");
+        let error = Failure::LLVM.wrap_caused(anyhow::anyhow!("Wrapped: {}", cause), &cause);
+        assert_eq!(Failure::of(&error), Some(Failure::LLVM));
+    }
+
+    #[test]
+    fn outermost_tag() {
+        let error = Failure::LLVM.wrap(Failure::IO.wrap(anyhow::anyhow!("Failed")));
+        assert_eq!(Failure::of(&error), Some(Failure::LLVM));
+
+        let error = Failure::Parsing.wrap(anyhow::Error::from(std::io::Error::from(
+            std::io::ErrorKind::NotFound,
+        )));
+        assert_eq!(Failure::of(&error), Some(Failure::Parsing));
+    }
+}
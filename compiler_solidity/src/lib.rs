@@ -2,17 +2,23 @@
 //! Solidity to zkEVM compiler library.
 //!
 
+pub(crate) mod abi;
 pub(crate) mod build;
 pub(crate) mod r#const;
 pub(crate) mod dump_flag;
 pub(crate) mod evmla;
+pub(crate) mod failure;
+pub(crate) mod linker;
+pub(crate) mod lowering;
 pub(crate) mod project;
 pub(crate) mod solc;
 pub(crate) mod yul;
 
+pub use self::abi::selectors as abi_selectors;
 pub use self::build::contract::Contract as ContractBuild;
 pub use self::build::Build;
 pub use self::dump_flag::DumpFlag;
+pub use self::failure::Failure;
 pub use self::project::contract::state::State as ContractState;
 pub use self::project::contract::Contract as ProjectContract;
 pub use self::project::Project;
@@ -20,6 +26,7 @@ pub use self::solc::combined_json::contract::Contract as SolcCombinedJsonContrac
 pub use self::solc::combined_json::CombinedJson as SolcCombinedJson;
 pub use self::solc::pipeline::Pipeline as SolcPipeline;
 pub use self::solc::standard_json::input::language::Language as SolcStandardJsonInputLanguage;
+pub use self::solc::standard_json::input::settings::optimizer::Optimizer as SolcStandardJsonInputSettingsOptimizer;
 pub use self::solc::standard_json::input::settings::selection::Selection as SolcStandardJsonInputSettingsSelection;
 pub use self::solc::standard_json::input::settings::Settings as SolcStandardJsonInputSettings;
 pub use self::solc::standard_json::input::source::Source as SolcStandardJsonInputSource;
@@ -27,6 +34,11 @@ pub use self::solc::standard_json::input::Input as SolcStandardJsonInput;
 pub use self::solc::standard_json::output::contract::evm::bytecode::Bytecode as SolcStandardJsonOutputContractEVMBytecode;
 pub use self::solc::standard_json::output::contract::evm::EVM as SolcStandardJsonOutputContractEVM;
 pub use self::solc::standard_json::output::contract::Contract as SolcStandardJsonOutputContract;
+pub use self::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
 pub use self::solc::standard_json::output::Output as SolcStandardJsonOutput;
 pub use self::solc::version::Version as SolcVersion;
 pub use self::solc::Compiler as SolcCompiler;
+pub use self::yul::error::Error as YulError;
+pub use self::yul::lexer::error::Error as YulLexerError;
+pub use self::yul::parser::error::Error as YulParserError;
+pub use self::yul::parser::statement::expression::function_call::verbatim_globals::VerbatimGlobals as YulVerbatimGlobals;
@@ -0,0 +1,152 @@
+//!
+//! The library linker.
+//!
+
+use std::collections::BTreeMap;
+
+/// The library address hexadecimal representation length.
+pub const ADDRESS_HEX_LENGTH: usize = 40;
+
+///
+/// Returns the address the unlinked library with the fully qualified `path` is compiled with.
+///
+/// The address is derived from the library path hash, so it can be found in the bytecode and
+/// reported in the link references.
+///
+pub(crate) fn placeholder_address(path: &str) -> String {
+    let hash = compiler_llvm_context::keccak256(path.as_bytes());
+    format!("0x{}", &hash[..ADDRESS_HEX_LENGTH])
+}
+
+///
+/// Returns the byte offsets of the placeholder addresses of the unlinked libraries with the
+/// fully qualified `paths` in the bytecode.
+///
+/// The libraries not referenced in the bytecode are omitted.
+///
+pub(crate) fn link_references<'a, I>(bytecode: &[u8], paths: I) -> BTreeMap<String, Vec<usize>>
+where
+    I: IntoIterator<Item = &'a String>,
+{
+    let mut references = BTreeMap::new();
+    for path in paths.into_iter() {
+        let address = hex::decode(&placeholder_address(path)["0x".len()..]).expect("Always valid");
+        let offsets: Vec<usize> = bytecode
+            .windows(address.len())
+            .enumerate()
+            .filter(|(_offset, window)| *window == address.as_slice())
+            .map(|(offset, _window)| offset)
+            .collect();
+        if !offsets.is_empty() {
+            references.insert(path.to_owned(), offsets);
+        }
+    }
+    references
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::build::contract::Contract as ContractBuild;
+    use crate::build::Build;
+    use crate::project::Project;
+
+    const LIBRARY_ADDRESS: &str = "0x1234567890abcdef1234567890abcdef12345678";
+
+    const INPUT: &str = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                mstore(0, linkersymbol("test.sol:Library"))
+                return(0, 32)
+            }
+        }
+    }
+}
+    "#;
+
+    fn libraries(address: &str) -> BTreeMap<String, BTreeMap<String, String>> {
+        let mut contracts = BTreeMap::new();
+        contracts.insert("Library".to_owned(), address.to_owned());
+        let mut libraries = BTreeMap::new();
+        libraries.insert("test.sol".to_owned(), contracts);
+        libraries
+    }
+
+    fn try_build_project(
+        libraries: BTreeMap<String, BTreeMap<String, String>>,
+        allow_unlinked_libraries: bool,
+    ) -> anyhow::Result<Build> {
+        let mut project = Project::try_from_test_yul(INPUT, &semver::Version::new(0, 8, 17))
+            .expect("Always valid");
+        project.libraries = libraries;
+        project.allow_unlinked_libraries = allow_unlinked_libraries;
+
+        project.compile_test(compiler_llvm_context::OptimizerSettings::none())
+    }
+
+    fn build_project(libraries: BTreeMap<String, BTreeMap<String, String>>) -> Build {
+        try_build_project(libraries, true).expect("Always valid")
+    }
+
+    fn build(libraries: BTreeMap<String, BTreeMap<String, String>>) -> ContractBuild {
+        build_project(libraries)
+            .contracts
+            .remove("Test")
+            .expect("Always exists")
+    }
+
+    #[test]
+    fn link_references_unlinked() {
+        let mut build = build_project(BTreeMap::new());
+        assert_eq!(build.warnings.len(), 1);
+        assert!(build.warnings[0].message.contains("test.sol:Library"));
+
+        let build = build.contracts.remove("Test").expect("Always exists");
+        let offsets = build
+            .link_references
+            .get("test.sol:Library")
+            .expect("Always exists");
+        assert!(!offsets.is_empty());
+
+        let address = hex::decode(&super::placeholder_address("test.sol:Library")["0x".len()..])
+            .expect("Always valid");
+        for offset in offsets.iter() {
+            assert_eq!(
+                &build.bytecode()[*offset..*offset + address.len()],
+                address.as_slice()
+            );
+        }
+    }
+
+    #[test]
+    fn unlinked_library_error() {
+        let error =
+            try_build_project(BTreeMap::new(), false).expect_err("The library is not linked");
+        let error = format!("{:?}", error);
+        assert!(error.contains("Library `test.sol:Library` not found in the project"));
+    }
+
+    #[test]
+    fn link_references_linked() {
+        let build = build(libraries(LIBRARY_ADDRESS));
+        assert!(build.link_references.is_empty());
+    }
+
+    #[test]
+    fn warning_zero_library_address() {
+        let build = build_project(libraries("0x0000000000000000000000000000000000000000"));
+        assert_eq!(build.warnings.len(), 1);
+        assert!(build.warnings[0].message.contains("test.sol:Library"));
+
+        let build = build_project(libraries(LIBRARY_ADDRESS));
+        assert!(build.warnings.is_empty());
+    }
+}
@@ -0,0 +1,5 @@
+//!
+//! The LLVM IR lowering shared by the Yul and EVM legacy assembly pipelines.
+//!
+
+pub mod return_data;
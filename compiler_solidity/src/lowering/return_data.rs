@@ -0,0 +1,91 @@
+//!
+//! Translates the return data instructions missing in the LLVM context.
+//!
+
+///
+/// Translates the return data word loading.
+///
+/// The word is copied to the scratch space at the memory offset `0` and loaded from there. The
+/// original scratch space word is restored afterwards, so the memory is not affected.
+///
+pub fn load<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    offset: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let scratch = context.field_const(0);
+    let original = compiler_llvm_context::memory::load(context, scratch)?.expect("Always exists");
+
+    compiler_llvm_context::return_data::copy(
+        context,
+        scratch,
+        offset,
+        context.field_const(compiler_common::SIZE_FIELD as u64),
+    )?;
+    let value = compiler_llvm_context::memory::load(context, scratch)?;
+
+    compiler_llvm_context::memory::store(context, scratch, original.into_int_value())?;
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::project::Project;
+
+    fn bytecode(runtime: &str) -> Vec<u8> {
+        let input = format!(
+            r#"
+object "Test" {{
+    code {{
+        {{
+            return(0, 0)
+        }}
+    }}
+    object "Test_deployed" {{
+        code {{
+            {{
+                pop(call(gas(), 0x1234, 0, 0, 0, 0, 0))
+                mstore(0, 42)
+                {}
+                return(0, 64)
+            }}
+        }}
+    }}
+}}
+    "#,
+            runtime
+        );
+        Project::try_from_test_yul(input.as_str(), &semver::Version::new(0, 8, 17))
+            .expect("Always valid")
+            .compile_test(compiler_llvm_context::OptimizerSettings::cycles())
+            .expect("Always valid")
+            .contracts
+            .remove("Test")
+            .expect("Always exists")
+            .build
+            .bytecode
+    }
+
+    #[test]
+    fn load() {
+        let lowered = bytecode("mstore(32, returndataload(calldataload(0)))");
+        let expanded = bytecode(
+            r#"let saved := mload(0)
+                returndatacopy(0, calldataload(0), 32)
+                let value := mload(0)
+                mstore(0, saved)
+                mstore(32, value)"#,
+        );
+        assert_eq!(lowered, expanded);
+
+        // The scratch space is restored, so the word stored there before is returned as is.
+        let clobbered = bytecode(
+            r#"returndatacopy(0, calldataload(0), 32)
+                mstore(32, mload(0))"#,
+        );
+        assert_ne!(lowered, clobbered);
+    }
+}
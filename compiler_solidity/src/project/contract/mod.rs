@@ -8,14 +8,19 @@ pub mod state;
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::RwLock;
+use std::time::Duration;
+use std::time::Instant;
 
 use compiler_llvm_context::WriteLLVM;
 
+use crate::build::coverage::Coverage;
+use crate::build::ir_stats::IRStats;
 use crate::dump_flag::DumpFlag;
+use crate::failure::Failure;
 use crate::project::Project;
+use crate::yul::parser::statement::expression::function_call::verbatim_settings::VerbatimSettings;
 
 use self::source::Source;
-use self::state::State;
 
 ///
 /// The contract data representation.
@@ -64,18 +69,29 @@ impl Contract {
     ///
     /// Compiles the specified contract, setting its build artifacts.
     ///
+    /// Returns the timings of the compilation passes along with the build, and the LLVM bitcode
+    /// of the module before optimization if it has been requested.
+    ///
     pub fn compile(
         mut self,
         project: Arc<RwLock<Project>>,
         target_machine: compiler_llvm_context::TargetMachine,
         optimizer_settings: compiler_llvm_context::OptimizerSettings,
         dump_flags: Vec<DumpFlag>,
-    ) -> anyhow::Result<compiler_llvm_context::Build> {
+    ) -> anyhow::Result<(
+        compiler_llvm_context::Build,
+        Vec<(String, Duration)>,
+        Option<Vec<u8>>,
+        Option<IRStats>,
+        Option<Coverage>,
+    )> {
         let llvm = inkwell::context::Context::create();
         let optimizer = compiler_llvm_context::Optimizer::new(target_machine, optimizer_settings);
+        let ethir_dump_code_type = DumpFlag::ethir_code_type(dump_flags.as_slice());
         let dump_flags = compiler_llvm_context::DumpFlag::initialize(
             dump_flags.contains(&DumpFlag::Yul),
-            dump_flags.contains(&DumpFlag::EthIR),
+            dump_flags.contains(&DumpFlag::EthIRDeploy)
+                || dump_flags.contains(&DumpFlag::EthIRRuntime),
             dump_flags.contains(&DumpFlag::EVM),
             false,
             dump_flags.contains(&DumpFlag::LLVM),
@@ -88,57 +104,176 @@ impl Contract {
             Some(project.clone()),
             dump_flags,
         );
+        Self::check_field_type(&context).map_err(|error| Failure::LLVM.wrap(error))?;
+        if let Some(data_layout) = project.read().expect("Sync").data_layout.as_deref() {
+            let target_data = inkwell::targets::TargetData::create(data_layout);
+            context
+                .module()
+                .set_data_layout(&target_data.get_data_layout());
+        }
         context.set_solidity_data(compiler_llvm_context::ContextSolidityData::default());
-        if let Source::EVM(_) = self.source {
+        if let Source::EVM(ref mut evm) = self.source {
             let version = project.read().expect("Sync").version.to_owned();
             let evmla_data = compiler_llvm_context::ContextEVMLAData::new(version);
             context.set_evmla_data(evmla_data);
+            evm.assembly.ethir_dump_code_type = ethir_dump_code_type;
         }
 
         let factory_dependencies = self.drain_factory_dependencies();
+        if let Some(limit) = project.read().expect("Sync").max_factory_dependencies {
+            if factory_dependencies.len() > limit {
+                anyhow::bail!(
+                    "The contract `{}` has {} factory dependencies, exceeding the limit of {}",
+                    self.path,
+                    factory_dependencies.len(),
+                    limit
+                );
+            }
+        }
+        let annotation_source = match self.source {
+            Source::Yul(ref yul) if project.read().expect("Sync").annotate_assembly => {
+                Some(yul.clone())
+            }
+            _ => None,
+        };
 
+        let mut pass_timings = Vec::with_capacity(2);
+
+        let _verbatim_settings = {
+            let project = project.read().expect("Sync");
+            VerbatimSettings::new(
+                project.allow_unknown_verbatim,
+                project.verbatim_globals.clone(),
+            )
+            .enter()
+        };
+        let coverage = if project.read().expect("Sync").emit_coverage {
+            Some(Coverage::record())
+        } else {
+            None
+        };
+        let start = Instant::now();
         self.source.declare(&mut context).map_err(|error| {
-            anyhow::anyhow!(
-                "The contract `{}` LLVM IR generator declaration pass error: {}",
-                self.path,
-                error
+            Failure::LLVM.wrap_caused(
+                anyhow::anyhow!(
+                    "The contract `{}` LLVM IR generator declaration pass error: {}",
+                    self.path,
+                    error
+                ),
+                &error,
             )
         })?;
         self.source.into_llvm(&mut context).map_err(|error| {
-            anyhow::anyhow!(
-                "The contract `{}` LLVM IR generator definition pass error: {}",
-                self.path,
-                error
+            Failure::LLVM.wrap_caused(
+                anyhow::anyhow!(
+                    "The contract `{}` LLVM IR generator definition pass error: {}",
+                    self.path,
+                    error
+                ),
+                &error,
             )
         })?;
+        let coverage = coverage.map(|coverage| coverage.finish());
+        if project.read().expect("Sync").preserve_unused_functions {
+            Self::preserve_functions(&context);
+        }
+        pass_timings.push(("LLVM IR generation".to_owned(), start.elapsed()));
+        let llvm_bitcode = if project.read().expect("Sync").emit_llvm_bc {
+            Some(
+                context
+                    .module()
+                    .write_bitcode_to_memory()
+                    .as_slice()
+                    .to_vec(),
+            )
+        } else {
+            None
+        };
+        let ir_stats = if project.read().expect("Sync").emit_ir_stats {
+            Some(IRStats::new(context.module()))
+        } else {
+            None
+        };
 
-        let mut build = context.build(self.path.as_str())?;
+        let start = Instant::now();
+        let mut build = context
+            .build(self.path.as_str())
+            .map_err(|error| Failure::LLVM.wrap_caused(anyhow::anyhow!("{}", error), &error))?;
+        pass_timings.push((
+            "LLVM optimization and code generation".to_owned(),
+            start.elapsed(),
+        ));
+        if let Some(yul) = annotation_source {
+            build.assembly_text = yul.annotate_assembly(build.assembly_text.as_str());
+        }
         for dependency in factory_dependencies.into_iter() {
-            let full_path = project
+            let (hash, full_path) = project
                 .read()
                 .expect("Sync")
-                .identifier_paths
-                .get(dependency.as_str())
-                .cloned()
-                .unwrap_or_else(|| panic!("Dependency `{}` full path not found", dependency));
-            let hash = match project
-                .read()
-                .expect("Sync")
-                .contract_states
-                .get(full_path.as_str())
-            {
-                Some(State::Build(build)) => build.build.hash.to_owned(),
-                Some(_) => {
-                    panic!("Dependency `{}` must be built at this point", full_path)
-                }
-                None => anyhow::bail!(
-                    "Dependency contract `{}` not found in the project",
-                    full_path
-                ),
-            };
+                .factory_dependency(dependency.as_str())?;
             build.factory_dependencies.insert(hash, full_path);
         }
-        Ok(build)
+        Ok((build, pass_timings, llvm_bitcode, ir_stats, coverage))
+    }
+
+    ///
+    /// Checks that the target field type is exactly `compiler_common::BITLENGTH_FIELD` bits wide.
+    ///
+    /// The opcode lowering silently assumes the 256-bit EVM word, so a target configuration
+    /// with another field width must be rejected before any code is generated.
+    ///
+    pub fn check_field_type<D>(context: &compiler_llvm_context::Context<D>) -> anyhow::Result<()>
+    where
+        D: compiler_llvm_context::Dependency,
+    {
+        let bit_width = context.field_type().get_bit_width() as usize;
+        if bit_width != compiler_common::BITLENGTH_FIELD {
+            anyhow::bail!(
+                "The target field type is {} bits wide, whereas the code generator requires {} bits",
+                bit_width,
+                compiler_common::BITLENGTH_FIELD
+            );
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Appends the private functions defined in the module to `llvm.used`, so the optimizer
+    /// keeps them even if they are never called, which makes them visible in the assembly.
+    ///
+    pub fn preserve_functions<D>(context: &compiler_llvm_context::Context<D>)
+    where
+        D: compiler_llvm_context::Dependency,
+    {
+        let pointer_type = context
+            .integer_type(compiler_common::BITLENGTH_BYTE)
+            .ptr_type(inkwell::AddressSpace::Generic);
+        let pointers: Vec<inkwell::values::PointerValue> = context
+            .module()
+            .get_functions()
+            .filter(|function| {
+                function.count_basic_blocks() > 0
+                    && function.get_linkage() == inkwell::module::Linkage::Private
+            })
+            .map(|function| {
+                function
+                    .as_global_value()
+                    .as_pointer_value()
+                    .const_cast(pointer_type)
+            })
+            .collect();
+        if pointers.is_empty() {
+            return;
+        }
+
+        let initializer = pointer_type.const_array(pointers.as_slice());
+        let used = context
+            .module()
+            .add_global(initializer.get_type(), None, "llvm.used");
+        used.set_linkage(inkwell::module::Linkage::Appending);
+        used.set_section("llvm.metadata");
+        used.set_initializer(&initializer);
     }
 }
 
@@ -154,3 +289,38 @@ where
         self.source.into_llvm(context)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::project::Project;
+    use crate::yul::parser::r#type::Type as YulType;
+
+    use super::Contract;
+
+    #[test]
+    fn field_type_bit_width() {
+        compiler_llvm_context::initialize_target();
+        let llvm = inkwell::context::Context::create();
+        let optimizer_settings = compiler_llvm_context::OptimizerSettings::none();
+        let target_machine =
+            compiler_llvm_context::TargetMachine::new(&optimizer_settings).expect("Always valid");
+        let optimizer = compiler_llvm_context::Optimizer::new(target_machine, optimizer_settings);
+        let context = compiler_llvm_context::Context::<Project>::new(
+            &llvm,
+            "test",
+            optimizer,
+            None,
+            compiler_llvm_context::DumpFlag::initialize(false, false, false, false, false, false),
+        );
+
+        assert_eq!(context.field_type().get_bit_width(), 256);
+        assert!(Contract::check_field_type(&context).is_ok());
+
+        assert_eq!(context.field_const(1).get_type(), context.field_type());
+        assert_eq!(YulType::default().into_llvm(&context), context.field_type());
+        assert_eq!(
+            YulType::Custom("custom".to_owned()).into_llvm(&context),
+            context.field_type()
+        );
+    }
+}
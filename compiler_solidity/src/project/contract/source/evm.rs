@@ -2,7 +2,13 @@
 //! The `solc --standard-json` contract EVM legacy assembly source.
 //!
 
+use std::collections::BTreeSet;
+
+use crate::evmla::assembly::data::Data;
+use crate::evmla::assembly::instruction::name::Name as InstructionName;
 use crate::evmla::assembly::Assembly;
+use crate::solc::source_map::kind::Kind as SourceMapKind;
+use crate::solc::source_map::SourceMap;
 
 ///
 /// The `solc --standard-json` contract EVM legacy assembly source.
@@ -22,6 +28,50 @@ impl EVM {
     pub fn new(assembly: Assembly) -> Self {
         Self { assembly }
     }
+
+    ///
+    /// Returns the deploy and runtime code source maps, with one entry per assembly instruction.
+    ///
+    pub fn source_maps(&self) -> (SourceMap, SourceMap) {
+        let source_map = |assembly: &Assembly| {
+            SourceMap::new(
+                SourceMapKind::EVMLegacyAssembly,
+                assembly
+                    .code
+                    .iter()
+                    .flatten()
+                    .map(|instruction| instruction.source_map_entry())
+                    .collect(),
+            )
+        };
+
+        let deploy = source_map(&self.assembly);
+        let runtime = match self.assembly.data.as_ref().and_then(|data| data.get("0")) {
+            Some(Data::Assembly(assembly)) => source_map(assembly),
+            _ => SourceMap::new(SourceMapKind::EVMLegacyAssembly, vec![]),
+        };
+        (deploy, runtime)
+    }
+
+    ///
+    /// Returns the fully qualified paths of the libraries referenced by `PUSHLIB` in the deploy
+    /// and runtime code.
+    ///
+    pub fn library_references(&self) -> BTreeSet<String> {
+        let mut assemblies = vec![&self.assembly];
+        if let Some(Data::Assembly(assembly)) =
+            self.assembly.data.as_ref().and_then(|data| data.get("0"))
+        {
+            assemblies.push(assembly);
+        }
+
+        assemblies
+            .into_iter()
+            .flat_map(|assembly| assembly.code.iter().flatten())
+            .filter(|instruction| instruction.name == InstructionName::PUSHLIB)
+            .filter_map(|instruction| instruction.value.clone())
+            .collect()
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for EVM
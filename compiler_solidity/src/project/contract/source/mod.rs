@@ -5,7 +5,10 @@
 pub mod evm;
 pub mod yul;
 
+use std::collections::BTreeSet;
+
 use crate::evmla::assembly::Assembly;
+use crate::solc::source_map::SourceMap;
 use crate::yul::parser::statement::object::Object;
 
 use self::evm::EVM;
@@ -38,6 +41,26 @@ impl Source {
     pub fn new_evm(assembly: Assembly) -> Self {
         Self::EVM(EVM::new(assembly))
     }
+
+    ///
+    /// Returns the deploy and runtime code source maps.
+    ///
+    pub fn source_maps(&self) -> (SourceMap, SourceMap) {
+        match self {
+            Self::Yul(inner) => inner.source_maps(),
+            Self::EVM(inner) => inner.source_maps(),
+        }
+    }
+
+    ///
+    /// Returns the fully qualified paths of the libraries referenced in the source.
+    ///
+    pub fn library_references(&self) -> BTreeSet<String> {
+        match self {
+            Self::Yul(inner) => inner.library_references(),
+            Self::EVM(inner) => inner.library_references(),
+        }
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Source
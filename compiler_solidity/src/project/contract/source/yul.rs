@@ -2,6 +2,15 @@
 //! The `solc --standard-json` contract Yul source.
 //!
 
+use std::collections::BTreeSet;
+
+use crate::solc::source_map::entry::jump::Jump as SourceMapJump;
+use crate::solc::source_map::entry::Entry as SourceMapEntry;
+use crate::solc::source_map::kind::Kind as SourceMapKind;
+use crate::solc::source_map::SourceMap;
+use crate::yul::error::Error as YulError;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::object::Object;
 
 ///
@@ -22,6 +31,103 @@ impl Yul {
     pub fn new(source: String, object: Object) -> Self {
         Self { source, object }
     }
+
+    ///
+    /// Annotates the assembly text with comments pointing to the Yul source locations.
+    ///
+    /// The object locations are written at the beginning, and each label of a function defined
+    /// in the object is preceded by the location of its definition.
+    ///
+    pub fn annotate_assembly(&self, assembly_text: &str) -> String {
+        let function_locations = self.object.function_locations();
+
+        let mut result = String::with_capacity(assembly_text.len() * 2);
+        let mut object = Some(&self.object);
+        while let Some(inner) = object {
+            result.push_str(
+                format!(
+                    "; Yul object `{}` at {}\n",
+                    inner.identifier, inner.location
+                )
+                .as_str(),
+            );
+            object = inner.inner_object.as_deref();
+        }
+
+        for line in assembly_text.lines() {
+            let location = line
+                .trim()
+                .strip_suffix(':')
+                .and_then(|label| function_locations.get(label));
+            if let Some(location) = location {
+                result.push_str(format!("; Yul function defined at {}\n", location).as_str());
+            }
+            result.push_str(line);
+            result.push('\n');
+        }
+        result
+    }
+
+    ///
+    /// Returns the deploy and runtime code source maps.
+    ///
+    /// The Yul statements are not mapped to the instructions they are lowered to, so the maps
+    /// are approximated with one entry per statement, in the source code order. Each entry spans
+    /// the rest of the statement line in the Yul source, which has the source index `0`.
+    ///
+    pub fn source_maps(&self) -> (SourceMap, SourceMap) {
+        let deploy = self.block_source_map(self.object.deploy_block());
+        let runtime = self
+            .object
+            .runtime_block()
+            .map(|block| self.block_source_map(block))
+            .unwrap_or_else(|| SourceMap::new(SourceMapKind::Yul, vec![]));
+        (deploy, runtime)
+    }
+
+    ///
+    /// Returns the fully qualified paths of the libraries referenced in the object.
+    ///
+    pub fn library_references(&self) -> BTreeSet<String> {
+        self.object.library_references()
+    }
+
+    ///
+    /// Returns the source map of the statements in the block.
+    ///
+    fn block_source_map(&self, block: &Block) -> SourceMap {
+        let lines: Vec<&str> = self.source.split('\n').collect();
+        let mut line_offsets = Vec::with_capacity(lines.len());
+        let mut offset = 0;
+        for line in lines.iter() {
+            line_offsets.push(offset);
+            offset += line.len() + 1;
+        }
+
+        let mut locations = Vec::new();
+        block.statement_locations(&mut locations);
+
+        let entries = locations
+            .into_iter()
+            .filter_map(|Location { line, column }| {
+                let text = lines.get(line.checked_sub(1)?)?;
+                let column_offset = text
+                    .char_indices()
+                    .nth(column.checked_sub(1)?)
+                    .map(|(offset, _)| offset)?;
+                let start = line_offsets[line - 1] + column_offset;
+                let length = text[column_offset..].trim_end().len();
+                Some(SourceMapEntry::new(
+                    start as isize,
+                    length as isize,
+                    0,
+                    SourceMapJump::Regular,
+                    0,
+                ))
+            })
+            .collect();
+        SourceMap::new(SourceMapKind::Yul, entries)
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Yul
@@ -29,6 +135,13 @@ where
     D: compiler_llvm_context::Dependency,
 {
     fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        let mut errors = self.object.unknown_builtins();
+        match errors.len() {
+            0 => {}
+            1 => return Err(errors.remove(0).into()),
+            _ => return Err(YulError::UnknownBuiltins(errors).into()),
+        }
+
         self.object.declare(context)
     }
 
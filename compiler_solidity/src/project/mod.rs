@@ -5,7 +5,12 @@
 pub mod contract;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::path::Path;
+use std::sync::atomic::AtomicBool;
+#[cfg(test)]
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::RwLock;
 
@@ -15,9 +20,14 @@ use rayon::iter::ParallelIterator;
 use crate::build::contract::Contract as ContractBuild;
 use crate::build::Build;
 use crate::dump_flag::DumpFlag;
+use crate::failure::Failure;
 use crate::project::contract::source::Source;
 use crate::project::contract::state::State;
+use crate::solc::standard_json::input::settings::optimizer::Optimizer as SolcStandardJsonInputSettingsOptimizer;
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::compact_json::CompactJson;
+use crate::yul::parser::statement::expression::function_call::verbatim_globals::VerbatimGlobals;
 use crate::yul::parser::statement::object::Object;
 
 use self::contract::state::State as ContractState;
@@ -36,6 +46,49 @@ pub struct Project {
     pub identifier_paths: BTreeMap<String, String>,
     /// The library addresses.
     pub libraries: BTreeMap<String, BTreeMap<String, String>>,
+    /// The per-contract optimizer overrides, keyed by the full contract path.
+    pub optimizer_overrides: BTreeMap<String, SolcStandardJsonInputSettingsOptimizer>,
+    /// The cancellation token, which stops scheduling new contracts once set, e.g. on SIGINT.
+    pub cancellation_token: Option<Arc<AtomicBool>>,
+    /// Whether to annotate the assembly with the Yul source locations.
+    pub annotate_assembly: bool,
+    /// Whether to generate the `solc` source maps.
+    pub emit_source_map: bool,
+    /// Whether to keep the unused functions from being eliminated by the optimizer.
+    pub preserve_unused_functions: bool,
+    /// Whether to keep the LLVM bitcode of the contract modules.
+    pub emit_llvm_bc: bool,
+    /// Whether to record the Yul built-in functions and EVM legacy assembly instructions lowered.
+    pub emit_coverage: bool,
+    /// Whether to collect the LLVM IR statistics of the contract modules.
+    pub emit_ir_stats: bool,
+    /// Whether to abort the compilation on the first contract error instead of collecting the
+    /// errors of all contracts in the build.
+    pub halt_on_first_error: bool,
+    /// The path of the only contract to compile along with its dependencies, if selected.
+    pub selected_contract: Option<String>,
+    /// The LLVM data layout overriding the target one in the contract modules, if set.
+    pub data_layout: Option<String>,
+    /// The maximum number of factory dependencies of a contract, if limited.
+    pub max_factory_dependencies: Option<usize>,
+    /// Whether to compile the libraries missing in the project with their placeholder addresses.
+    pub allow_unlinked_libraries: bool,
+    /// Whether to lower the unknown `verbatim` simulations in the Yul contracts to a revert.
+    /// It is intended for the forward compatibility with newer `solc` versions, which may emit
+    /// simulations not supported yet. By default, such simulations cause a compile-time error.
+    pub allow_unknown_verbatim: bool,
+    /// The global variable getters table of the `verbatim` simulations in the Yul contracts.
+    /// It allows supporting the globals introduced by newer system contracts without changing
+    /// the compiler. By default, only the globals known to the compiler are available.
+    pub verbatim_globals: Arc<VerbatimGlobals>,
+    /// The warnings found while parsing the sources, which are reported by the caller.
+    /// The warnings found while compiling are moved to the build.
+    pub warnings: Vec<SolcStandardJsonOutputError>,
+    /// The number of contract compilations started, which never exceeds the number of contracts.
+    /// It is shared, so it can be read after the project is consumed by the compilation.
+    /// Only for testing purposes.
+    #[cfg(test)]
+    pub compilation_count: Arc<AtomicUsize>,
 }
 
 impl Project {
@@ -60,12 +113,32 @@ impl Project {
                 .collect(),
             identifier_paths,
             libraries,
+            optimizer_overrides: BTreeMap::new(),
+            cancellation_token: None,
+            annotate_assembly: false,
+            emit_source_map: false,
+            preserve_unused_functions: false,
+            emit_llvm_bc: false,
+            halt_on_first_error: true,
+            emit_coverage: false,
+            emit_ir_stats: false,
+            selected_contract: None,
+            data_layout: None,
+            max_factory_dependencies: None,
+            allow_unlinked_libraries: false,
+            allow_unknown_verbatim: false,
+            verbatim_globals: Arc::new(VerbatimGlobals::default()),
+            warnings: Vec::new(),
+            #[cfg(test)]
+            compilation_count: Arc::new(AtomicUsize::new(0)),
         }
     }
 
     ///
     /// Compiles the specified contract, setting its build artifacts.
     ///
+    /// If the contract is being built by another thread, waits until its build is finished.
+    ///
     pub fn compile(
         project: Arc<RwLock<Self>>,
         contract_path: &str,
@@ -85,35 +158,92 @@ impl Project {
                     contract_path.to_owned(),
                     ContractState::Waiter(waiter.clone()),
                 );
+                let optimizer_override = project_guard
+                    .optimizer_overrides
+                    .get(contract_path)
+                    .map(SolcStandardJsonInputSettingsOptimizer::settings);
+                let emit_source_map = project_guard.emit_source_map;
+                let library_references = contract.source.library_references();
+                for path in library_references.iter() {
+                    if let Some(address) = project_guard.library_address(path.as_str()) {
+                        if address.chars().all(|character| character == '0') {
+                            let mut warning =
+                                SolcStandardJsonOutputError::warning_zero_library_address(
+                                    path.as_str(),
+                                );
+                            warning.push_contract_path(contract_path);
+                            project_guard.warnings.push(warning);
+                        }
+                    }
+                }
+                let unlinked_libraries: BTreeSet<String> = library_references
+                    .into_iter()
+                    .filter(|path| project_guard.library_address(path.as_str()).is_none())
+                    .collect();
+                if project_guard.allow_unlinked_libraries {
+                    for path in unlinked_libraries.iter() {
+                        let mut warning =
+                            SolcStandardJsonOutputError::warning_unlinked_library(path.as_str());
+                        warning.push_contract_path(contract_path);
+                        project_guard.warnings.push(warning);
+                    }
+                }
+                #[cfg(test)]
+                project_guard
+                    .compilation_count
+                    .fetch_add(1, Ordering::SeqCst);
                 std::mem::drop(project_guard);
 
                 let identifier = contract.identifier().to_owned();
                 let abi = contract.abi.take();
-                match contract.compile(
-                    project.clone(),
-                    target_machine,
-                    optimizer_settings,
-                    dump_flags,
-                ) {
-                    Ok(build) => {
-                        let build =
+                let source_maps = if emit_source_map {
+                    Some(contract.source.source_maps())
+                } else {
+                    None
+                };
+                let result = match optimizer_override {
+                    Some(optimizer_settings) => compiler_llvm_context::TargetMachine::new(
+                        &optimizer_settings,
+                    )
+                    .and_then(|target_machine| {
+                        contract.compile(
+                            project.clone(),
+                            target_machine,
+                            optimizer_settings,
+                            dump_flags,
+                        )
+                    }),
+                    None => contract.compile(
+                        project.clone(),
+                        target_machine,
+                        optimizer_settings,
+                        dump_flags,
+                    ),
+                };
+                let state = match result {
+                    Ok((build, pass_timings, llvm_bitcode, ir_stats, coverage)) => {
+                        let mut build =
                             ContractBuild::new(contract_path.to_owned(), identifier, build, abi);
-                        project
-                            .write()
-                            .expect("Sync")
-                            .contract_states
-                            .insert(contract_path.to_owned(), ContractState::Build(build));
-                        waiter.1.notify_all();
+                        build.pass_timings = pass_timings;
+                        build.source_maps = source_maps;
+                        build.llvm_bitcode = llvm_bitcode;
+                        build.coverage = coverage;
+                        build.ir_stats = ir_stats;
+                        build.link_references = crate::linker::link_references(
+                            build.bytecode(),
+                            unlinked_libraries.iter(),
+                        );
+                        ContractState::Build(build)
                     }
-                    Err(error) => {
-                        project
-                            .write()
-                            .expect("Sync")
-                            .contract_states
-                            .insert(contract_path.to_owned(), ContractState::Error(error));
-                        waiter.1.notify_all();
-                    }
-                }
+                    Err(error) => ContractState::Error(error),
+                };
+                project
+                    .write()
+                    .expect("Sync")
+                    .contract_states
+                    .insert(contract_path.to_owned(), state);
+                let _guard = waiter.0.lock().expect("Sync");
+                waiter.1.notify_all();
             }
             ContractState::Waiter(waiter) => {
                 project_guard.contract_states.insert(
@@ -122,7 +252,21 @@ impl Project {
                 );
                 std::mem::drop(project_guard);
 
-                let _guard = waiter.1.wait(waiter.0.lock().expect("Sync"));
+                let mut guard = waiter.0.lock().expect("Sync");
+                loop {
+                    let is_being_built = matches!(
+                        project
+                            .read()
+                            .expect("Sync")
+                            .contract_states
+                            .get(contract_path),
+                        Some(ContractState::Waiter(_))
+                    );
+                    if !is_being_built {
+                        break;
+                    }
+                    guard = waiter.1.wait(guard).expect("Sync");
+                }
             }
             ContractState::Build(build) => {
                 project_guard
@@ -137,9 +281,176 @@ impl Project {
         }
     }
 
+    ///
+    /// Selects the only contract to compile, along with the contracts it depends on.
+    ///
+    /// The contract is specified either by its full path, by its Yul object identifier, or by
+    /// the trailing part of its fully qualified path, e.g. `file.sol:Contract`. If the contract
+    /// is not found or is ambiguous, the error lists the valid fully qualified names.
+    ///
+    pub fn select_contract(&mut self, name: &str) -> anyhow::Result<()> {
+        let path = if self.contract_states.contains_key(name) {
+            Some(name.to_owned())
+        } else if let Ok(path) = compiler_llvm_context::Dependency::resolve_path(self, name) {
+            Some(path)
+        } else {
+            let mut matches = self.contract_states.keys().filter(|path| {
+                path.strip_suffix(name)
+                    .map(|prefix| prefix.is_empty() || prefix.ends_with('/'))
+                    .unwrap_or_default()
+            });
+            match (matches.next(), matches.next()) {
+                (Some(path), None) => Some(path.to_owned()),
+                _ => None,
+            }
+        };
+
+        match path {
+            Some(path) => {
+                self.selected_contract = Some(path);
+                Ok(())
+            }
+            None => anyhow::bail!(
+                "Contract `{}` not found or ambiguous. The valid names are:\n{}",
+                name,
+                self.contract_states
+                    .keys()
+                    .map(String::as_str)
+                    .collect::<Vec<&str>>()
+                    .join("\n")
+            ),
+        }
+    }
+
+    ///
+    /// Sets the LLVM data layout of the contract modules, overriding the target one.
+    ///
+    /// The layout is validated here, since LLVM aborts the process on an invalid one.
+    ///
+    pub fn set_data_layout(&mut self, data_layout: &str) -> anyhow::Result<()> {
+        if let Some(specification) = data_layout
+            .split('-')
+            .find(|specification| !Self::is_valid_data_layout_specification(specification))
+        {
+            anyhow::bail!(
+                "The data layout `{}` specification `{}` is invalid",
+                data_layout,
+                specification
+            );
+        }
+
+        self.data_layout = Some(data_layout.to_owned());
+        Ok(())
+    }
+
+    ///
+    /// Checks a single LLVM data layout specification, e.g. `i64:64` or `S32`.
+    ///
+    /// Besides the syntax, the sizes must be non-zero, the alignments must be powers of two in
+    /// bytes, and the preferred alignments must not be less than the ABI ones.
+    ///
+    fn is_valid_data_layout_specification(specification: &str) -> bool {
+        let number = |string: &str| -> Option<u64> {
+            if string.is_empty() || !string.chars().all(|character| character.is_ascii_digit()) {
+                return None;
+            }
+            string.parse().ok()
+        };
+        let numbers = |string: &str, min: usize, max: usize| -> Option<Vec<u64>> {
+            let numbers = string
+                .split(':')
+                .map(number)
+                .collect::<Option<Vec<u64>>>()?;
+            if (min..=max).contains(&numbers.len()) {
+                Some(numbers)
+            } else {
+                None
+            }
+        };
+        let is_size = |bits: u64| bits > 0 && bits % 8 == 0;
+        let is_alignment = |bits: u64| bits % 8 == 0 && (bits / 8).is_power_of_two();
+        let are_alignments = |alignments: &[u64], is_abi_zero_allowed: bool| match alignments {
+            [abi] => is_alignment(*abi) || (is_abi_zero_allowed && *abi == 0),
+            [abi, preferred] => {
+                (is_alignment(*abi) || (is_abi_zero_allowed && *abi == 0))
+                    && is_alignment(*preferred)
+                    && preferred >= abi
+            }
+            _ => false,
+        };
+
+        let mut characters = specification.chars();
+        let kind = characters.next();
+        let tail = characters.as_str();
+        match kind {
+            Some('e' | 'E') => tail.is_empty(),
+            Some('P' | 'A' | 'G') => number(tail).is_some(),
+            Some('S') => {
+                matches!(number(tail), Some(alignment) if alignment == 0 || is_alignment(alignment))
+            }
+            Some('m') => matches!(tail, ":e" | ":l" | ":o" | ":x" | ":w" | ":m" | ":a"),
+            Some('F') => match tail.strip_prefix(['i', 'n']).and_then(number) {
+                Some(alignment) => is_alignment(alignment),
+                None => false,
+            },
+            Some('n') => match numbers(tail.strip_prefix("i:").unwrap_or(tail), 1, usize::MAX) {
+                Some(sizes) => sizes.into_iter().all(is_size),
+                None => false,
+            },
+            Some('p') => match tail.split_once(':') {
+                Some((address_space, sizes))
+                    if address_space.is_empty() || number(address_space).is_some() =>
+                {
+                    match numbers(sizes, 2, 4) {
+                        Some(sizes) => {
+                            let index_size = sizes.get(3).copied().unwrap_or(sizes[0]);
+                            is_size(sizes[0])
+                                && are_alignments(&sizes[1..sizes.len().min(3)], false)
+                                && is_size(index_size)
+                                && index_size <= sizes[0]
+                        }
+                        None => false,
+                    }
+                }
+                _ => false,
+            },
+            Some(kind @ ('i' | 'f' | 'v' | 'a')) => match tail.split_once(':') {
+                Some((size, alignments)) => {
+                    let is_size_valid = match (kind, number(size)) {
+                        ('a', None) => size.is_empty(),
+                        ('a', Some(size)) => size == 0,
+                        (_, Some(size)) => size == 1 || is_size(size),
+                        (_, None) => false,
+                    };
+                    is_size_valid
+                        && numbers(alignments, 1, 2)
+                            .map(|alignments| are_alignments(alignments.as_slice(), kind == 'a'))
+                            .unwrap_or_default()
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    ///
+    /// Whether the compilation has been cancelled.
+    ///
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token
+            .as_ref()
+            .map(|token| token.load(Ordering::SeqCst))
+            .unwrap_or_default()
+    }
+
     ///
     /// Compiles all contracts, returning their build artifacts.
     ///
+    /// If the compilation is cancelled, the contracts not started yet are skipped, and an error
+    /// is returned instead of a partial build.
+    ///
+    /// If a contract is selected, only it and its dependencies are compiled.
+    ///
     #[allow(clippy::needless_collect)]
     pub fn compile_all(
         self,
@@ -147,18 +458,23 @@ impl Project {
         optimizer_settings: compiler_llvm_context::OptimizerSettings,
         dump_flags: Vec<DumpFlag>,
     ) -> anyhow::Result<Build> {
+        let parsing_warnings = self.warnings.len();
         let project = Arc::new(RwLock::new(self));
 
-        let contract_paths: Vec<String> = project
-            .read()
-            .expect("Sync")
-            .contract_states
-            .keys()
-            .cloned()
-            .collect();
+        let contract_paths: Vec<String> = {
+            let project = project.read().expect("Sync");
+            match project.selected_contract {
+                Some(ref path) => vec![path.to_owned()],
+                None => project.contract_states.keys().cloned().collect(),
+            }
+        };
         let _: Vec<()> = contract_paths
             .into_par_iter()
             .map(|contract_path| {
+                if project.read().expect("Sync").is_cancelled() {
+                    return;
+                }
+
                 Self::compile(
                     project.clone(),
                     contract_path.as_str(),
@@ -169,17 +485,28 @@ impl Project {
             })
             .collect();
 
-        let project = Arc::try_unwrap(project)
+        let mut project = Arc::try_unwrap(project)
             .expect("No other references must exist at this point")
             .into_inner()
             .expect("Sync");
-        let mut build = Build::default();
+        if project.is_cancelled() {
+            anyhow::bail!("Compilation interrupted");
+        }
+
+        let mut build = Build {
+            warnings: project.warnings.split_off(parsing_warnings),
+            ..Build::default()
+        };
         for (path, state) in project.contract_states.into_iter() {
             match state {
                 State::Build(contract_build) => {
                     build.contracts.insert(path, contract_build);
                 }
-                State::Error(error) => return Err(error),
+                State::Error(error) if project.halt_on_first_error => return Err(error),
+                State::Error(error) => {
+                    build.errors.insert(path, error);
+                }
+                State::Source(_) if project.selected_contract.is_some() => {}
                 _ => panic!("Contract `{}` must be built at this point", path),
             }
         }
@@ -189,23 +516,143 @@ impl Project {
     ///
     /// Parses the default Yul source code and returns the source data.
     ///
-    pub fn try_from_default_yul(path: &Path, version: &semver::Version) -> anyhow::Result<Self> {
-        let yul = std::fs::read_to_string(path)
-            .map_err(|error| anyhow::anyhow!("Yul file {:?} reading error: {}", path, error))?;
+    /// If the file contains several top-level objects, each of them becomes a separate contract
+    /// with the object identifier appended to the path.
+    ///
+    /// The identifiers longer than `max_identifier_length` bytes, if specified, are rejected.
+    ///
+    pub fn try_from_default_yul(
+        path: &Path,
+        version: &semver::Version,
+        max_identifier_length: Option<usize>,
+    ) -> anyhow::Result<Self> {
+        let yul = Self::read_yul(path)?;
         let mut lexer = Lexer::new(yul.clone());
+        if let Some(max_identifier_length) = max_identifier_length {
+            lexer.set_max_identifier_length(max_identifier_length);
+        }
         let path = path.to_string_lossy().to_string();
-        let object = Object::parse(&mut lexer, None)
+        let objects = Object::parse_all(&mut lexer)
             .map_err(|error| anyhow::anyhow!("Yul object `{}` parsing error: {}", path, error,))?;
+        let is_multi_object = objects.len() > 1;
 
         let mut project_contracts = BTreeMap::new();
-        project_contracts.insert(
-            path.clone(),
-            Contract::new(path, Source::new_yul(yul, object), None),
-        );
-        Ok(Self::new(
-            version.to_owned(),
-            project_contracts,
-            BTreeMap::new(),
+        let mut warnings = Vec::new();
+        for object in objects.into_iter() {
+            let path = if is_multi_object {
+                format!("{}:{}", path, object.identifier)
+            } else {
+                path.clone()
+            };
+
+            for variable in object.shadowed_loop_variables() {
+                let mut warning = SolcStandardJsonOutputError::warning_shadowed_loop_variable(
+                    variable.inner.as_str(),
+                    variable.location,
+                );
+                warning.push_contract_path(path.as_str());
+                warnings.push(warning);
+            }
+            if object.is_likely_swapped() {
+                let mut warning =
+                    SolcStandardJsonOutputError::warning_deploy_and_runtime_code_swapped();
+                warning.push_contract_path(path.as_str());
+                warnings.push(warning);
+            }
+            for (function, variable) in object.unassigned_function_results() {
+                let mut warning = SolcStandardJsonOutputError::warning_unassigned_function_result(
+                    function.as_str(),
+                    variable.inner.as_str(),
+                    variable.location,
+                );
+                warning.push_contract_path(path.as_str());
+                warnings.push(warning);
+            }
+            for location in object.deploy_code_stops() {
+                let mut warning = SolcStandardJsonOutputError::warning_deploy_code_stop(location);
+                warning.push_contract_path(path.as_str());
+                warnings.push(warning);
+            }
+            for (byte_location, word_location) in object.overlapping_byte_and_word_stores() {
+                let mut warning =
+                    SolcStandardJsonOutputError::warning_overlapping_byte_and_word_stores(
+                        byte_location,
+                        word_location,
+                    );
+                warning.push_contract_path(path.as_str());
+                warnings.push(warning);
+            }
+            for (size, location) in object.large_create_input_sizes() {
+                let mut warning =
+                    SolcStandardJsonOutputError::warning_large_create_input_size(&size, location);
+                warning.push_contract_path(path.as_str());
+                warnings.push(warning);
+            }
+            for location in object.reserved_memory_hashes() {
+                let mut warning =
+                    SolcStandardJsonOutputError::warning_reserved_memory_hash(location);
+                warning.push_contract_path(path.as_str());
+                warnings.push(warning);
+            }
+
+            project_contracts.insert(
+                path.clone(),
+                Contract::new(path, Source::new_yul(yul.clone(), object), None),
+            );
+        }
+        let mut project = Self::new(version.to_owned(), project_contracts, BTreeMap::new());
+        project.warnings = warnings;
+        Ok(project)
+    }
+
+    ///
+    /// Reads the Yul file, reporting the byte offset of the first invalid UTF-8 sequence if the
+    /// file is not valid UTF-8.
+    ///
+    fn read_yul(path: &Path) -> anyhow::Result<String> {
+        let bytes = std::fs::read(path)
+            .map_err(|error| anyhow::anyhow!("Yul file {:?} reading error: {}", path, error))?;
+        String::from_utf8(bytes).map_err(|error| {
+            anyhow::anyhow!(
+                "Yul file {:?} contains invalid UTF-8 at byte offset {}",
+                path,
+                error.utf8_error().valid_up_to()
+            )
+        })
+    }
+
+    ///
+    /// Parses the Yul file and returns the identifiers of its top-level objects.
+    ///
+    pub fn yul_object_identifiers(path: &Path) -> anyhow::Result<Vec<String>> {
+        let yul = Self::read_yul(path)?;
+        let mut lexer = Lexer::new(yul);
+        let objects = Object::parse_all(&mut lexer)
+            .map_err(|error| anyhow::anyhow!("Yul file {:?} parsing error: {}", path, error))?;
+
+        Ok(objects
+            .into_iter()
+            .map(|object| object.identifier)
+            .collect())
+    }
+
+    ///
+    /// Parses the Yul file and returns the AST of its top-level objects in the `solc`
+    /// compact JSON format.
+    ///
+    pub fn yul_compact_json(path: &Path) -> anyhow::Result<serde_json::Value> {
+        let yul = Self::read_yul(path)?;
+        let mut lexer = Lexer::new(yul.clone());
+        let objects = Object::parse_all(&mut lexer)
+            .map_err(|error| anyhow::anyhow!("Yul file {:?} parsing error: {}", path, error))?;
+
+        // The Yul mode compiles a single source, which is the first one in the `solc` terms.
+        let mut exporter = CompactJson::new(yul.as_str(), 0);
+        Ok(serde_json::Value::Array(
+            objects
+                .iter()
+                .map(|object| exporter.object(object))
+                .collect(),
         ))
     }
 
@@ -231,6 +678,74 @@ impl Project {
             BTreeMap::new(),
         ))
     }
+
+    ///
+    /// Parses the test Yul source code and compiles it without optimizations.
+    ///
+    /// Only for testing purposes.
+    ///
+    #[cfg(test)]
+    pub fn compile_test_yul(yul: &str) -> anyhow::Result<Build> {
+        Self::try_from_test_yul(yul, &semver::Version::new(0, 8, 17))?
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+    }
+
+    ///
+    /// Compiles the project with the given `optimizer_settings` and no dump flags.
+    ///
+    /// Only for testing purposes.
+    ///
+    #[cfg(test)]
+    pub fn compile_test(
+        self,
+        optimizer_settings: compiler_llvm_context::OptimizerSettings,
+    ) -> anyhow::Result<Build> {
+        compiler_llvm_context::initialize_target();
+        let target_machine = compiler_llvm_context::TargetMachine::new(&optimizer_settings)?;
+        self.compile_all(target_machine, optimizer_settings, vec![])
+    }
+
+    ///
+    /// Returns the bytecode hash and the full path of the factory dependency with the given
+    /// `identifier`.
+    ///
+    /// The dependency must have been built by the time its dependants are finalized, so an error
+    /// here means the compilation has been scheduled incorrectly.
+    ///
+    pub fn factory_dependency(&self, identifier: &str) -> anyhow::Result<(String, String)> {
+        let full_path = self
+            .identifier_paths
+            .get(identifier)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Dependency `{}` full path not found", identifier))?;
+        match self.contract_states.get(full_path.as_str()) {
+            Some(State::Build(build)) => Ok((build.build.hash.to_owned(), full_path)),
+            Some(_) => anyhow::bail!(
+                "Dependency contract `{}` is not built at this point",
+                full_path
+            ),
+            None => anyhow::bail!(
+                "Dependency contract `{}` not found in the project",
+                full_path
+            ),
+        }
+    }
+
+    ///
+    /// Returns the unprefixed address of the library with the fully qualified `path`, if it is
+    /// linked at compile time.
+    ///
+    fn library_address(&self, path: &str) -> Option<String> {
+        self.libraries
+            .iter()
+            .flat_map(|(file_path, contracts)| {
+                contracts.iter().map(move |(contract_name, address)| {
+                    (format!("{}:{}", file_path, contract_name), address)
+                })
+            })
+            .find(|(key, _address)| key.as_str() == path)
+            .map(|(_key, address)| address.strip_prefix("0x").unwrap_or(address).to_owned())
+    }
 }
 
 impl compiler_llvm_context::Dependency for Project {
@@ -243,6 +758,15 @@ impl compiler_llvm_context::Dependency for Project {
     ) -> anyhow::Result<String> {
         let contract_path = project.read().expect("Lock").resolve_path(identifier)?;
 
+        if let Some(ContractState::Build(build)) = project
+            .read()
+            .expect("Lock")
+            .contract_states
+            .get(contract_path.as_str())
+        {
+            return Ok(build.build.hash.to_owned());
+        }
+
         Self::compile(
             project.clone(),
             contract_path.as_str(),
@@ -258,11 +782,17 @@ impl compiler_llvm_context::Dependency for Project {
             .get(contract_path.as_str())
         {
             Some(ContractState::Build(build)) => Ok(build.build.hash.to_owned()),
-            Some(ContractState::Error(error)) => anyhow::bail!(
-                "Dependency contract `{}` compiling error: {}",
-                identifier,
-                error
-            ),
+            Some(ContractState::Error(error)) => {
+                let message = anyhow::anyhow!(
+                    "Dependency contract `{}` compiling error: {}",
+                    identifier,
+                    error
+                );
+                Err(match Failure::of(error) {
+                    Some(failure) => failure.wrap(message),
+                    None => message,
+                })
+            }
             Some(_) => panic!(
                 "Dependency contract `{}` must be built at this point",
                 contract_path
@@ -287,15 +817,979 @@ impl compiler_llvm_context::Dependency for Project {
     }
 
     fn resolve_library(&self, path: &str) -> anyhow::Result<String> {
-        for (file_path, contracts) in self.libraries.iter() {
-            for (contract_name, address) in contracts.iter() {
-                let key = format!("{}:{}", file_path, contract_name);
-                if key.as_str() == path {
-                    return Ok(address["0x".len()..].to_owned());
+        match self.library_address(path) {
+            Some(address) => Ok(address),
+            None if self.allow_unlinked_libraries => {
+                Ok(crate::linker::placeholder_address(path)["0x".len()..].to_owned())
+            }
+            None => anyhow::bail!("Library `{}` not found in the project", path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use crate::build::Build;
+    use crate::solc::pipeline::Pipeline as SolcPipeline;
+    use crate::solc::standard_json::input::settings::optimizer::Optimizer as SolcStandardJsonInputSettingsOptimizer;
+    use crate::solc::standard_json::output::contract::Contract as SolcStandardJsonOutputContract;
+    use crate::solc::standard_json::output::Output as SolcStandardJsonOutput;
+
+    use super::Project;
+
+    const SOLC_OUTPUT: &str = r#"{
+    "contracts": {
+        "test.sol": {
+            "First": {
+                "irOptimized": "object \"First_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"First_1_deployed\" {\n        code {\n            {\n                function f(a) -> r {\n                    r := add(a, 1)\n                }\n                mstore(0, f(calldataload(0)))\n                return(0, 32)\n            }\n        }\n    }\n}\n"
+            },
+            "Second": {
+                "irOptimized": "object \"Second_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Second_1_deployed\" {\n        code {\n            {\n                function f(a) -> r {\n                    r := add(a, 1)\n                }\n                mstore(0, f(calldataload(0)))\n                return(0, 32)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#;
+
+    fn compile(
+        optimizer_overrides: BTreeMap<String, SolcStandardJsonInputSettingsOptimizer>,
+    ) -> Build {
+        let mut output: SolcStandardJsonOutput =
+            serde_json::from_str(SOLC_OUTPUT).expect("Always valid");
+        let mut project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+        project.optimizer_overrides = optimizer_overrides;
+
+        project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid")
+    }
+
+    #[test]
+    fn optimizer_overrides() {
+        let default = compile(BTreeMap::new());
+
+        let mut optimizer_overrides = BTreeMap::new();
+        optimizer_overrides.insert(
+            "test.sol:First".to_owned(),
+            SolcStandardJsonInputSettingsOptimizer::new(true),
+        );
+        let overridden = compile(optimizer_overrides);
+
+        let bytecode = |build: &Build, path: &str| {
+            build
+                .contracts
+                .get(path)
+                .expect("Always exists")
+                .build
+                .bytecode
+                .clone()
+        };
+        assert_ne!(
+            bytecode(&default, "test.sol:First"),
+            bytecode(&overridden, "test.sol:First")
+        );
+        assert_eq!(
+            bytecode(&default, "test.sol:Second"),
+            bytecode(&overridden, "test.sol:Second")
+        );
+    }
+
+    #[test]
+    fn cancellation() {
+        let mut output: SolcStandardJsonOutput =
+            serde_json::from_str(SOLC_OUTPUT).expect("Always valid");
+        let mut project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+        project.cancellation_token = Some(Arc::new(AtomicBool::new(true)));
+
+        let error = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect_err("Must be interrupted");
+        assert_eq!(error.to_string(), "Compilation interrupted");
+    }
+
+    #[test]
+    fn annotate_assembly() {
+        let assembly_text = |annotate_assembly: bool| -> String {
+            let mut output: SolcStandardJsonOutput =
+                serde_json::from_str(SOLC_OUTPUT).expect("Always valid");
+            let mut project = output
+                .try_to_project(
+                    BTreeMap::new(),
+                    SolcPipeline::Yul,
+                    &semver::Version::new(0, 8, 17),
+                    &[],
+                )
+                .expect("Always valid");
+            project.annotate_assembly = annotate_assembly;
+
+            let mut build = project
+                .compile_test(compiler_llvm_context::OptimizerSettings::none())
+                .expect("Always valid");
+            build
+                .contracts
+                .remove("test.sol:First")
+                .expect("Always exists")
+                .build
+                .assembly_text
+        };
+        let plain = assembly_text(false);
+        let annotated = assembly_text(true);
+
+        assert!(annotated.starts_with(
+            "; Yul object `First_1` at 1:1\n; Yul object `First_1_deployed` at 7:5\n"
+        ));
+
+        let mut lines = annotated.lines().skip(2);
+        let mut instructions = Vec::new();
+        let mut annotated_functions = 0;
+        while let Some(line) = lines.next() {
+            if line == "; Yul function defined at 10:17" {
+                let label = lines.next().expect("Always exists");
+                assert_eq!(label.trim(), "f:");
+                instructions.push(label);
+                annotated_functions += 1;
+                continue;
+            }
+            assert!(
+                !line.starts_with("; Yul "),
+                "Unexpected annotation `{}`",
+                line
+            );
+            assert_ne!(line.trim(), "f:", "The function label is not annotated");
+            instructions.push(line);
+        }
+        assert_eq!(annotated_functions, 1);
+        assert_eq!(instructions, plain.lines().collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn emit_source_map() {
+        let mut output: SolcStandardJsonOutput =
+            serde_json::from_str(SOLC_OUTPUT).expect("Always valid");
+        let mut project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+        project.emit_source_map = true;
+
+        let mut build = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid");
+
+        let mut contract: SolcStandardJsonOutputContract =
+            serde_json::from_str("{}").expect("Always valid");
+        build
+            .contracts
+            .remove("test.sol:First")
+            .expect("Always exists")
+            .write_to_standard_json(&mut contract)
+            .expect("Always valid");
+        let evm = contract.evm.expect("Always exists");
+
+        for source_map in [
+            evm.bytecode.and_then(|bytecode| bytecode.yul_source_map),
+            evm.deployed_bytecode
+                .and_then(|bytecode| bytecode.yul_source_map),
+        ] {
+            let source_map = source_map.expect("Always exists");
+            let entries: Vec<&str> = source_map.split(';').collect();
+            assert_eq!(entries[0].split(':').count(), 5);
+            for entry in entries.into_iter() {
+                assert!(entry.split(':').count() <= 5);
+            }
+        }
+    }
+
+    #[test]
+    fn optimize_runs() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                for { let i := 0 } lt(i, 16) { i := add(i, 1) } {
+                    sstore(i, mul(i, calldataload(0)))
+                }
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let compile = |runs: usize| {
+            let project = Project::try_from_test_yul(input, &semver::Version::new(0, 8, 17))
+                .expect("Always valid");
+            project
+                .compile_test(
+                    SolcStandardJsonInputSettingsOptimizer::new_with_runs(true, Some(runs))
+                        .settings(),
+                )
+                .expect("Always valid")
+                .contracts
+                .remove("Test")
+                .expect("Always exists")
+                .build
+                .bytecode
+        };
+
+        assert_ne!(compile(1), compile(10_000));
+
+        // The per-contract overrides map the runs to the optimizer settings the same way.
+        let compile_overridden = |runs: usize| {
+            let mut project = Project::try_from_test_yul(input, &semver::Version::new(0, 8, 17))
+                .expect("Always valid");
+            project.optimizer_overrides.insert(
+                "Test".to_owned(),
+                SolcStandardJsonInputSettingsOptimizer::new_with_runs(true, Some(runs)),
+            );
+            project
+                .compile_test(compiler_llvm_context::OptimizerSettings::none())
+                .expect("Always valid")
+                .contracts
+                .remove("Test")
+                .expect("Always exists")
+                .build
+                .bytecode
+        };
+
+        assert_eq!(compile_overridden(1), compile(1));
+        assert_eq!(compile_overridden(10_000), compile(10_000));
+    }
+
+    #[test]
+    fn shared_dependency_compiled_once() {
+        let mut output: SolcStandardJsonOutput = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "A": {
+                "irOptimized": "object \"A_1\" { code { { return(0, 0) } } object \"A_1_deployed\" { code { { mstore(0, dataoffset(\"C_3\")) return(0, 32) } } object \"C_3\" { code { { return(0, 0) } } object \"C_3_deployed\" { code { { return(0, 0) } } } } } }"
+            },
+            "B": {
+                "irOptimized": "object \"B_1\" { code { { return(0, 0) } } object \"B_1_deployed\" { code { { mstore(0, dataoffset(\"C_3\")) return(0, 32) } } object \"C_3\" { code { { return(0, 0) } } object \"C_3_deployed\" { code { { return(0, 0) } } } } } }"
+            },
+            "C": {
+                "irOptimized": "object \"C_3\" { code { { return(0, 0) } } object \"C_3_deployed\" { code { { return(0, 0) } } } }"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+        let project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+        let compilation_count = project.compilation_count.clone();
+
+        let build = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid");
+
+        assert_eq!(compilation_count.load(Ordering::SeqCst), 3);
+        let dependency_hash = build
+            .contracts
+            .get("test.sol:C")
+            .map(|contract| contract.build.hash.clone())
+            .expect("Always exists");
+        for path in ["test.sol:A", "test.sol:B"] {
+            let factory_dependencies = &build
+                .contracts
+                .get(path)
+                .expect("Always exists")
+                .build
+                .factory_dependencies;
+            assert_eq!(
+                factory_dependencies
+                    .get(dependency_hash.as_str())
+                    .map(String::as_str),
+                Some("test.sol:C")
+            );
+        }
+    }
+
+    #[test]
+    fn dependency_duplicate_linked() {
+        let mut output: SolcStandardJsonOutput = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "other.sol": {
+            "C": {
+                "irOptimized": "object \"C_3\" { code { { return(0, 0) } } object \"C_3_deployed\" { code { { return(0, 0) } } } }"
+            }
+        },
+        "test.sol": {
+            "A": {
+                "irOptimized": "object \"A_1\" { code { { return(0, 0) } } object \"A_1_deployed\" { code { { mstore(0, dataoffset(\"C_3\")) return(0, 32) } } object \"C_3\" { code { { return(0, 0) } } object \"C_3_deployed\" { code { { return(0, 0) } } } } } }"
+            },
+            "C": {
+                "irOptimized": "object \"C_3\" { code { { return(0, 0) } } object \"C_3_deployed\" { code { { return(0, 0) } } } }"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+        let mut project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+        project.selected_contract = Some("test.sol:A".to_owned());
+
+        let build = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid");
+
+        // The identifier of the identical contracts resolves to the last path.
+        let dependency_hash = build
+            .contracts
+            .get("test.sol:C")
+            .map(|contract| contract.build.hash.clone())
+            .expect("Always exists");
+        let factory_dependencies = &build
+            .contracts
+            .get("test.sol:A")
+            .expect("Always exists")
+            .build
+            .factory_dependencies;
+        assert_eq!(
+            factory_dependencies
+                .get(dependency_hash.as_str())
+                .map(String::as_str),
+            Some("test.sol:C")
+        );
+    }
+
+    #[test]
+    fn error_max_factory_dependencies() {
+        let mut output: SolcStandardJsonOutput = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "A": {
+                "irOptimized": "object \"A_1\" { code { { return(0, 0) } } object \"A_1_deployed\" { code { { mstore(0, dataoffset(\"C_3\")) mstore(32, dataoffset(\"D_4\")) return(0, 64) } } object \"C_3\" { code { { return(0, 0) } } object \"C_3_deployed\" { code { { return(0, 0) } } } } object \"D_4\" { code { { return(0, 0) } } object \"D_4_deployed\" { code { { return(0, 0) } } } } } }"
+            },
+            "C": {
+                "irOptimized": "object \"C_3\" { code { { return(0, 0) } } object \"C_3_deployed\" { code { { return(0, 0) } } } }"
+            },
+            "D": {
+                "irOptimized": "object \"D_4\" { code { { return(0, 0) } } object \"D_4_deployed\" { code { { return(0, 0) } } } }"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+        let mut project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+        project.max_factory_dependencies = Some(1);
+
+        let error = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect_err("Must be an error");
+        assert!(error.to_string().contains(
+            "The contract `test.sol:A` has 2 factory dependencies, exceeding the limit of 1"
+        ));
+    }
+
+    #[test]
+    fn preserve_unused_functions() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+
+            function unused_helper(x) -> y {
+                y := add(x, 42)
+            }
+        }
+    }
+}
+    "#;
+
+        for is_preserved in [false, true] {
+            let mut project = Project::try_from_test_yul(input, &semver::Version::new(0, 8, 17))
+                .expect("Always valid");
+            project.preserve_unused_functions = is_preserved;
+
+            let build = project
+                .compile_test(compiler_llvm_context::OptimizerSettings::cycles())
+                .expect("Always valid");
+
+            let assembly_text = build.contracts["Test"].build.assembly_text.as_str();
+            assert_eq!(assembly_text.contains("unused_helper"), is_preserved);
+        }
+    }
+
+    #[test]
+    fn aggregate_yul_object_errors() {
+        let input = r#"
+object "Valid" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Valid_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+
+object "Invalid" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Invalid_deployed" {
+        code {
+            {
+                undeclared_function()
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let directory =
+            std::env::temp_dir().join(format!("zksolc-yul-errors-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("Always valid");
+        let path = directory.join("test.yul");
+        std::fs::write(&path, input).expect("Always valid");
+        let version = semver::Version::new(0, 8, 17);
+
+        for halt_on_first_error in [true, false] {
+            let mut project =
+                Project::try_from_default_yul(&path, &version, None).expect("Always valid");
+            project.halt_on_first_error = halt_on_first_error;
+
+            let result = project.compile_test(compiler_llvm_context::OptimizerSettings::none());
+            if halt_on_first_error {
+                assert!(result.is_err());
+                continue;
+            }
+
+            let build = result.expect("Always valid");
+            let valid_path = format!("{}:Valid", path.to_string_lossy());
+            let invalid_path = format!("{}:Invalid", path.to_string_lossy());
+            assert!(!build.contracts[valid_path.as_str()]
+                .build
+                .bytecode
+                .is_empty());
+            assert!(!build.contracts.contains_key(invalid_path.as_str()));
+            assert!(build.errors[invalid_path.as_str()]
+                .to_string()
+                .contains("Undeclared function `undeclared_function`"));
+        }
+
+        std::fs::remove_dir_all(&directory).expect("Always valid");
+    }
+
+    #[test]
+    fn warning_unassigned_function_result() {
+        let input = r#"object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                function assigned(a) -> r {
+                    if a { r := 1 }
+                }
+                function unassigned(a) -> r {
+                    sstore(0, a)
+                }
+                function reverting() -> r {
+                    revert(0, 0)
+                }
+                mstore(0, add(assigned(calldataload(0)), unassigned(1)))
+                return(0, 32)
+            }
+        }
+    }
+}
+"#;
+
+        let directory =
+            std::env::temp_dir().join(format!("zksolc-yul-unassigned-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("Always valid");
+        let path = directory.join("test.yul");
+        std::fs::write(&path, input).expect("Always valid");
+
+        let project = Project::try_from_default_yul(&path, &semver::Version::new(0, 8, 17), None)
+            .expect("Always valid");
+        assert_eq!(project.warnings.len(), 1);
+        assert!(project.warnings[0]
+            .message
+            .contains("Variable `r` of function `unassigned` at 13:"));
+        assert!(project.warnings[0]
+            .formatted_message
+            .contains(path.to_string_lossy().as_ref()));
+
+        std::fs::remove_dir_all(&directory).expect("Always valid");
+    }
+
+    #[test]
+    fn error_yul_invalid_utf8() {
+        let mut input =
+            b"object \"Test\" {\n    code {\n        {\n            return(0, 0)".to_vec();
+        let offset = input.len();
+        input.extend_from_slice(b"\xff\n        }\n    }\n}\n");
+
+        let directory =
+            std::env::temp_dir().join(format!("zksolc-yul-utf8-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("Always valid");
+        let path = directory.join("test.yul");
+        std::fs::write(&path, input).expect("Always valid");
+
+        let error = Project::try_from_default_yul(&path, &semver::Version::new(0, 8, 17), None)
+            .expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Yul file {:?} contains invalid UTF-8 at byte offset {}",
+                path, offset
+            )
+        );
+
+        std::fs::remove_dir_all(&directory).expect("Always valid");
+    }
+
+    #[test]
+    fn error_yul_identifier_too_long() {
+        let input = format!(
+            r#"object "Test" {{
+    code {{
+        {{
+            let {} := 42
+            return(0, 0)
+        }}
+    }}
+}}
+"#,
+            "a".repeat(300)
+        );
+
+        let directory =
+            std::env::temp_dir().join(format!("zksolc-yul-identifier-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("Always valid");
+        let path = directory.join("test.yul");
+        std::fs::write(&path, input).expect("Always valid");
+        let version = semver::Version::new(0, 8, 17);
+
+        assert!(Project::try_from_default_yul(&path, &version, None).is_ok());
+        assert!(Project::try_from_default_yul(&path, &version, Some(300)).is_ok());
+        let error = Project::try_from_default_yul(&path, &version, Some(256))
+            .expect_err("Must be an error");
+        assert!(error
+            .to_string()
+            .contains("Identifier is 300 bytes long, whereas the limit is 256 bytes"));
+
+        std::fs::remove_dir_all(&directory).expect("Always valid");
+    }
+
+    ///
+    /// The small contracts exercising the edge cases of the LLVM IR generator, which must stay
+    /// valid before and after the optimization.
+    ///
+    fn optimizer_corpus() -> Vec<(&'static str, String)> {
+        let wrap = |runtime: &str| {
+            format!(
+                r#"
+object "Test" {{
+    code {{
+        {{
+            return(0, 0)
+        }}
+    }}
+    object "Test_deployed" {{
+        code {{
+{}
+        }}
+    }}
+}}
+    "#,
+                runtime
+            )
+        };
+
+        let immutables = 16;
+        let set_immutables: String = (0..immutables)
+            .map(|index| {
+                format!(
+                    "            setimmutable(0, \"value_{}\", add(calldataload(0), {}))\n",
+                    index, index
+                )
+            })
+            .collect();
+        let load_immutables: String = (0..immutables)
+            .map(|index| {
+                format!(
+                    "                sum := add(sum, loadimmutable(\"value_{}\"))\n",
+                    index
+                )
+            })
+            .collect();
+
+        vec![
+            (
+                "deep_nesting",
+                wrap(
+                    r#"
+            {
+                let x := calldataload(0)
+                for { let i := 0 } lt(i, 4) { i := add(i, 1) } {
+                    if gt(x, i) {
+                        switch and(x, 3)
+                        case 0 {
+                            for { let j := 0 } lt(j, i) { j := add(j, 1) } {
+                                if eq(j, 2) { break }
+                                if iszero(j) { continue }
+                                x := add(x, j)
+                            }
+                        }
+                        case 1 {
+                            if lt(x, 100) {
+                                if gt(x, 50) {
+                                    x := mul(x, 2)
+                                }
+                            }
+                        }
+                        default {
+                            x := sub(x, 1)
+                        }
+                    }
+                }
+                mstore(0, x)
+                return(0, 32)
+            }
+"#,
+                ),
+            ),
+            (
+                "compound_returns",
+                wrap(
+                    r#"
+            {
+                let a, b, c := split(calldataload(0))
+                let d, e := swap(a, add(b, c))
+                mstore(0, d)
+                mstore(32, e)
+                return(0, 64)
+            }
+
+            function split(value) -> x, y, z {
+                x := and(value, 0xff)
+                y := shr(8, value)
+                if iszero(y) { leave }
+                z := add(x, y)
+            }
+
+            function swap(p, q) -> r, s {
+                r, s := swap_inner(q, p)
+            }
+
+            function swap_inner(p, q) -> r, s {
+                r := p
+                s := q
+            }
+"#,
+                ),
+            ),
+            (
+                "near_call_abi",
+                wrap(
+                    r#"
+            {
+                let x := ZKSYNC_NEAR_CALL_test(gas(), calldataload(0))
+                mstore(0, x)
+                return(0, 32)
+            }
+
+            function ZKSYNC_NEAR_CALL_test(abi_data, a) -> r {
+                r := add(a, 1)
+            }
+
+            function ZKSYNC_CATCH_NEAR_CALL() {
+                revert(0, 0)
+            }
+"#,
+                ),
+            ),
+            (
+                "many_immutables",
+                format!(
+                    r#"
+object "Test" {{
+    code {{
+        {{
+{}            return(0, 0)
+        }}
+    }}
+    object "Test_deployed" {{
+        code {{
+            {{
+                let sum := 0
+{}                mstore(0, sum)
+                return(0, 32)
+            }}
+        }}
+    }}
+}}
+    "#,
+                    set_immutables, load_immutables
+                ),
+            ),
+        ]
+    }
+
+    #[test]
+    fn optimizer_corpus_verifiable() {
+        for (name, input) in optimizer_corpus().into_iter() {
+            for optimizer_settings in [
+                compiler_llvm_context::OptimizerSettings::none(),
+                compiler_llvm_context::OptimizerSettings::cycles(),
+                compiler_llvm_context::OptimizerSettings::size(),
+            ] {
+                let project =
+                    Project::try_from_test_yul(input.as_str(), &semver::Version::new(0, 8, 17))
+                        .unwrap_or_else(|error| {
+                            panic!("Contract `{}` parsing error: {}", name, error)
+                        });
+                if let Err(error) = project.compile_test(optimizer_settings) {
+                    panic!("Contract `{}` compiling error: {}", name, error);
                 }
             }
         }
+    }
+
+    #[test]
+    fn select_contract_by_qualified_name() {
+        let solc_output = SOLC_OUTPUT.replace("\"test.sol\"", "\"/project/test.sol\"");
+        for name in ["test.sol:Second", "/project/test.sol:Second", "Second_1"] {
+            let mut output: SolcStandardJsonOutput =
+                serde_json::from_str(solc_output.as_str()).expect("Always valid");
+            let mut project = output
+                .try_to_project(
+                    BTreeMap::new(),
+                    SolcPipeline::Yul,
+                    &semver::Version::new(0, 8, 17),
+                    &[],
+                )
+                .expect("Always valid");
+            project.select_contract(name).expect("Always valid");
+
+            let build = project
+                .compile_test(compiler_llvm_context::OptimizerSettings::none())
+                .expect("Always valid");
+            assert_eq!(
+                build.contracts.keys().collect::<Vec<&String>>(),
+                vec!["/project/test.sol:Second"]
+            );
+        }
+    }
+
+    #[test]
+    fn error_select_contract_not_found() {
+        let mut output: SolcStandardJsonOutput =
+            serde_json::from_str(SOLC_OUTPUT).expect("Always valid");
+        let mut project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let error = project
+            .select_contract("test.sol:Third")
+            .expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            "Contract `test.sol:Third` not found or ambiguous. The valid names are:\ntest.sol:First\ntest.sol:Second"
+        );
+    }
+
+    #[test]
+    fn data_layout() {
+        let mut output: SolcStandardJsonOutput =
+            serde_json::from_str(SOLC_OUTPUT).expect("Always valid");
+        let mut project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
 
-        anyhow::bail!("Library `{}` not found in the project", path);
+        let data_layout = "E-p:256:256-i256:256:256-S32-a:256:256";
+        project.set_data_layout(data_layout).expect("Always valid");
+        assert_eq!(project.data_layout.as_deref(), Some(data_layout));
+
+        for (invalid, specification) in [
+            ("E-p:256-i256:256:256", "p:256"),
+            ("E-p:0:256-i256:256:256", "p:0:256"),
+            ("E-p:256:256-i256:48", "i256:48"),
+            ("E-p:256:256-i256:256:128", "i256:256:128"),
+            ("E-p:256:256-i0:256", "i0:256"),
+            ("E-p:256:256-S24", "S24"),
+            ("E-p:256:256-n0", "n0"),
+        ] {
+            let error = project
+                .set_data_layout(invalid)
+                .expect_err("Must be an error");
+            assert_eq!(
+                error.to_string(),
+                format!(
+                    "The data layout `{}` specification `{}` is invalid",
+                    invalid, specification
+                )
+            );
+        }
+        assert_eq!(project.data_layout.as_deref(), Some(data_layout));
+    }
+
+    #[test]
+    fn data_layout_module() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let module_data_layout = |data_layout: Option<&str>| {
+            let mut project = Project::try_from_test_yul(input, &semver::Version::new(0, 8, 17))
+                .expect("Always valid");
+            if let Some(data_layout) = data_layout {
+                project.set_data_layout(data_layout).expect("Always valid");
+            }
+            project.emit_llvm_bc = true;
+            let bitcode = project
+                .compile_test(compiler_llvm_context::OptimizerSettings::none())
+                .expect("Always valid")
+                .contracts
+                .remove("Test")
+                .expect("Always exists")
+                .llvm_bitcode
+                .expect("Always exists");
+
+            let llvm = inkwell::context::Context::create();
+            let buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range_copy(
+                bitcode.as_slice(),
+                "Test",
+            );
+            let module = inkwell::module::Module::parse_bitcode_from_buffer(&buffer, &llvm)
+                .expect("Always valid");
+            module
+                .get_data_layout()
+                .as_str()
+                .to_string_lossy()
+                .to_string()
+        };
+
+        let data_layout = "E-p:256:256-i256:256:256-S32-a:256:256-n8:256";
+        assert_ne!(module_data_layout(None), data_layout);
+        assert_eq!(module_data_layout(Some(data_layout)), data_layout);
+    }
+
+    #[test]
+    fn error_factory_dependency_not_built() {
+        let mut output: SolcStandardJsonOutput =
+            serde_json::from_str(SOLC_OUTPUT).expect("Always valid");
+        let project = output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let error = project
+            .factory_dependency("First_1")
+            .expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            "Dependency contract `test.sol:First` is not built at this point"
+        );
+
+        let error = project
+            .factory_dependency("Third_1")
+            .expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            "Dependency `Third_1` full path not found"
+        );
     }
 }
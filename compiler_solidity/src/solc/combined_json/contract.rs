@@ -25,6 +25,26 @@ pub struct Contract {
     /// The `solc` hexadecimal binary runtime part output.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bin_runtime: Option<String>,
+    /// The `solc` source map of the deploy code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srcmap: Option<String>,
+    /// The `solc` source map of the runtime code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srcmap_runtime: Option<String>,
+    /// The Yul source map of the deploy code, if requested for the Yul pipeline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yul_srcmap: Option<String>,
+    /// The Yul source map of the runtime code, if requested for the Yul pipeline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yul_srcmap_runtime: Option<String>,
+    /// The EVM legacy assembly source map of the deploy code, if requested for the EVM legacy
+    /// assembly pipeline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asm_srcmap: Option<String>,
+    /// The EVM legacy assembly source map of the runtime code, if requested for the EVM legacy
+    /// assembly pipeline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asm_srcmap_runtime: Option<String>,
     /// The factory dependencies.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub factory_deps: Option<BTreeMap<String, String>>,
@@ -66,6 +66,25 @@ impl CombinedJson {
         })
     }
 
+    ///
+    /// Returns the contract with the specified fully-qualified `path:Name`.
+    ///
+    /// The exact match is preferred. Otherwise, the `solc` path is matched as a suffix starting
+    /// at a path separator, since it may be relative to the base path.
+    ///
+    pub fn contract_mut(&mut self, full_path: &str) -> Option<&mut Contract> {
+        if self.contracts.contains_key(full_path) {
+            return self.contracts.get_mut(full_path);
+        }
+
+        self.contracts.iter_mut().find_map(|(json_path, contract)| {
+            full_path
+                .strip_suffix(json_path.as_str())
+                .filter(|prefix| prefix.ends_with('/'))
+                .map(|_| contract)
+        })
+    }
+
     ///
     /// Writes the JSON to the specified directory.
     ///
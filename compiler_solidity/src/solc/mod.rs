@@ -4,6 +4,7 @@
 
 pub mod combined_json;
 pub mod pipeline;
+pub mod source_map;
 pub mod standard_json;
 pub mod version;
 
@@ -43,6 +44,17 @@ impl Compiler {
         Self { executable }
     }
 
+    ///
+    /// Returns the subprocess command line, which is printed on failures to ease reproduction.
+    ///
+    fn command_line(command: &std::process::Command) -> String {
+        std::iter::once(command.get_program())
+            .chain(command.get_args())
+            .map(|argument| argument.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     ///
     /// Compiles the Solidity `--standard-json` input into Yul IR.
     ///
@@ -72,9 +84,15 @@ impl Compiler {
         }
 
         let input_json = serde_json::to_vec(&input).expect("Always valid");
+        let command_line = Self::command_line(&command);
 
         let process = command.spawn().map_err(|error| {
-            anyhow::anyhow!("{} subprocess spawning error: {:?}", self.executable, error)
+            anyhow::anyhow!(
+                "{} subprocess spawning error: {:?}\nCommand: {}",
+                self.executable,
+                error,
+                command_line
+            )
         })?;
         process
             .stdin
@@ -82,7 +100,12 @@ impl Compiler {
             .ok_or_else(|| anyhow::anyhow!("{} stdin getting error", self.executable))?
             .write_all(input_json.as_slice())
             .map_err(|error| {
-                anyhow::anyhow!("{} stdin writing error: {:?}", self.executable, error)
+                anyhow::anyhow!(
+                    "{} stdin writing error: {:?}\nCommand: {}",
+                    self.executable,
+                    error,
+                    command_line
+                )
             })?;
 
         let output = process.wait_with_output().map_err(|error| {
@@ -90,9 +113,10 @@ impl Compiler {
         })?;
         if !output.status.success() {
             anyhow::bail!(
-                "{} error: {}",
+                "{} error: {}\nCommand: {}",
                 self.executable,
-                String::from_utf8_lossy(output.stderr.as_slice()).to_string()
+                String::from_utf8_lossy(output.stderr.as_slice()).to_string(),
+                command_line
             );
         }
 
@@ -179,7 +203,38 @@ impl Compiler {
             );
         }
 
-        Ok(String::from_utf8_lossy(output.stdout.as_slice()).to_string())
+        Ok(Self::sort_extra_output(
+            String::from_utf8_lossy(output.stdout.as_slice()).as_ref(),
+        ))
+    }
+
+    ///
+    /// Sorts the `solc` extra output sections by their `======= <path>:<name> =======` headers,
+    /// so the printed text is stable regardless of the order the contracts are emitted in.
+    ///
+    pub fn sort_extra_output(output: &str) -> String {
+        let mut preamble = String::new();
+        let mut sections: Vec<(String, String)> = Vec::new();
+        for line in output.split_inclusive('\n') {
+            let trimmed = line.trim_end();
+            if trimmed.starts_with("======= ") && trimmed.ends_with(" =======") {
+                sections.push((trimmed.to_owned(), line.to_owned()));
+                continue;
+            }
+
+            match sections.last_mut() {
+                Some((_header, section)) => section.push_str(line),
+                None => preamble.push_str(line),
+            }
+        }
+        sections.sort_by(|(header_1, _), (header_2, _)| header_1.cmp(header_2));
+
+        sections
+            .into_iter()
+            .fold(preamble, |mut result, (_header, section)| {
+                result.push_str(section.as_str());
+                result
+            })
     }
 
     ///
@@ -227,3 +282,69 @@ impl Compiler {
         Ok(Version::new(long, default))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::solc::pipeline::Pipeline as SolcPipeline;
+    use crate::solc::standard_json::input::settings::Settings as StandardJsonInputSettings;
+    use crate::solc::standard_json::input::Input as StandardJsonInput;
+
+    use super::Compiler;
+
+    #[cfg(unix)]
+    #[test]
+    fn error_standard_json_command_line() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let directory =
+            std::env::temp_dir().join(format!("zksolc-solc-stub-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("Always valid");
+        let executable = directory.join("solc");
+        std::fs::write(
+            &executable,
+            "#!/bin/sh\ncat > /dev/null\necho 'stub failure' >&2\nexit 1\n",
+        )
+        .expect("Always valid");
+        std::fs::set_permissions(&executable, std::fs::Permissions::from_mode(0o755))
+            .expect("Always valid");
+
+        let input = StandardJsonInput::try_from_sources(
+            BTreeMap::new(),
+            BTreeMap::new(),
+            StandardJsonInputSettings::get_output_selection(vec![], SolcPipeline::Yul),
+            false,
+        )
+        .expect("Always valid");
+        let executable = executable.to_string_lossy().to_string();
+        let error = Compiler::new(executable.clone())
+            .standard_json(
+                input,
+                Some("/base".to_owned()),
+                vec!["/include".to_owned()],
+                Some("/allow".to_owned()),
+            )
+            .expect_err("Must be an error");
+        assert!(error.to_string().contains("stub failure"));
+        assert!(error.to_string().ends_with(
+            format!(
+                "\nCommand: {} --standard-json --base-path /base --include-path /include --allow-paths /allow",
+                executable
+            )
+            .as_str()
+        ));
+
+        std::fs::remove_dir_all(&directory).expect("Always valid");
+    }
+
+    #[test]
+    fn sort_extra_output() {
+        let first = "======= test.sol:First =======\nFunction signatures:\n26121ff0: f()\n\n";
+        let second = "======= test.sol:Second =======\nFunction signatures:\ne2179b8e: g()\n\n";
+
+        let sorted = Compiler::sort_extra_output(format!("\n{}{}", second, first).as_str());
+        assert_eq!(sorted, format!("\n{}{}", first, second));
+        assert_eq!(Compiler::sort_extra_output(sorted.as_str()), sorted);
+    }
+}
@@ -0,0 +1,26 @@
+//!
+//! The `solc` source map entry jump type.
+//!
+
+///
+/// The `solc` source map entry jump type.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jump {
+    /// The jump into a function.
+    In,
+    /// The return from a function.
+    Out,
+    /// The regular jump or a non-jump instruction.
+    Regular,
+}
+
+impl std::fmt::Display for Jump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::In => write!(f, "i"),
+            Self::Out => write!(f, "o"),
+            Self::Regular => write!(f, "-"),
+        }
+    }
+}
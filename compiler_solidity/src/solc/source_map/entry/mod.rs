@@ -0,0 +1,58 @@
+//!
+//! The `solc` source map entry.
+//!
+
+pub mod jump;
+
+use self::jump::Jump;
+
+///
+/// The `solc` source map entry.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    /// The byte offset of the range start in the source file, or `-1` if unknown.
+    pub start: isize,
+    /// The length of the range in bytes, or `-1` if unknown.
+    pub length: isize,
+    /// The source file index, or `-1` if unknown.
+    pub source: isize,
+    /// The jump type.
+    pub jump: Jump,
+    /// The modifier depth.
+    pub modifier_depth: usize,
+}
+
+impl Entry {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        start: isize,
+        length: isize,
+        source: isize,
+        jump: Jump,
+        modifier_depth: usize,
+    ) -> Self {
+        Self {
+            start,
+            length,
+            source,
+            jump,
+            modifier_depth,
+        }
+    }
+
+    ///
+    /// Returns the `s:l:f:j:m` fields as strings.
+    ///
+    pub fn fields(&self) -> [String; 5] {
+        [
+            self.start.to_string(),
+            self.length.to_string(),
+            self.source.to_string(),
+            self.jump.to_string(),
+            self.modifier_depth.to_string(),
+        ]
+    }
+}
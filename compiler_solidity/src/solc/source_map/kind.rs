@@ -0,0 +1,20 @@
+//!
+//! The `solc` source map kind.
+//!
+
+///
+/// The `solc` source map kind, which tells the source code the entry offsets point into.
+///
+/// Neither kind maps the zkEVM bytecode instructions, so the source maps are written under
+/// their own keys instead of the `solc` ones.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// One entry per Yul statement, pointing into the Yul source code of the contract, i.e. the
+    /// `solc` `irOptimized` output or the `--yul` input file, which is the only source and has
+    /// the index `0`.
+    Yul,
+    /// One entry per EVM legacy assembly instruction, pointing into the original sources with
+    /// the `solc` source indexes.
+    EVMLegacyAssembly,
+}
@@ -0,0 +1,84 @@
+//!
+//! The `solc` source map.
+//!
+
+pub mod entry;
+pub mod kind;
+
+use self::entry::Entry;
+use self::kind::Kind;
+
+///
+/// The `solc` source map.
+///
+/// It is written in the compressed `s:l:f:j:m` format, where the entries are separated with `;`,
+/// the fields equal to those of the previous entry are left empty, and the trailing empty fields
+/// are omitted along with their colons.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceMap {
+    /// The source map kind.
+    pub kind: Kind,
+    /// The source map entries.
+    pub entries: Vec<Entry>,
+}
+
+impl SourceMap {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(kind: Kind, entries: Vec<Entry>) -> Self {
+        Self { kind, entries }
+    }
+}
+
+impl std::fmt::Display for SourceMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut previous: Option<[String; 5]> = None;
+        for (index, entry) in self.entries.iter().enumerate() {
+            if index > 0 {
+                write!(f, ";")?;
+            }
+
+            let fields = entry.fields();
+            let mut compressed: Vec<&str> = fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| match previous {
+                    Some(ref previous) if previous[index] == *field => "",
+                    _ => field.as_str(),
+                })
+                .collect();
+            while compressed.last() == Some(&"") {
+                compressed.pop();
+            }
+            write!(f, "{}", compressed.join(":"))?;
+
+            previous = Some(fields);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::entry::jump::Jump;
+    use super::entry::Entry;
+    use super::kind::Kind;
+    use super::SourceMap;
+
+    #[test]
+    fn compression() {
+        let source_map = SourceMap::new(
+            Kind::Yul,
+            vec![
+                Entry::new(0, 10, 0, Jump::Regular, 0),
+                Entry::new(0, 10, 0, Jump::Regular, 0),
+                Entry::new(2, 10, 0, Jump::In, 0),
+                Entry::new(2, 4, 1, Jump::In, 1),
+            ],
+        );
+        assert_eq!(source_map.to_string(), "0:10:0:-:0;;2:::i;:4:1::1");
+    }
+}
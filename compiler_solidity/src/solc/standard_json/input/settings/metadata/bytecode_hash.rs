@@ -0,0 +1,46 @@
+//!
+//! The metadata bytecode hash mode.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The metadata bytecode hash mode.
+///
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BytecodeHash {
+    /// No metadata hash is appended.
+    #[serde(rename = "none")]
+    None,
+    /// The IPFS hash, which is the `solc` default.
+    #[default]
+    #[serde(rename = "ipfs")]
+    Ipfs,
+    /// The Swarm hash.
+    #[serde(rename = "bzzr1")]
+    Bzzr1,
+}
+
+impl std::str::FromStr for BytecodeHash {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "none" => Ok(Self::None),
+            "ipfs" => Ok(Self::Ipfs),
+            "bzzr1" => Ok(Self::Bzzr1),
+            string => anyhow::bail!("Unknown metadata bytecode hash mode `{}`", string),
+        }
+    }
+}
+
+impl std::fmt::Display for BytecodeHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Ipfs => write!(f, "ipfs"),
+            Self::Bzzr1 => write!(f, "bzzr1"),
+        }
+    }
+}
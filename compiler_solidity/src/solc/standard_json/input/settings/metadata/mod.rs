@@ -0,0 +1,50 @@
+//!
+//! The `solc --standard-json` input settings metadata.
+//!
+
+pub mod bytecode_hash;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use self::bytecode_hash::BytecodeHash;
+
+///
+/// The `solc --standard-json` input settings metadata.
+///
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    /// The bytecode hash mode, which is passed to `solc` as is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytecode_hash: Option<BytecodeHash>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solc::standard_json::input::settings::Settings;
+
+    use super::bytecode_hash::BytecodeHash;
+
+    #[test]
+    fn deserialize_bytecode_hash() {
+        let settings: Settings = serde_json::from_str(
+            r#"{
+    "outputSelection": {},
+    "optimizer": { "enabled": true },
+    "metadata": { "bytecodeHash": "none" }
+}"#,
+        )
+        .expect("Always valid");
+        assert_eq!(
+            settings
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.bytecode_hash),
+            Some(BytecodeHash::None)
+        );
+
+        let serialized = serde_json::to_value(&settings).expect("Always valid");
+        assert_eq!(serialized["metadata"]["bytecodeHash"], "none");
+    }
+}
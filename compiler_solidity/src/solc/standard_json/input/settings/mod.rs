@@ -2,6 +2,7 @@
 //! The `solc --standard-json` input settings representation.
 //!
 
+pub mod metadata;
 pub mod optimizer;
 pub mod selection;
 
@@ -10,11 +11,22 @@ use std::collections::BTreeMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::linker::ADDRESS_HEX_LENGTH;
 use crate::solc::pipeline::Pipeline as SolcPipeline;
 
+use self::metadata::Metadata;
 use self::optimizer::Optimizer;
 use self::selection::Selection;
 
+///
+/// The user output selections passed through to the output, which do not depend on the EVM code.
+///
+/// The other selections, e.g. `evm.bytecode`, are produced by `zksolc`, so requesting them from
+/// `solc` only slows the compilation down.
+///
+pub const PASSTHROUGH_SELECTIONS: [&str; 5] =
+    ["abi", "metadata", "devdoc", "userdoc", "storageLayout"];
+
 ///
 /// The `solc --standard-json` input settings representation.
 ///
@@ -25,9 +37,17 @@ pub struct Settings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub libraries: Option<BTreeMap<String, BTreeMap<String, String>>>,
     /// The output selection filters.
+    #[serde(default)]
     pub output_selection: serde_json::Value,
     /// The optimizer settings.
     pub optimizer: Optimizer,
+    /// The `zksolc`-specific per-contract optimizer settings, keyed by the full contract path.
+    /// Must be taken out before passing the input to `solc`, which rejects unknown keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optimizer_overrides: Option<BTreeMap<String, Optimizer>>,
+    /// The metadata settings, which are passed to `solc` as is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
 }
 
 impl Settings {
@@ -43,6 +63,8 @@ impl Settings {
             libraries: Some(libraries),
             output_selection,
             optimizer: Optimizer::new(optimize),
+            optimizer_overrides: None,
+            metadata: None,
         }
     }
 
@@ -57,6 +79,8 @@ impl Settings {
             files.push("*".to_owned());
         }
 
+        // The AST is always requested, since the warnings are extracted from it, but it is only
+        // passed through to the standard JSON output with `--output-ast`.
         let general_selections = vec![Selection::AST];
         let per_contract_selections = vec![
             Selection::ABI,
@@ -81,6 +105,76 @@ impl Settings {
         serde_json::Value::Object(map)
     }
 
+    ///
+    /// Merges the `required` output selection into the existing one, e.g. the one from the
+    /// user's standard JSON input, so the extra outputs requested by the user are preserved.
+    ///
+    /// Only the existing selections from `PASSTHROUGH_SELECTIONS` are kept. The selections are
+    /// merged per file and contract, skipping the duplicates. The existing selection is replaced
+    /// if it is not an object.
+    ///
+    pub fn extend_output_selection(&mut self, required: serde_json::Value) {
+        if let Some(output_selection) = self.output_selection.as_object_mut() {
+            for contracts in output_selection.values_mut() {
+                for selections in contracts
+                    .as_object_mut()
+                    .into_iter()
+                    .flat_map(|contracts| contracts.values_mut())
+                {
+                    if let Some(selections) = selections.as_array_mut() {
+                        selections.retain(|selection| {
+                            selection
+                                .as_str()
+                                .map(|selection| PASSTHROUGH_SELECTIONS.contains(&selection))
+                                .unwrap_or_default()
+                        });
+                    }
+                }
+            }
+        }
+
+        let (output_selection, required) = match (self.output_selection.as_object_mut(), required) {
+            (Some(output_selection), serde_json::Value::Object(required)) => {
+                (output_selection, required)
+            }
+            (_, required) => {
+                self.output_selection = required;
+                return;
+            }
+        };
+
+        for (file, contracts) in required.into_iter() {
+            let file_selection = output_selection
+                .entry(file)
+                .or_insert_with(|| serde_json::json!({}));
+            let (file_selection, contracts) = match (file_selection.as_object_mut(), contracts) {
+                (Some(file_selection), serde_json::Value::Object(contracts)) => {
+                    (file_selection, contracts)
+                }
+                (_, contracts) => {
+                    *file_selection = contracts;
+                    continue;
+                }
+            };
+
+            for (contract, selections) in contracts.into_iter() {
+                let contract_selection = file_selection
+                    .entry(contract)
+                    .or_insert_with(|| serde_json::json!([]));
+                match (contract_selection.as_array_mut(), selections) {
+                    (Some(contract_selection), serde_json::Value::Array(selections)) => {
+                        for selection in selections.into_iter() {
+                            if !contract_selection.contains(&selection) {
+                                contract_selection.push(selection);
+                            }
+                        }
+                    }
+                    (_, selections) => *contract_selection = selections,
+                }
+            }
+        }
+    }
+
     ///
     /// Generates the AST output selection pattern.
     ///
@@ -103,6 +197,9 @@ impl Settings {
     ///
     /// Parses the library list and returns their double hashmap with path and name as keys.
     ///
+    /// The addresses are accepted with or without the `0x` prefix and stored in the canonical
+    /// lowercase form with the prefix.
+    ///
     pub fn parse_libraries(
         input: Vec<String>,
     ) -> anyhow::Result<BTreeMap<String, BTreeMap<String, String>>> {
@@ -122,11 +219,98 @@ impl Settings {
             let address = path_and_address
                 .next()
                 .ok_or_else(|| anyhow::anyhow!("The library `{}` address is missing", path))?;
+            let address = Self::normalize_library_address(address).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The library `{}` address `{}` must be {} hexadecimal digits long, optionally prefixed with `0x`",
+                    path,
+                    address,
+                    ADDRESS_HEX_LENGTH,
+                )
+            })?;
             libraries
                 .entry(file.to_owned())
                 .or_insert_with(BTreeMap::new)
-                .insert(contract.to_owned(), address.to_owned());
+                .insert(contract.to_owned(), address);
         }
         Ok(libraries)
     }
+
+    ///
+    /// Returns the library address in the canonical lowercase form with the `0x` prefix, or
+    /// `None` if the address is not a valid hexadecimal address.
+    ///
+    fn normalize_library_address(address: &str) -> Option<String> {
+        let address = address.strip_prefix("0x").unwrap_or(address);
+        if address.len() != ADDRESS_HEX_LENGTH || hex::decode(address).is_err() {
+            return None;
+        }
+        Some(format!("0x{}", address.to_lowercase()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solc::pipeline::Pipeline as SolcPipeline;
+
+    use super::Settings;
+
+    const ADDRESS: &str = "0x1234567890abcdef1234567890ABCDEF12345678";
+
+    #[test]
+    fn extend_output_selection() {
+        let mut settings: Settings = serde_json::from_str(
+            r#"{
+    "outputSelection": {
+        "*": {
+            "*": ["storageLayout", "abi", "evm.bytecode", "evm.methodIdentifiers"]
+        }
+    },
+    "optimizer": {
+        "enabled": true
+    }
+}"#,
+        )
+        .expect("Always valid");
+
+        settings.extend_output_selection(Settings::get_output_selection(vec![], SolcPipeline::Yul));
+        assert_eq!(
+            settings.output_selection,
+            serde_json::json!({
+                "*": {
+                    "*": ["storageLayout", "abi", "irOptimized"],
+                    "": ["ast"]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn parse_libraries_prefixed() {
+        let libraries = Settings::parse_libraries(vec![format!("test.sol:Library={}", ADDRESS)])
+            .expect("Always valid");
+        assert_eq!(
+            libraries["test.sol"]["Library"],
+            ADDRESS.to_lowercase().as_str()
+        );
+    }
+
+    #[test]
+    fn parse_libraries_unprefixed() {
+        let libraries =
+            Settings::parse_libraries(vec![format!("test.sol:Library={}", &ADDRESS["0x".len()..])])
+                .expect("Always valid");
+        assert_eq!(
+            libraries["test.sol"]["Library"],
+            ADDRESS.to_lowercase().as_str()
+        );
+    }
+
+    #[test]
+    fn error_parse_libraries_invalid_length() {
+        let error = Settings::parse_libraries(vec!["test.sol:Library=0x1234".to_owned()])
+            .expect_err("Must be an error");
+        assert!(error.to_string().contains(
+            "The library `test.sol:Library` address `0x1234` must be 40 hexadecimal digits long"
+        ));
+    }
 }
@@ -8,18 +8,53 @@ use serde::Serialize;
 ///
 /// The `solc --standard-json` input settings optimizer representation.
 ///
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Optimizer {
     /// Whether the optimizer is enabled.
     pub enabled: bool,
+    /// The expected number of contract runs, which trades the bytecode size for speed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runs: Option<usize>,
 }
 
 impl Optimizer {
+    /// The number of runs below which the bytecode size is optimized instead of cycles.
+    pub const SIZE_RUNS_THRESHOLD: usize = 200;
+
     ///
     /// A shortcut constructor.
     ///
     pub fn new(enabled: bool) -> Self {
-        Self { enabled }
+        Self {
+            enabled,
+            runs: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor with the expected number of contract runs.
+    ///
+    pub fn new_with_runs(enabled: bool, runs: Option<usize>) -> Self {
+        Self { enabled, runs }
+    }
+
+    ///
+    /// Returns the LLVM optimizer settings.
+    ///
+    /// Like in `solc`, a low number of runs favors the bytecode size, while the cycles are
+    /// optimized by default.
+    ///
+    pub fn settings(&self) -> compiler_llvm_context::OptimizerSettings {
+        if !self.enabled {
+            return compiler_llvm_context::OptimizerSettings::none();
+        }
+
+        match self.runs {
+            Some(runs) if runs < Self::SIZE_RUNS_THRESHOLD => {
+                compiler_llvm_context::OptimizerSettings::size()
+            }
+            _ => compiler_llvm_context::OptimizerSettings::cycles(),
+        }
     }
 }
@@ -0,0 +1,26 @@
+//!
+//! The `solc --standard-json` output contract EVM bytecode link reference.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The `solc --standard-json` output contract EVM bytecode link reference.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct LinkReference {
+    /// The byte offset of the library address in the bytecode.
+    pub start: usize,
+    /// The library address length in bytes.
+    pub length: usize,
+}
+
+impl LinkReference {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: usize, length: usize) -> Self {
+        Self { start, length }
+    }
+}
@@ -0,0 +1,92 @@
+//!
+//! The `solc --standard-json` output contract EVM bytecode.
+//!
+
+pub mod link_reference;
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::solc::source_map::kind::Kind as SourceMapKind;
+use crate::solc::source_map::SourceMap;
+
+use self::link_reference::LinkReference;
+
+///
+/// The `solc --standard-json` output contract EVM bytecode.
+///
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Bytecode {
+    /// The bytecode object.
+    pub object: String,
+    /// The Yul source map, if requested for the Yul pipeline.
+    #[serde(rename = "yulSourceMap", skip_serializing_if = "Option::is_none")]
+    pub yul_source_map: Option<String>,
+    /// The EVM legacy assembly source map, if requested for the EVM legacy assembly pipeline.
+    #[serde(
+        rename = "legacyAssemblySourceMap",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub legacy_assembly_source_map: Option<String>,
+    /// The unlinked library references, keyed by the library file path and name.
+    #[serde(rename = "linkReferences", skip_serializing_if = "Option::is_none")]
+    pub link_references: Option<BTreeMap<String, BTreeMap<String, Vec<LinkReference>>>>,
+}
+
+impl Bytecode {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(object: String) -> Self {
+        Self {
+            object,
+            yul_source_map: None,
+            legacy_assembly_source_map: None,
+            link_references: None,
+        }
+    }
+
+    ///
+    /// Sets the source map under the key of its kind.
+    ///
+    pub fn set_source_map(&mut self, source_map: &SourceMap) {
+        let value = Some(source_map.to_string());
+        match source_map.kind {
+            SourceMapKind::Yul => self.yul_source_map = value,
+            SourceMapKind::EVMLegacyAssembly => self.legacy_assembly_source_map = value,
+        }
+    }
+
+    ///
+    /// Sets the link references from the byte offsets of the unlinked libraries, keyed by the
+    /// fully qualified library path.
+    ///
+    /// Does nothing if there are no unlinked libraries.
+    ///
+    pub fn set_link_references(&mut self, offsets: &BTreeMap<String, Vec<usize>>) {
+        if offsets.is_empty() {
+            return;
+        }
+
+        let mut link_references: BTreeMap<String, BTreeMap<String, Vec<LinkReference>>> =
+            BTreeMap::new();
+        for (path, offsets) in offsets.iter() {
+            let (file_path, name) = path.rsplit_once(':').unwrap_or(("", path.as_str()));
+            link_references
+                .entry(file_path.to_owned())
+                .or_default()
+                .insert(
+                    name.to_owned(),
+                    offsets
+                        .iter()
+                        .map(|offset| {
+                            LinkReference::new(*offset, crate::linker::ADDRESS_HEX_LENGTH / 2)
+                        })
+                        .collect(),
+                );
+        }
+        self.link_references = Some(link_references);
+    }
+}
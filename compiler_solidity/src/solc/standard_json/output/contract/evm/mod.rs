@@ -22,6 +22,10 @@ pub struct EVM {
     /// The contract bytecode.
     /// Is reset by that of zkEVM before yielding the compiled project artifacts.
     pub bytecode: Option<Bytecode>,
+    /// The contract deployed bytecode.
+    /// Only set along with the runtime code source map, as zkEVM has a single bytecode.
+    #[serde(rename = "deployedBytecode", skip_serializing_if = "Option::is_none")]
+    pub deployed_bytecode: Option<Bytecode>,
 }
 
 impl EVM {
@@ -32,6 +36,7 @@ impl EVM {
         Self {
             assembly: None,
             bytecode: Some(Bytecode::new(bytecode)),
+            deployed_bytecode: None,
         }
     }
 }
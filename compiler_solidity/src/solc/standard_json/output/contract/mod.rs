@@ -9,6 +9,8 @@ use std::collections::BTreeMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+
 use self::evm::EVM;
 
 ///
@@ -32,4 +34,7 @@ pub struct Contract {
     /// The contract's zkEVM bytecode hash.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hash: Option<String>,
+    /// The warnings located in the contract, which are also present in the global list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<SolcStandardJsonOutputError>>,
 }
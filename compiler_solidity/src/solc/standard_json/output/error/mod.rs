@@ -4,11 +4,14 @@
 
 pub mod source_location;
 
+use std::collections::HashSet;
 use std::str::FromStr;
 
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::yul::lexer::token::location::Location;
+
 use self::source_location::SourceLocation;
 
 ///
@@ -110,6 +113,302 @@ impl Error {
         }
     }
 
+    ///
+    /// Returns the non-terminating runtime code warning.
+    ///
+    pub fn warning_runtime_code_not_terminating() -> Self {
+        let message = r#"
+┌──────────────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: The runtime code has a path which reaches its end without a terminating instruction,    │
+│ such as 'return', 'revert', or 'stop'. It is likely a bug in the contract source code.           │
+└──────────────────────────────────────────────────────────────────────────────────────────────────┘"#
+            .to_owned();
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the likely swapped deploy and runtime code warning.
+    ///
+    pub fn warning_deploy_and_runtime_code_swapped() -> Self {
+        let message = r#"
+┌──────────────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: The deploy and runtime code look swapped, since the runtime object references itself    │
+│ with 'dataoffset' or 'datasize', while the deploy code does not reference the runtime object.    │
+│ Such a contract is likely to be broken. Please check that the objects are not mislabeled.        │
+└──────────────────────────────────────────────────────────────────────────────────────────────────┘"#
+            .to_owned();
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the shadowed loop variable warning.
+    ///
+    pub fn warning_shadowed_loop_variable(name: &str, location: Location) -> Self {
+        let variable = format!("Variable `{}` at {}.", name, location);
+        let message = format!(
+            r#"
+┌──────────────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: A `for` loop variable shadows a variable of an enclosing scope, e.g. an outer loop      │
+│ counter. Assigning it in the loop may clobber the outer variable by mistake.                     │
+│ {:<96} │
+└──────────────────────────────────────────────────────────────────────────────────────────────────┘"#,
+            variable
+        );
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the unassigned function return variable warning.
+    ///
+    pub fn warning_unassigned_function_result(
+        function: &str,
+        variable: &str,
+        location: Location,
+    ) -> Self {
+        let variable = format!(
+            "Variable `{}` of function `{}` at {}.",
+            variable, function, location
+        );
+        let message = format!(
+            r#"
+┌──────────────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: A function return variable is never assigned, so the function always returns zero in   │
+│ it. It is likely a bug in the contract source code.                                              │
+│ {:<96} │
+└──────────────────────────────────────────────────────────────────────────────────────────────────┘"#,
+            variable
+        );
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the deploy code `stop` warning.
+    ///
+    pub fn warning_deploy_code_stop(location: Location) -> Self {
+        let call = format!("The `stop` call at {}.", location);
+        let message = format!(
+            r#"
+┌──────────────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: The deploy code is stopped instead of returning the runtime code, so the contract is    │
+│ deployed without code. Use 'return' or 'revert' to leave the deploy code.                        │
+│ {:<96} │
+└──────────────────────────────────────────────────────────────────────────────────────────────────┘"#,
+            call
+        );
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the large constant `create` input size warning.
+    ///
+    pub fn warning_large_create_input_size(size: &num::BigUint, location: Location) -> Self {
+        let call = format!(
+            "The call at {} with the input size of {} bytes.",
+            location, size
+        );
+        let message = format!(
+            r#"
+┌──────────────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: The contract is created with a large constant input size, which is likely a bug and     │
+│ is not going to fit into the heap.                                                               │
+│ {:<96} │
+└──────────────────────────────────────────────────────────────────────────────────────────────────┘"#,
+            call
+        );
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the `keccak256` over the reserved memory slots warning.
+    ///
+    pub fn warning_reserved_memory_hash(location: Location) -> Self {
+        let call = format!("The `keccak256` call at {}.", location);
+        let message = format!(
+            r#"
+┌──────────────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: The hashed constant memory range overlaps the memory pointer or the empty slot, which   │
+│ is likely a bug. Only the scratch space below the offset `64` is meant to be hashed in place.    │
+│ {:<96} │
+└──────────────────────────────────────────────────────────────────────────────────────────────────┘"#,
+            call
+        );
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the overlapping `mstore8` and `mstore` warning.
+    ///
+    pub fn warning_overlapping_byte_and_word_stores(
+        byte_location: Location,
+        word_location: Location,
+    ) -> Self {
+        let calls = format!(
+            "The `mstore8` call at {} and the `mstore` call at {}.",
+            byte_location, word_location
+        );
+        let message = format!(
+            r#"
+┌──────────────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: A byte and a word are stored to overlapping constant offsets, so one of them may be     │
+│ clobbered by the other. Make sure the overlap is intended.                                       │
+│ {:<96} │
+└──────────────────────────────────────────────────────────────────────────────────────────────────┘"#,
+            calls
+        );
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the zero library address warning.
+    ///
+    pub fn warning_zero_library_address(path: &str) -> Self {
+        let library = format!("Library `{}`.", path);
+        let message = format!(
+            r#"
+┌──────────────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: A library is linked with the zero address, which usually means it has not been          │
+│ deployed yet. Calls to the library will fail until the bytecode is linked with its real address. │
+│ {:<96} │
+└──────────────────────────────────────────────────────────────────────────────────────────────────┘"#,
+            library
+        );
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the unlinked library warning.
+    ///
+    pub fn warning_unlinked_library(path: &str) -> Self {
+        let library = format!("Library `{}`.", path);
+        let message = format!(
+            r#"
+┌──────────────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: A library is not linked, so it is compiled with a placeholder address, which is         │
+│ reported in the link references. Calls to the library will fail until it is linked.              │
+│ {:<96} │
+└──────────────────────────────────────────────────────────────────────────────────────────────────┘"#,
+            library
+        );
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the compilation error, e.g. an LLVM IR generator failure, with the given message.
+    ///
+    pub fn error_compilation(message: String) -> Self {
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: message.clone(),
+            message,
+            severity: "error".to_owned(),
+            source_location: None,
+            r#type: "CompilerError".to_owned(),
+        }
+    }
+
+    ///
+    /// Removes the repeated errors of the same kind reported at the same source location,
+    /// keeping the first occurrence of each.
+    ///
+    pub fn deduplicate(errors: Vec<Self>) -> Vec<Self> {
+        let mut sites = HashSet::with_capacity(errors.len());
+        errors
+            .into_iter()
+            .filter(|error| sites.insert((error.message.clone(), error.source_location.clone())))
+            .collect()
+    }
+
     ///
     /// Appends the contract path to the message..
     ///
@@ -124,3 +423,52 @@ impl std::fmt::Display for Error {
         write!(f, "{}", self.formatted_message)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::project::Project;
+
+    use super::Error;
+
+    #[test]
+    fn error_compilation_json() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                undeclared_function()
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let error = Project::compile_test_yul(input).expect_err("Must be an error");
+
+        let mut error = Error::error_compilation(error.to_string());
+        error.push_contract_path("Test");
+        let json: serde_json::Value = serde_json::from_str(
+            serde_json::to_string(&error)
+                .expect("Always valid")
+                .as_str(),
+        )
+        .expect("Always valid");
+        assert_eq!(json["severity"], "error");
+        assert_eq!(json["type"], "CompilerError");
+        assert!(json["message"]
+            .as_str()
+            .expect("Always exists")
+            .contains("Undeclared function `undeclared_function`"));
+        assert!(json["formattedMessage"]
+            .as_str()
+            .expect("Always exists")
+            .ends_with("--> Test\n"));
+    }
+}
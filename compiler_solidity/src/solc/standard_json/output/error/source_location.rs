@@ -10,7 +10,7 @@ use serde::Serialize;
 ///
 /// The `solc --standard-json` output error source location.
 ///
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct SourceLocation {
     /// The source file path.
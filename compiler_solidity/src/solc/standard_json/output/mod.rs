@@ -7,6 +7,10 @@ pub mod error;
 pub mod source;
 
 use std::collections::BTreeMap;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -22,6 +26,7 @@ use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::object::Object;
 
 use self::contract::Contract;
+use self::error::source_location::SourceLocation;
 use self::error::Error as SolcStandardJsonOutputError;
 use self::source::Source;
 
@@ -65,7 +70,6 @@ impl Output {
         if let SolcPipeline::EVM = pipeline {
             self.preprocess_dependencies()?;
         }
-        self.sources = None;
 
         let files = match self.contracts.as_mut() {
             Some(files) => files,
@@ -80,6 +84,7 @@ impl Output {
             }
         };
         let mut project_contracts = BTreeMap::new();
+        let mut messages = Vec::new();
 
         for (path, contracts) in files.iter_mut() {
             for (name, contract) in contracts.iter_mut() {
@@ -104,6 +109,85 @@ impl Output {
                         let object = Object::parse(&mut lexer, None).map_err(|error| {
                             anyhow::anyhow!("Contract `{}` parsing error: {:?}", full_path, error)
                         })?;
+                        if !object.is_runtime_code_terminating() {
+                            let mut warning =
+                                SolcStandardJsonOutputError::warning_runtime_code_not_terminating();
+                            warning.push_contract_path(full_path.as_str());
+                            contract
+                                .warnings
+                                .get_or_insert_with(Vec::new)
+                                .push(warning.clone());
+                            messages.push(warning);
+                        }
+                        for variable in object.shadowed_loop_variables() {
+                            let mut warning =
+                                SolcStandardJsonOutputError::warning_shadowed_loop_variable(
+                                    variable.inner.as_str(),
+                                    variable.location,
+                                );
+                            warning.push_contract_path(full_path.as_str());
+                            contract
+                                .warnings
+                                .get_or_insert_with(Vec::new)
+                                .push(warning.clone());
+                            messages.push(warning);
+                        }
+                        if object.is_likely_swapped() {
+                            let mut warning =
+                                SolcStandardJsonOutputError::warning_deploy_and_runtime_code_swapped();
+                            warning.push_contract_path(full_path.as_str());
+                            contract
+                                .warnings
+                                .get_or_insert_with(Vec::new)
+                                .push(warning.clone());
+                            messages.push(warning);
+                        }
+                        for location in object.deploy_code_stops() {
+                            let mut warning =
+                                SolcStandardJsonOutputError::warning_deploy_code_stop(location);
+                            warning.push_contract_path(full_path.as_str());
+                            contract
+                                .warnings
+                                .get_or_insert_with(Vec::new)
+                                .push(warning.clone());
+                            messages.push(warning);
+                        }
+                        for (byte_location, word_location) in
+                            object.overlapping_byte_and_word_stores()
+                        {
+                            let mut warning = SolcStandardJsonOutputError::warning_overlapping_byte_and_word_stores(
+                                byte_location,
+                                word_location,
+                            );
+                            warning.push_contract_path(full_path.as_str());
+                            contract
+                                .warnings
+                                .get_or_insert_with(Vec::new)
+                                .push(warning.clone());
+                            messages.push(warning);
+                        }
+                        for (size, location) in object.large_create_input_sizes() {
+                            let mut warning =
+                                SolcStandardJsonOutputError::warning_large_create_input_size(
+                                    &size, location,
+                                );
+                            warning.push_contract_path(full_path.as_str());
+                            contract
+                                .warnings
+                                .get_or_insert_with(Vec::new)
+                                .push(warning.clone());
+                            messages.push(warning);
+                        }
+                        for location in object.reserved_memory_hashes() {
+                            let mut warning =
+                                SolcStandardJsonOutputError::warning_reserved_memory_hash(location);
+                            warning.push_contract_path(full_path.as_str());
+                            contract
+                                .warnings
+                                .get_or_insert_with(Vec::new)
+                                .push(warning.clone());
+                            messages.push(warning);
+                        }
 
                         ProjectContractSource::new_yul(ir_optimized, object)
                     }
@@ -123,6 +207,7 @@ impl Output {
                 project_contracts.insert(full_path, project_contract);
             }
         }
+        self.errors.get_or_insert_with(Vec::new).extend(messages);
 
         Ok(Project::new(
             version.to_owned(),
@@ -131,6 +216,130 @@ impl Output {
         ))
     }
 
+    ///
+    /// Detects the pipeline the output has been produced with.
+    ///
+    /// Returns `None` if the output contains neither Yul nor EVM legacy assembly.
+    ///
+    pub fn pipeline(&self) -> Option<SolcPipeline> {
+        for contract in self.contracts.as_ref()?.values().flat_map(BTreeMap::values) {
+            if contract
+                .ir_optimized
+                .as_ref()
+                .map(|ir_optimized| !ir_optimized.is_empty())
+                .unwrap_or_default()
+            {
+                return Some(SolcPipeline::Yul);
+            }
+            if contract
+                .evm
+                .as_ref()
+                .and_then(|evm| evm.assembly.as_ref())
+                .is_some()
+            {
+                return Some(SolcPipeline::EVM);
+            }
+        }
+
+        None
+    }
+
+    ///
+    /// Downgrades the errors with the specified `solc` error codes to warnings.
+    ///
+    /// The error type prefix of the formatted message, e.g. `TypeError:`, is replaced with
+    /// `Warning:`, since the formatted message is what is printed to the user.
+    ///
+    /// The downgraded errors no longer abort the compilation, so the option must be used with care,
+    /// since `solc` may have produced incomplete output for the affected sources.
+    ///
+    pub fn remap_errors(&mut self, error_codes: &[String]) {
+        let errors = match self.errors.as_mut() {
+            Some(errors) => errors,
+            None => return,
+        };
+
+        for error in errors.iter_mut() {
+            let is_remapped = error
+                .error_code
+                .as_ref()
+                .map(|error_code| error_codes.contains(error_code))
+                .unwrap_or_default();
+            if is_remapped && error.severity.as_str() == "error" {
+                error.severity = "warning".to_owned();
+                let prefix = [format!("{}:", error.r#type), "Error:".to_owned()]
+                    .into_iter()
+                    .find(|prefix| error.formatted_message.starts_with(prefix.as_str()));
+                if let Some(prefix) = prefix {
+                    error.formatted_message =
+                        format!("Warning:{}", &error.formatted_message[prefix.len()..]);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Removes the warnings located in files outside of the project root directory.
+    ///
+    /// The location file may be either a path or a source index, which is resolved using the
+    /// `sources` data. The relative paths are considered relative to the project root. Both the
+    /// project root and the paths are canonicalized before the comparison.
+    ///
+    pub fn remove_dependency_warnings(&mut self, project_root: &Path) {
+        let errors = match self.errors.as_mut() {
+            Some(errors) => errors,
+            None => return,
+        };
+        let sources = self.sources.as_ref();
+        let canonical_root = Self::canonicalize(project_root);
+
+        errors.retain(|error| {
+            if error.severity.as_str() != "warning" {
+                return true;
+            }
+            let file = match error.source_location.as_ref() {
+                Some(source_location) => source_location.file.as_str(),
+                None => return true,
+            };
+
+            let path = match file.parse::<usize>() {
+                Ok(index) => match sources.and_then(|sources| {
+                    sources
+                        .iter()
+                        .find_map(|(path, source)| (source.id == index).then_some(path))
+                }) {
+                    Some(path) => path.as_str(),
+                    None => return true,
+                },
+                Err(_) => file,
+            };
+
+            Self::canonicalize(project_root.join(path).as_path()).starts_with(&canonical_root)
+        });
+    }
+
+    ///
+    /// Canonicalizes the path, or only resolves its `.` and `..` components if it does not
+    /// exist in the file system.
+    ///
+    fn canonicalize(path: &Path) -> PathBuf {
+        if let Ok(path) = path.canonicalize() {
+            return path;
+        }
+
+        let mut result = PathBuf::new();
+        for component in path.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    result.pop();
+                }
+                component => result.push(component),
+            }
+        }
+        result
+    }
+
     ///
     /// The pass, which replaces with dependency indexes with actual data.
     ///
@@ -224,13 +433,67 @@ impl Output {
         };
 
         let mut messages = Vec::new();
+        let mut contract_warnings = BTreeMap::new();
         for (path, source) in sources.iter() {
-            if let Some(ast) = source.ast.as_ref() {
-                let mut warnings = ast.get_warnings()?;
+            if let Some(ast) = source.try_ast()? {
+                let mut warnings = SolcStandardJsonOutputError::deduplicate(ast.get_warnings()?);
                 for warning in warnings.iter_mut() {
                     warning.push_contract_path(path.as_str());
                 }
                 messages.extend(warnings);
+
+                for definition in ast.contract_definitions() {
+                    let name = match definition.name.as_ref() {
+                        Some(name) => name,
+                        None => continue,
+                    };
+                    let mut warnings =
+                        SolcStandardJsonOutputError::deduplicate(definition.get_warnings()?);
+                    for warning in warnings.iter_mut() {
+                        warning.push_contract_path(path.as_str());
+                    }
+
+                    if let Some(range) = definition
+                        .src
+                        .as_deref()
+                        .and_then(|src| SourceLocation::from_str(src).ok())
+                    {
+                        warnings.extend(
+                            self.errors
+                                .iter()
+                                .flatten()
+                                .filter(|error| error.severity.as_str() == "warning")
+                                .filter(|error| match error.source_location.as_ref() {
+                                    Some(location) => {
+                                        location.file == *path
+                                            && location.start >= range.start
+                                            && location.start < range.end
+                                    }
+                                    None => false,
+                                })
+                                .cloned(),
+                        );
+                    }
+
+                    contract_warnings.insert((path.to_owned(), name.to_owned()), warnings);
+                }
+            }
+        }
+
+        for ((path, name), warnings) in contract_warnings.into_iter() {
+            if warnings.is_empty() {
+                continue;
+            }
+            if let Some(contract) = self
+                .contracts
+                .as_mut()
+                .and_then(|files| files.get_mut(path.as_str()))
+                .and_then(|contracts| contracts.get_mut(name.as_str()))
+            {
+                contract
+                    .warnings
+                    .get_or_insert_with(Vec::new)
+                    .extend(warnings);
             }
         }
 
@@ -245,3 +508,669 @@ impl Output {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::Path;
+
+    use crate::solc::pipeline::Pipeline as SolcPipeline;
+    use crate::solc::version::Version as SolcVersion;
+
+    use super::Output;
+    use super::SolcStandardJsonOutputError;
+
+    #[test]
+    fn remap_errors() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "errors": [
+        {
+            "component": "general",
+            "errorCode": "5574",
+            "formattedMessage": "Error: Contract code size exceeds 24576 bytes",
+            "message": "Contract code size exceeds 24576 bytes",
+            "severity": "error",
+            "type": "CodeSizeError"
+        },
+        {
+            "component": "general",
+            "errorCode": "9553",
+            "formattedMessage": "TypeError: Invalid type for argument",
+            "message": "Invalid type for argument",
+            "severity": "error",
+            "type": "TypeError"
+        }
+    ]
+}"#,
+        )
+        .expect("Always valid");
+
+        output.remap_errors(&["5574".to_owned()]);
+
+        let errors = output.errors.expect("Always exists");
+        assert_eq!(errors[0].severity, "warning");
+        assert_eq!(
+            errors[0].formatted_message,
+            "Warning: Contract code size exceeds 24576 bytes"
+        );
+        assert_eq!(errors[1].severity, "error");
+        assert_eq!(
+            errors[1].formatted_message,
+            "TypeError: Invalid type for argument"
+        );
+    }
+
+    #[test]
+    fn compile_without_solc() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Test": {
+                "irOptimized": "object \"Test_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Test_1_deployed\" {\n        code {\n            {\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+        let version = semver::Version::new(0, 8, 17);
+
+        let pipeline = output.pipeline().expect("Always exists");
+        assert_eq!(pipeline, SolcPipeline::Yul);
+
+        let project = output
+            .try_to_project(BTreeMap::new(), pipeline, &version, &[])
+            .expect("Always valid");
+        let build = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid");
+        build
+            .write_to_standard_json(
+                &mut output,
+                &SolcVersion::new(version.to_string(), version.clone()),
+                &semver::Version::new(1, 2, 0),
+            )
+            .expect("Always valid");
+
+        let bytecode = output
+            .contracts
+            .as_ref()
+            .and_then(|files| files.get("test.sol"))
+            .and_then(|contracts| contracts.get("Test"))
+            .and_then(|contract| contract.evm.as_ref())
+            .and_then(|evm| evm.bytecode.as_ref())
+            .map(|bytecode| bytecode.object.as_str())
+            .expect("Always exists");
+        assert!(!bytecode.is_empty());
+    }
+
+    #[test]
+    fn ast_passthrough() {
+        let ast = serde_json::json!({
+            "absolutePath": "test.sol",
+            "exportedSymbols": { "Test": [1] },
+            "id": 2,
+            "nodeType": "SourceUnit",
+            "nodes": [
+                {
+                    "id": 1,
+                    "name": "Test",
+                    "nodeType": "ContractDefinition",
+                    "nodes": [],
+                    "src": "0:17:0"
+                }
+            ],
+            "src": "0:17:0"
+        });
+        let mut output: Output = serde_json::from_value(serde_json::json!({
+            "contracts": {
+                "test.sol": {
+                    "Test": {
+                        "irOptimized": "object \"Test_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Test_1_deployed\" {\n        code {\n            {\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+                    }
+                }
+            },
+            "sources": {
+                "test.sol": { "id": 0, "ast": ast }
+            }
+        }))
+        .expect("Always valid");
+        let version = semver::Version::new(0, 8, 17);
+
+        let project = output
+            .try_to_project(BTreeMap::new(), SolcPipeline::Yul, &version, &[])
+            .expect("Always valid");
+        let build = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid");
+        build
+            .write_to_standard_json(
+                &mut output,
+                &SolcVersion::new(version.to_string(), version.clone()),
+                &semver::Version::new(1, 2, 0),
+            )
+            .expect("Always valid");
+
+        let output = serde_json::to_value(&output).expect("Always valid");
+        assert_eq!(output["sources"]["test.sol"]["ast"], ast);
+    }
+
+    #[test]
+    fn warning_runtime_code_not_terminating() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Falling": {
+                "irOptimized": "object \"Falling_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Falling_1_deployed\" {\n        code {\n            {\n                if calldatasize() {\n                    return(0, 0)\n                }\n                mstore(0, 42)\n            }\n        }\n    }\n}\n"
+            },
+            "Terminating": {
+                "irOptimized": "object \"Terminating_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Terminating_1_deployed\" {\n        code {\n            {\n                if calldatasize() {\n                    return(0, 0)\n                }\n                fail()\n            }\n\n            function fail() {\n                revert(0, 0)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+
+        output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let errors = output.errors.expect("Always exists");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].formatted_message.contains("test.sol:Falling"));
+    }
+
+    #[test]
+    fn warning_deploy_and_runtime_code_swapped() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Normal": {
+                "irOptimized": "object \"Normal_1\" {\n    code {\n        {\n            codecopy(0, dataoffset(\"Normal_1_deployed\"), datasize(\"Normal_1_deployed\"))\n            return(0, datasize(\"Normal_1_deployed\"))\n        }\n    }\n    object \"Normal_1_deployed\" {\n        code {\n            {\n                mstore(0, 42)\n                return(0, 32)\n            }\n        }\n    }\n}\n"
+            },
+            "Swapped": {
+                "irOptimized": "object \"Swapped_1\" {\n    code {\n        {\n            mstore(0, 42)\n            return(0, 32)\n        }\n    }\n    object \"Swapped_1_deployed\" {\n        code {\n            {\n                codecopy(0, dataoffset(\"Swapped_1_deployed\"), datasize(\"Swapped_1_deployed\"))\n                return(0, datasize(\"Swapped_1_deployed\"))\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+
+        output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let errors = output.errors.expect("Always exists");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].formatted_message.contains("look swapped"));
+        assert!(errors[0].formatted_message.contains("test.sol:Swapped"));
+    }
+
+    #[test]
+    fn remove_dependency_warnings() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "sources": {
+        "/project/src/Main.sol": { "id": 0 },
+        "/dependencies/Library.sol": { "id": 1 }
+    },
+    "errors": [
+        {
+            "component": "general",
+            "formattedMessage": "Warning: Unused local variable",
+            "message": "Unused local variable",
+            "severity": "warning",
+            "sourceLocation": { "file": "/project/src/Main.sol", "start": 0, "end": 1 },
+            "type": "Warning"
+        },
+        {
+            "component": "general",
+            "formattedMessage": "Warning: Unused local variable",
+            "message": "Unused local variable",
+            "severity": "warning",
+            "sourceLocation": { "file": "/dependencies/Library.sol", "start": 0, "end": 1 },
+            "type": "Warning"
+        },
+        {
+            "component": "general",
+            "formattedMessage": "Warning: Function state mutability can be restricted to pure",
+            "message": "Function state mutability can be restricted to pure",
+            "severity": "warning",
+            "sourceLocation": { "file": "1", "start": 0, "end": 1 },
+            "type": "Warning"
+        },
+        {
+            "component": "general",
+            "formattedMessage": "Warning: Unused local variable",
+            "message": "Unused local variable",
+            "severity": "warning",
+            "sourceLocation": { "file": "/project/../dependencies/Library.sol", "start": 0, "end": 1 },
+            "type": "Warning"
+        },
+        {
+            "component": "general",
+            "formattedMessage": "Warning: Unused local variable",
+            "message": "Unused local variable",
+            "severity": "warning",
+            "sourceLocation": { "file": "../dependencies/Library.sol", "start": 0, "end": 1 },
+            "type": "Warning"
+        },
+        {
+            "component": "general",
+            "formattedMessage": "TypeError: Invalid type for argument",
+            "message": "Invalid type for argument",
+            "severity": "error",
+            "sourceLocation": { "file": "/dependencies/Library.sol", "start": 0, "end": 1 },
+            "type": "TypeError"
+        }
+    ]
+}"#,
+        )
+        .expect("Always valid");
+
+        output.remove_dependency_warnings(Path::new("/project"));
+
+        let errors = output.errors.expect("Always exists");
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[0]
+                .source_location
+                .as_ref()
+                .map(|location| location.file.as_str()),
+            Some("/project/src/Main.sol")
+        );
+        assert_eq!(errors[1].severity, "error");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn remove_dependency_warnings_symlinked_root() {
+        let directory =
+            std::env::temp_dir().join(format!("zksolc-dependency-warnings-{}", std::process::id()));
+        let project = directory.join("project");
+        let link = directory.join("link");
+        std::fs::create_dir_all(project.join("src")).expect("Always valid");
+        std::fs::write(project.join("src/Main.sol"), "").expect("Always valid");
+        std::os::unix::fs::symlink(project.as_path(), link.as_path()).expect("Always valid");
+
+        let main_path = project.join("src/Main.sol").to_string_lossy().into_owned();
+        let mut output: Output = serde_json::from_value(serde_json::json!({
+            "errors": [
+                {
+                    "component": "general",
+                    "formattedMessage": "Warning: Unused local variable",
+                    "message": "Unused local variable",
+                    "severity": "warning",
+                    "sourceLocation": { "file": main_path, "start": 0, "end": 1 },
+                    "type": "Warning"
+                },
+                {
+                    "component": "general",
+                    "formattedMessage": "Warning: Unused local variable",
+                    "message": "Unused local variable",
+                    "severity": "warning",
+                    "sourceLocation": { "file": "../Library.sol", "start": 0, "end": 1 },
+                    "type": "Warning"
+                }
+            ]
+        }))
+        .expect("Always valid");
+
+        output.remove_dependency_warnings(link.as_path());
+        std::fs::remove_dir_all(directory.as_path()).expect("Always valid");
+
+        let errors = output.errors.expect("Always exists");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0]
+                .source_location
+                .as_ref()
+                .map(|location| location.file.as_str()),
+            Some(main_path.as_str())
+        );
+    }
+
+    #[test]
+    fn contract_warnings() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Test": {
+                "irOptimized": "object \"Test_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Test_1_deployed\" {\n        code {\n            {\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    },
+    "sources": {
+        "test.sol": {
+            "id": 0,
+            "ast": {
+                "nodeType": "SourceUnit",
+                "src": "0:200:0",
+                "nodes": [
+                    {
+                        "nodeType": "ContractDefinition",
+                        "name": "Test",
+                        "src": "0:200:0",
+                        "nodes": [
+                            {
+                                "nodeType": "FunctionCall",
+                                "src": "100:30:0",
+                                "expression": {
+                                    "nodeType": "Identifier",
+                                    "name": "ecrecover"
+                                }
+                            }
+                        ]
+                    }
+                ]
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+        let version = semver::Version::new(0, 8, 17);
+
+        let project = output
+            .try_to_project(BTreeMap::new(), SolcPipeline::Yul, &version, &[])
+            .expect("Always valid");
+        let build = project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .expect("Always valid");
+        build
+            .write_to_standard_json(
+                &mut output,
+                &SolcVersion::new(version.to_string(), version.clone()),
+                &semver::Version::new(1, 2, 0),
+            )
+            .expect("Always valid");
+
+        let is_ecrecover_warning = |error: &SolcStandardJsonOutputError| {
+            error.severity == "warning" && error.message.contains("'ecrecover'")
+        };
+        assert!(output
+            .errors
+            .as_ref()
+            .expect("Always exists")
+            .iter()
+            .any(is_ecrecover_warning));
+        let contract_warnings = output
+            .contracts
+            .as_ref()
+            .and_then(|files| files.get("test.sol"))
+            .and_then(|contracts| contracts.get("Test"))
+            .and_then(|contract| contract.warnings.as_ref())
+            .expect("Always exists");
+        assert_eq!(contract_warnings.len(), 1);
+        assert!(is_ecrecover_warning(&contract_warnings[0]));
+    }
+
+    #[test]
+    fn deduplicate_warnings() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Test": {
+                "irOptimized": "object \"Test_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Test_1_deployed\" {\n        code {\n            {\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    },
+    "sources": {
+        "test.sol": {
+            "id": 0,
+            "ast": {
+                "nodeType": "SourceUnit",
+                "src": "0:200:0",
+                "nodes": [
+                    {
+                        "nodeType": "ContractDefinition",
+                        "name": "Test",
+                        "src": "0:200:0",
+                        "nodes": [
+                            {
+                                "nodeType": "FunctionCall",
+                                "src": "100:30:0",
+                                "expression": {
+                                    "nodeType": "Identifier",
+                                    "name": "ecrecover"
+                                }
+                            },
+                            {
+                                "nodeType": "FunctionCall",
+                                "src": "100:30:0",
+                                "expression": {
+                                    "nodeType": "Identifier",
+                                    "name": "ecrecover"
+                                }
+                            },
+                            {
+                                "nodeType": "FunctionCall",
+                                "src": "150:30:0",
+                                "expression": {
+                                    "nodeType": "Identifier",
+                                    "name": "ecrecover"
+                                }
+                            }
+                        ]
+                    }
+                ]
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+
+        output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let starts: Vec<isize> = output
+            .errors
+            .as_ref()
+            .expect("Always exists")
+            .iter()
+            .filter(|error| error.message.contains("'ecrecover'"))
+            .filter_map(|error| error.source_location.as_ref())
+            .map(|location| location.start)
+            .collect();
+        assert_eq!(starts, vec![100, 150]);
+        let contract_warnings = output
+            .contracts
+            .as_ref()
+            .and_then(|files| files.get("test.sol"))
+            .and_then(|contracts| contracts.get("Test"))
+            .and_then(|contract| contract.warnings.as_ref())
+            .expect("Always exists");
+        assert_eq!(contract_warnings.len(), 2);
+    }
+
+    #[test]
+    fn warning_shadowed_loop_variable() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Test": {
+                "irOptimized": "object \"Test_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Test_1_deployed\" {\n        code {\n            {\n                for { let i := 0 } lt(i, 4) { i := add(i, 1) } {\n                    for { let i := 0 } lt(i, 4) { i := add(i, 1) } {\n                        sstore(i, i)\n                    }\n                    for { let j := 0 } lt(j, 4) { j := add(j, 1) } {\n                        sstore(j, i)\n                    }\n                }\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+
+        output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let errors = output.errors.expect("Always exists");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("Variable `i` at 11:"));
+        assert!(errors[0].formatted_message.contains("test.sol:Test"));
+    }
+
+    #[test]
+    fn warning_deploy_code_stop() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Normal": {
+                "irOptimized": "object \"Normal_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Normal_1_deployed\" {\n        code {\n            {\n                stop()\n            }\n        }\n    }\n}\n"
+            },
+            "Stopping": {
+                "irOptimized": "object \"Stopping_1\" {\n    code {\n        {\n            if callvalue() { stop() }\n            return(0, 0)\n        }\n    }\n    object \"Stopping_1_deployed\" {\n        code {\n            {\n                stop()\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+
+        output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let errors = output.errors.expect("Always exists");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("The `stop` call at 4:"));
+        assert!(errors[0].formatted_message.contains("test.sol:Stopping"));
+    }
+
+    #[test]
+    fn warning_large_create_input_size() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Reasonable": {
+                "irOptimized": "object \"Reasonable_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Reasonable_1_deployed\" {\n        code {\n            {\n                let a := create(0, 0, 0x100)\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+            },
+            "Huge": {
+                "irOptimized": "object \"Huge_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Huge_1_deployed\" {\n        code {\n            {\n                let a := create(0, 0, 0xffffffff)\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+
+        output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let errors = output.errors.expect("Always exists");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0]
+            .message
+            .contains("The call at 10:26 with the input size of 4294967295 bytes."));
+        assert!(errors[0].formatted_message.contains("test.sol:Huge"));
+    }
+
+    #[test]
+    fn warning_overlapping_byte_and_word_stores() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Separate": {
+                "irOptimized": "object \"Separate_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Separate_1_deployed\" {\n        code {\n            {\n                mstore(0, 1)\n                mstore8(32, 2)\n                return(0, 33)\n            }\n        }\n    }\n}\n"
+            },
+            "Overlapping": {
+                "irOptimized": "object \"Overlapping_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Overlapping_1_deployed\" {\n        code {\n            {\n                mstore(0, 1)\n                mstore8(31, 2)\n                return(0, 32)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+
+        output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let errors = output.errors.expect("Always exists");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0]
+            .message
+            .contains("The `mstore8` call at 11:17 and the `mstore` call at 10:17."));
+        assert!(errors[0].formatted_message.contains("test.sol:Overlapping"));
+    }
+
+    #[test]
+    fn warning_reserved_memory_hash() {
+        let mut output: Output = serde_json::from_str(
+            r#"{
+    "contracts": {
+        "test.sol": {
+            "Scratch": {
+                "irOptimized": "object \"Scratch_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Scratch_1_deployed\" {\n        code {\n            {\n                let a := keccak256(0, 0x40)\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+            },
+            "Reserved": {
+                "irOptimized": "object \"Reserved_1\" {\n    code {\n        {\n            return(0, 0)\n        }\n    }\n    object \"Reserved_1_deployed\" {\n        code {\n            {\n                let a := keccak256(0, 0x80)\n                return(0, 0)\n            }\n        }\n    }\n}\n"
+            }
+        }
+    }
+}"#,
+        )
+        .expect("Always valid");
+
+        output
+            .try_to_project(
+                BTreeMap::new(),
+                SolcPipeline::Yul,
+                &semver::Version::new(0, 8, 17),
+                &[],
+            )
+            .expect("Always valid");
+
+        let errors = output.errors.expect("Always exists");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("The `keccak256` call at 10:26."));
+        assert!(errors[0].formatted_message.contains("test.sol:Reserved"));
+    }
+}
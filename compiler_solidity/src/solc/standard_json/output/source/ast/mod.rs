@@ -193,6 +193,17 @@ impl AST {
         ))
     }
 
+    ///
+    /// Returns the contract definition nodes at the top level of the source unit.
+    ///
+    pub fn contract_definitions(&self) -> Vec<&Self> {
+        self.nodes
+            .iter()
+            .flatten()
+            .filter(|node| node.node_type.as_deref() == Some("ContractDefinition"))
+            .collect()
+    }
+
     ///
     /// Returns the list of warnings for some specific parts of the AST.
     ///
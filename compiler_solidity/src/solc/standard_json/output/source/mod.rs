@@ -17,6 +17,19 @@ use self::ast::AST;
 pub struct Source {
     /// The source code ID.
     pub id: usize,
-    /// The source code AST.
-    pub ast: Option<AST>,
+    /// The source code AST, kept as is to be passed through to the output.
+    pub ast: Option<serde_json::Value>,
+}
+
+impl Source {
+    ///
+    /// Parses the source code AST, if it is present.
+    ///
+    pub fn try_ast(&self) -> anyhow::Result<Option<AST>> {
+        self.ast
+            .clone()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|error| anyhow::anyhow!("The AST parsing error: {}", error))
+    }
 }
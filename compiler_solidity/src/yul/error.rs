@@ -3,6 +3,7 @@
 //!
 
 use crate::yul::lexer::error::Error as LexerError;
+use crate::yul::lexer::token::location::Location;
 use crate::yul::parser::error::Error as ParserError;
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
@@ -13,4 +14,62 @@ pub enum Error {
     /// The parser error.
     #[error("Syntax error: {0}")]
     Parser(#[from] ParserError),
+    /// The call to a function, which is neither declared in the scope nor a supported builtin.
+    #[error("{location} {}", unknown_builtin_message(.name, *.is_unsupported))]
+    UnknownBuiltin {
+        /// The function call location.
+        location: Location,
+        /// The function name, as spelled in the source code.
+        name: String,
+        /// Whether the function is an EVM builtin deliberately unsupported on zkEVM, as opposed
+        /// to a genuinely unknown one, e.g. a typo.
+        is_unsupported: bool,
+    },
+    /// The calls to several unknown builtins, ordered by their locations.
+    #[error("{}", unknown_builtins_message(.0))]
+    UnknownBuiltins(Vec<Self>),
+}
+
+impl Error {
+    ///
+    /// Returns the lexer error, if the lexical analysis has failed.
+    ///
+    pub fn as_lexer_error(&self) -> Option<&LexerError> {
+        match self {
+            Self::Lexer(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the parser error, if the syntax analysis has failed.
+    ///
+    pub fn as_parser_error(&self) -> Option<&ParserError> {
+        match self {
+            Self::Parser(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+///
+/// Formats the unknown builtin error message, depending on whether the builtin is unsupported.
+///
+fn unknown_builtin_message(name: &str, is_unsupported: bool) -> String {
+    if is_unsupported {
+        format!("The `{}` instruction is not supported", name.to_uppercase())
+    } else {
+        format!("Undeclared function `{}`", name)
+    }
+}
+
+///
+/// Formats the several unknown builtin errors, one per line.
+///
+fn unknown_builtins_message(errors: &[Error]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join("\n")
 }
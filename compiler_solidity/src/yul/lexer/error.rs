@@ -14,4 +14,14 @@ pub enum Error {
         /// The invalid sequence of characters.
         sequence: String,
     },
+    /// The identifier length limit exceeded error.
+    #[error("{location} Identifier is {length} bytes long, whereas the limit is {limit} bytes")]
+    IdentifierTooLong {
+        /// The identifier location.
+        location: Location,
+        /// The identifier length.
+        length: usize,
+        /// The identifier length limit.
+        limit: usize,
+    },
 }
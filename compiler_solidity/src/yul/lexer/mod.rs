@@ -18,6 +18,12 @@ use self::token::lexeme::Lexeme;
 use self::token::location::Location;
 use self::token::Token;
 
+#[cfg(test)]
+thread_local! {
+    /// The number of characters checked by the lexeme scanners on the current thread.
+    static SCANNED_CHARACTERS: std::cell::Cell<usize> = std::cell::Cell::new(0);
+}
+
 ///
 /// The compiler lexer.
 ///
@@ -30,6 +36,10 @@ pub struct Lexer {
     location: Location,
     /// The peeked lexeme, waiting to be fetched.
     peeked: Option<Token>,
+    /// The maximum identifier length in bytes, if limited.
+    max_identifier_length: Option<usize>,
+    /// The comments skipped so far, with their start locations and delimiters.
+    comments: Vec<(Location, String)>,
 }
 
 impl Lexer {
@@ -44,9 +54,51 @@ impl Lexer {
             offset: 0,
             location: Location::default(),
             peeked: None,
+            max_identifier_length: None,
+            comments: Vec::new(),
         }
     }
 
+    ///
+    /// Limits the identifier length to `length` bytes, making the longer identifiers an error.
+    ///
+    /// The identifiers are not limited by default.
+    ///
+    pub fn set_max_identifier_length(&mut self, length: usize) {
+        self.max_identifier_length = Some(length);
+    }
+
+    ///
+    /// Counts a character checked while scanning a lexeme.
+    ///
+    /// Only for testing purposes.
+    ///
+    #[cfg(test)]
+    pub fn count_scanned_character() {
+        SCANNED_CHARACTERS.with(|scanned| scanned.set(scanned.get() + 1));
+    }
+
+    ///
+    /// Returns the number of characters checked while scanning the lexemes on the current thread
+    /// and resets it.
+    ///
+    /// Only for testing purposes.
+    ///
+    #[cfg(test)]
+    pub fn take_scanned_characters() -> usize {
+        SCANNED_CHARACTERS.with(|scanned| scanned.replace(0))
+    }
+
+    ///
+    /// Returns the comments skipped so far in the source code order.
+    ///
+    /// The comments are kept with their delimiters, e.g. `// text` or `/* text */`, so the
+    /// single-line and multi-line ones can be told apart.
+    ///
+    pub fn comments(&self) -> &[(Location, String)] {
+        self.comments.as_slice()
+    }
+
     ///
     /// Advances the lexer, returning the next lexeme.
     ///
@@ -56,7 +108,11 @@ impl Lexer {
             return Ok(peeked);
         }
 
-        while let Some(character) = self.input.chars().nth(self.offset) {
+        while let Some(character) = self
+            .input
+            .get(self.offset..)
+            .and_then(|rest| rest.chars().next())
+        {
             if character.is_ascii_whitespace() {
                 if character == '\n' {
                     self.location.line += 1;
@@ -69,6 +125,10 @@ impl Lexer {
             }
 
             if let Some(token) = Comment::parse(&self.input[self.offset..]) {
+                let end = (self.offset + token.length).min(self.input.len());
+                let text = self.input[self.offset..end].trim_end_matches(&['\r', '\n'][..]);
+                self.comments.push((self.location, text.to_owned()));
+
                 self.offset += token.length;
                 self.location
                     .shift_down(token.location.line, token.location.column);
@@ -94,6 +154,16 @@ impl Lexer {
             if let Some(mut token) = Identifier::parse(&self.input[self.offset..]) {
                 token.location = self.location;
 
+                if let Some(limit) = self.max_identifier_length {
+                    if token.length > limit {
+                        return Err(Error::IdentifierTooLong {
+                            location: self.location,
+                            length: token.length,
+                            limit,
+                        });
+                    }
+                }
+
                 self.offset += token.length;
                 self.location.shift_right(token.length);
                 return Ok(token);
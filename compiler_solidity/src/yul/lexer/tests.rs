@@ -3,6 +3,7 @@
 //!
 
 use crate::yul::lexer::error::Error;
+use crate::yul::lexer::token::lexeme::identifier::Identifier;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::Lexer;
@@ -89,3 +90,148 @@ object "Test" {
         }
     }
 }
+
+#[test]
+fn long_identifier() {
+    let identifier = format!("_{}", "a".repeat(9_999));
+    let input = format!("let {} := 42", identifier);
+
+    let mut lexer = Lexer::new(input);
+    lexer.next().expect("Always valid");
+    let token = lexer.next().expect("Always valid");
+    assert_eq!(
+        token.lexeme,
+        Lexeme::Identifier(Identifier::new(identifier))
+    );
+    assert_eq!(token.length, 10_000);
+    assert_eq!(token.location, Location::new(1, 5));
+    let token = lexer.next().expect("Always valid");
+    assert_eq!(token.location, Location::new(1, 10_006));
+}
+
+#[test]
+fn long_identifiers_linear_time() {
+    let identifier = "a".repeat(10_000);
+    let input = vec![identifier.as_str(); 100].join(" \n ");
+    let length = input.len();
+
+    Lexer::take_scanned_characters();
+    let mut lexer = Lexer::new(input);
+    let mut count = 0;
+    while lexer.next().expect("Always valid").lexeme != Lexeme::EndOfFile {
+        count += 1;
+    }
+    assert_eq!(count, 100);
+
+    // Each identifier is scanned once as an identifier and once as a possible keyword, with the
+    // terminating character and the first character checked by the integer scanner on top.
+    let scanned = Lexer::take_scanned_characters();
+    assert!(scanned >= 2 * count * identifier.len());
+    assert!(scanned <= 2 * length + 3 * count);
+}
+
+#[test]
+fn error_identifier_too_long() {
+    let input = format!("let {} := 42", "a".repeat(10_000));
+
+    let mut lexer = Lexer::new(input);
+    lexer.set_max_identifier_length(256);
+    lexer.next().expect("Always valid");
+    assert_eq!(
+        lexer.next(),
+        Err(Error::IdentifierTooLong {
+            location: Location::new(1, 5),
+            length: 10_000,
+            limit: 256,
+        })
+    );
+}
+
+#[test]
+fn comments() {
+    let input = r#"
+{
+    // The first statement.
+    mstore(0, "// not a comment") /* inline */ let x := 1
+    /*
+        The function.
+    */
+    function f() -> r {
+        r := 42 // The result.
+    }
+}
+"#;
+
+    let mut lexer = Lexer::new(input.to_owned());
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.next().expect("Always valid");
+        if token.lexeme == Lexeme::EndOfFile {
+            break;
+        }
+        tokens.push(token);
+    }
+
+    assert_eq!(
+        lexer.comments(),
+        &[
+            (Location::new(3, 5), "// The first statement.".to_owned()),
+            (Location::new(4, 35), "/* inline */".to_owned()),
+            (
+                Location::new(5, 5),
+                "/*\n        The function.\n    */".to_owned()
+            ),
+            (Location::new(9, 17), "// The result.".to_owned()),
+        ]
+    );
+
+    let string = tokens
+        .iter()
+        .find(|token| matches!(token.lexeme, Lexeme::Literal(_)) && token.location.column == 15)
+        .expect("Always exists");
+    assert_eq!(string.location, Location::new(4, 15));
+    assert_eq!(string.lexeme.to_string(), "// not a comment");
+
+    let r#let = tokens
+        .iter()
+        .find(|token| token.location.line == 4 && token.location.column > 35)
+        .expect("Always exists");
+    assert_eq!(r#let.location, Location::new(4, 48));
+
+    let function = tokens
+        .iter()
+        .find(|token| token.location.line == 8)
+        .expect("Always exists");
+    assert_eq!(function.location, Location::new(8, 5));
+}
+
+#[test]
+fn comment_trailing_without_new_line() {
+    let mut lexer = Lexer::new("let x := 1 // The trailing comment.".to_owned());
+    let mut count = 0;
+    while lexer.next().expect("Always valid").lexeme != Lexeme::EndOfFile {
+        count += 1;
+    }
+    assert_eq!(count, 4);
+    assert_eq!(
+        lexer.comments(),
+        &[(Location::new(1, 12), "// The trailing comment.".to_owned())]
+    );
+}
+
+#[test]
+fn comment_multi_line_unterminated() {
+    let mut lexer = Lexer::new("let x := 1 /* The unterminated comment.".to_owned());
+    let mut count = 0;
+    while lexer.next().expect("Always valid").lexeme != Lexeme::EndOfFile {
+        count += 1;
+    }
+    assert_eq!(count, 4);
+    assert_eq!(
+        lexer.comments(),
+        &[(
+            Location::new(1, 12),
+            "/* The unterminated comment.".to_owned()
+        )]
+    );
+}
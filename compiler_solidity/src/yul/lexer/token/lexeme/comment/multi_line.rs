@@ -28,8 +28,8 @@ impl Comment {
         let length = end_position + Self::END.len();
         let lines = input.matches('\n').count();
         let columns = match input.rfind('\n') {
-            Some(new_line) => end_position - (new_line + 1),
-            None => end_position,
+            Some(new_line) => end_position - (new_line + 1) + Self::END.len() + 1,
+            None => length,
         };
 
         Token::new(Location::new(lines, columns), Lexeme::Comment, length)
@@ -58,6 +58,9 @@ impl Identifier {
     /// Checks whether the character can continue an identifier.
     ///
     pub fn can_continue(character: char) -> bool {
+        #[cfg(test)]
+        crate::yul::lexer::Lexer::count_scanned_character();
+
         Self::can_begin(character)
             || character.is_numeric()
             || character == '_'
@@ -122,6 +122,9 @@ impl Keyword {
     /// Checks whether the character can continue a keyword.
     ///
     pub fn can_continue(character: char) -> bool {
+        #[cfg(test)]
+        crate::yul::lexer::Lexer::count_scanned_character();
+
         Self::can_begin(character) || character.is_numeric()
     }
 
@@ -2,16 +2,20 @@
 //! The boolean literal lexeme.
 //!
 
+use serde::Serialize;
+
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 
 ///
 /// The boolean literal lexeme.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum Boolean {
     /// Created from the `false` keyword.
+    #[serde(rename = "False")]
     False,
     /// Created from the `true` keyword.
+    #[serde(rename = "True")]
     True,
 }
 
@@ -2,6 +2,8 @@
 //! The integer literal lexeme.
 //!
 
+use serde::Serialize;
+
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::lexeme::Literal;
 use crate::yul::lexer::token::location::Location;
@@ -10,16 +12,20 @@ use crate::yul::lexer::token::Token;
 ///
 /// The integer literal lexeme.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum Integer {
     /// An integer literal, like `42`.
+    #[serde(rename = "Decimal")]
     Decimal {
         /// The inner literal contents.
+        #[serde(rename = "inner")]
         inner: String,
     },
     /// A hexadecimal literal, like `0xffff`.
+    #[serde(rename = "Hexadecimal")]
     Hexadecimal {
         /// The inner literal contents.
+        #[serde(rename = "inner")]
         inner: String,
     },
 }
@@ -80,6 +86,9 @@ impl Integer {
     /// Checks whether the character can continue a decimal number.
     ///
     pub fn can_continue_decimal(character: char) -> bool {
+        #[cfg(test)]
+        crate::yul::lexer::Lexer::count_scanned_character();
+
         character.is_digit(compiler_common::BASE_DECIMAL)
     }
 
@@ -94,6 +103,9 @@ impl Integer {
     /// Checks whether the character can continue a hexadecimal number.
     ///
     pub fn can_continue_hexadecimal(character: char) -> bool {
+        #[cfg(test)]
+        crate::yul::lexer::Lexer::count_scanned_character();
+
         character.is_digit(compiler_common::BASE_HEXADECIMAL)
     }
 
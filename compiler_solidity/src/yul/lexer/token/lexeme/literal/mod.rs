@@ -6,6 +6,8 @@ pub mod boolean;
 pub mod integer;
 pub mod string;
 
+use serde::Serialize;
+
 use self::boolean::Boolean;
 use self::integer::Integer;
 use self::string::String;
@@ -13,13 +15,16 @@ use self::string::String;
 ///
 /// The literal lexeme.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum Literal {
     /// A boolean literal, like `true`, or `false`.
+    #[serde(rename = "Boolean")]
     Boolean(Boolean),
     /// An integer literal, like `42`, or `0xff`.
+    #[serde(rename = "Integer")]
     Integer(Integer),
     /// A string literal, like `"message"`.
+    #[serde(rename = "String")]
     String(String),
 }
 
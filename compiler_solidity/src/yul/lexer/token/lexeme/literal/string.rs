@@ -2,6 +2,8 @@
 //! The string literal lexeme.
 //!
 
+use serde::Serialize;
+
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::lexeme::Literal;
 use crate::yul::lexer::token::location::Location;
@@ -10,11 +12,13 @@ use crate::yul::lexer::token::Token;
 ///
 /// The string literal lexeme.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct String {
     /// The inner string contents.
+    #[serde(rename = "inner")]
     pub inner: std::string::String,
     /// Whether the string is hexadecimal.
+    #[serde(rename = "isHexadecimal")]
     pub is_hexadecimal: bool,
 }
 
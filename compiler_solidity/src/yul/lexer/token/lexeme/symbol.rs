@@ -27,6 +27,8 @@ pub enum Symbol {
     Comma,
     /// The `:` symbol.
     Colon,
+    /// The `=` symbol, which is not valid Yul, but is recognized to suggest `:=` instead.
+    Equals,
 }
 
 impl Symbol {
@@ -45,6 +47,7 @@ impl Symbol {
                 ")" => (Self::ParenthesisRight, 1),
                 "," => (Self::Comma, 1),
                 ":" => (Self::Colon, 1),
+                "=" => (Self::Equals, 1),
 
                 _ => return None,
             },
@@ -69,6 +72,7 @@ impl std::fmt::Display for Symbol {
             Self::ParenthesisRight => write!(f, ")"),
             Self::Comma => write!(f, ","),
             Self::Colon => write!(f, ":"),
+            Self::Equals => write!(f, "="),
         }
     }
 }
@@ -2,14 +2,18 @@
 //! The lexical token location.
 //!
 
+use serde::Serialize;
+
 ///
 /// The token location in the source code file.
 ///
-#[derive(Debug, Clone, Copy, Eq)]
+#[derive(Debug, Clone, Copy, Eq, Serialize)]
 pub struct Location {
     /// The line number, starting from 1.
+    #[serde(rename = "line")]
     pub line: usize,
     /// The column number, starting from 1.
+    #[serde(rename = "column")]
     pub column: usize,
 }
 
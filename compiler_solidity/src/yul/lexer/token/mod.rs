@@ -34,6 +34,15 @@ impl Token {
             length,
         }
     }
+
+    ///
+    /// Returns the location right after the token.
+    ///
+    pub fn end(&self) -> Location {
+        let mut end = self.location;
+        end.shift_right(self.length);
+        end
+    }
 }
 
 impl std::fmt::Display for Token {
@@ -0,0 +1,543 @@
+//!
+//! The YUL AST exporter to the `solc` compact JSON format.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::Statement;
+
+///
+/// The YUL AST exporter to the `solc` compact JSON format.
+///
+/// The nodes are named after the `solc` Yul AST ones, that is, `YulBlock`, `YulFunctionCall`,
+/// etc. The objects, which `solc` does not export, are represented with `YulObject` nodes.
+///
+/// The node `src` ranges span from the node location to the end of its last token, both recorded
+/// by the parser. The statements starting with a keyword, except for `object`, are located at the
+/// token following the keyword, so their ranges do not include it.
+///
+#[derive(Debug)]
+pub struct CompactJson<'a> {
+    /// The source code lines.
+    lines: Vec<&'a str>,
+    /// The byte offsets of the source code lines.
+    line_offsets: Vec<usize>,
+    /// The source index in the `solc` sources list, used in the node `src`.
+    source_index: usize,
+    /// The next node ID.
+    next_id: usize,
+}
+
+impl<'a> CompactJson<'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(source: &'a str, source_index: usize) -> Self {
+        let lines: Vec<&str> = source.split('\n').collect();
+        let mut line_offsets = Vec::with_capacity(lines.len());
+        let mut offset = 0;
+        for line in lines.iter() {
+            line_offsets.push(offset);
+            offset += line.len() + 1;
+        }
+
+        Self {
+            lines,
+            line_offsets,
+            source_index,
+            next_id: 0,
+        }
+    }
+
+    ///
+    /// Exports the object and its inner objects.
+    ///
+    pub fn object(&mut self, object: &Object) -> serde_json::Value {
+        let (id, src) = self.node(&object.location, &object.end);
+        let code = self.block(&object.code.block);
+        let objects: Vec<serde_json::Value> = object
+            .inner_object
+            .iter()
+            .map(|object| self.object(object))
+            .collect();
+        let mut dependencies: Vec<&String> = object.factory_dependencies.iter().collect();
+        dependencies.sort();
+        let data: BTreeMap<&String, String> = object
+            .data
+            .iter()
+            .map(|(name, value)| (name, hex::encode(value)))
+            .collect();
+
+        serde_json::json!({
+            "id": id,
+            "nodeType": "YulObject",
+            "src": src,
+            "name": object.identifier,
+            "code": code,
+            "objects": objects,
+            "dependencies": dependencies,
+            "data": data,
+        })
+    }
+
+    ///
+    /// Exports the block.
+    ///
+    fn block(&mut self, block: &Block) -> serde_json::Value {
+        let (id, src) = self.node(&block.location, &block.end);
+        let statements: Vec<serde_json::Value> = block
+            .statements
+            .iter()
+            .filter_map(|statement| self.statement(statement))
+            .collect();
+
+        serde_json::json!({
+            "id": id,
+            "nodeType": "YulBlock",
+            "src": src,
+            "statements": statements,
+        })
+    }
+
+    ///
+    /// Exports the statement.
+    ///
+    /// Returns `None` for the objects and code sections, which cannot appear in a block.
+    ///
+    fn statement(&mut self, statement: &Statement) -> Option<serde_json::Value> {
+        let end = statement.end();
+        let value = match statement {
+            Statement::Object(_) | Statement::Code(_) => return None,
+            Statement::Block(block) => self.block(block),
+            Statement::Expression(expression) => {
+                let (id, src) = self.node(&expression.location(), &end);
+                let expression = self.expression(expression);
+                serde_json::json!({
+                    "id": id,
+                    "nodeType": "YulExpressionStatement",
+                    "src": src,
+                    "expression": expression,
+                })
+            }
+            Statement::FunctionDefinition(function) => {
+                let (id, src) = self.node(&function.location, &end);
+                let parameters = self.typed_names(function.arguments.as_slice());
+                let return_variables = self.typed_names(function.result.as_slice());
+                let body = self.block(&function.body);
+                serde_json::json!({
+                    "id": id,
+                    "nodeType": "YulFunctionDefinition",
+                    "src": src,
+                    "name": function.identifier,
+                    "parameters": parameters,
+                    "returnVariables": return_variables,
+                    "body": body,
+                })
+            }
+            Statement::VariableDeclaration(declaration) => {
+                let (id, src) = self.node(&declaration.location, &end);
+                let variables = self.typed_names(declaration.bindings.as_slice());
+                let value = declaration
+                    .expression
+                    .as_ref()
+                    .map(|expression| self.expression(expression));
+                serde_json::json!({
+                    "id": id,
+                    "nodeType": "YulVariableDeclaration",
+                    "src": src,
+                    "variables": variables,
+                    "value": value,
+                })
+            }
+            Statement::Assignment(assignment) => {
+                let (id, src) = self.node(&assignment.location, &end);
+                let variable_names: Vec<serde_json::Value> = assignment
+                    .bindings
+                    .iter()
+                    .map(|binding| {
+                        self.identifier(&binding.location, &binding.end(), binding.inner.as_str())
+                    })
+                    .collect();
+                let value = self.expression(&assignment.initializer);
+                serde_json::json!({
+                    "id": id,
+                    "nodeType": "YulAssignment",
+                    "src": src,
+                    "variableNames": variable_names,
+                    "value": value,
+                })
+            }
+            Statement::IfConditional(conditional) => {
+                let (id, src) = self.node(&conditional.location, &end);
+                let condition = self.expression(&conditional.condition);
+                let body = self.block(&conditional.block);
+                serde_json::json!({
+                    "id": id,
+                    "nodeType": "YulIf",
+                    "src": src,
+                    "condition": condition,
+                    "body": body,
+                })
+            }
+            Statement::Switch(switch) => {
+                let (id, src) = self.node(&switch.location, &end);
+                let expression = self.expression(&switch.expression);
+                let mut cases = Vec::with_capacity(switch.cases.len() + 1);
+                for case in switch.cases.iter() {
+                    let (id, src) = self.node(&case.location, &case.block.end);
+                    let value = self.literal(&case.literal);
+                    let body = self.block(&case.block);
+                    cases.push(serde_json::json!({
+                        "id": id,
+                        "nodeType": "YulCase",
+                        "src": src,
+                        "value": value,
+                        "body": body,
+                    }));
+                }
+                if let Some(default) = switch.default.as_ref() {
+                    let (id, src) = self.node(&default.location, &default.end);
+                    let body = self.block(default);
+                    cases.push(serde_json::json!({
+                        "id": id,
+                        "nodeType": "YulCase",
+                        "src": src,
+                        "value": "default",
+                        "body": body,
+                    }));
+                }
+                serde_json::json!({
+                    "id": id,
+                    "nodeType": "YulSwitch",
+                    "src": src,
+                    "expression": expression,
+                    "cases": cases,
+                })
+            }
+            Statement::ForLoop(for_loop) => {
+                let (id, src) = self.node(&for_loop.location, &end);
+                let pre = self.block(&for_loop.initializer);
+                let condition = self.expression(&for_loop.condition);
+                let post = self.block(&for_loop.finalizer);
+                let body = self.block(&for_loop.body);
+                serde_json::json!({
+                    "id": id,
+                    "nodeType": "YulForLoop",
+                    "src": src,
+                    "pre": pre,
+                    "condition": condition,
+                    "post": post,
+                    "body": body,
+                })
+            }
+            Statement::Continue(location) => self.leaf(location, &end, "YulContinue"),
+            Statement::Break(location) => self.leaf(location, &end, "YulBreak"),
+            Statement::Leave(location) => self.leaf(location, &end, "YulLeave"),
+        };
+
+        Some(value)
+    }
+
+    ///
+    /// Exports the expression.
+    ///
+    fn expression(&mut self, expression: &Expression) -> serde_json::Value {
+        match expression {
+            Expression::FunctionCall(call) => {
+                let (id, src) = self.node(&call.location, &call.end);
+                let name = call.name.to_string();
+                let mut name_end = call.location;
+                name_end.shift_right(name.len());
+                let function_name = self.identifier(&call.location, &name_end, name.as_str());
+                let arguments: Vec<serde_json::Value> = call
+                    .arguments
+                    .iter()
+                    .map(|argument| self.expression(argument))
+                    .collect();
+                serde_json::json!({
+                    "id": id,
+                    "nodeType": "YulFunctionCall",
+                    "src": src,
+                    "functionName": function_name,
+                    "arguments": arguments,
+                })
+            }
+            Expression::Identifier(identifier) => self.identifier(
+                &identifier.location,
+                &identifier.end(),
+                identifier.inner.as_str(),
+            ),
+            Expression::Literal(literal) => self.literal(literal),
+        }
+    }
+
+    ///
+    /// Exports the literal.
+    ///
+    fn literal(&mut self, literal: &Literal) -> serde_json::Value {
+        let (id, src) = self.node(&literal.location, &literal.end);
+        let kind = match literal.inner {
+            LexicalLiteral::Boolean(_) => "bool",
+            LexicalLiteral::Integer(_) => "number",
+            LexicalLiteral::String(_) => "string",
+        };
+        let mut value = serde_json::json!({
+            "id": id,
+            "nodeType": "YulLiteral",
+            "src": src,
+            "kind": kind,
+            "type": "",
+        });
+        match literal.inner {
+            LexicalLiteral::String(ref inner) if inner.is_hexadecimal => {
+                value["hexValue"] = serde_json::Value::String(inner.to_string());
+            }
+            ref inner => {
+                value["value"] = serde_json::Value::String(inner.to_string());
+            }
+        }
+        value
+    }
+
+    ///
+    /// Exports the identifier.
+    ///
+    fn identifier(&mut self, location: &Location, end: &Location, name: &str) -> serde_json::Value {
+        let (id, src) = self.node(location, end);
+        serde_json::json!({
+            "id": id,
+            "nodeType": "YulIdentifier",
+            "src": src,
+            "name": name,
+        })
+    }
+
+    ///
+    /// Exports the identifier declarations.
+    ///
+    fn typed_names(&mut self, identifiers: &[Identifier]) -> Vec<serde_json::Value> {
+        identifiers
+            .iter()
+            .map(|identifier| {
+                let (id, src) = self.node(&identifier.location, &identifier.end());
+                serde_json::json!({
+                    "id": id,
+                    "nodeType": "YulTypedName",
+                    "src": src,
+                    "name": identifier.inner,
+                    "type": "",
+                })
+            })
+            .collect()
+    }
+
+    ///
+    /// Exports the node without children.
+    ///
+    fn leaf(&mut self, location: &Location, end: &Location, node_type: &str) -> serde_json::Value {
+        let (id, src) = self.node(location, end);
+        serde_json::json!({
+            "id": id,
+            "nodeType": node_type,
+            "src": src,
+        })
+    }
+
+    ///
+    /// Allocates the node ID and returns it along with the node `src` string.
+    ///
+    fn node(&mut self, location: &Location, end: &Location) -> (usize, String) {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let src = match (self.offset(location), self.offset(end)) {
+            (Some(start), Some(end)) if start <= end => {
+                format!("{}:{}:{}", start, end - start, self.source_index)
+            }
+            _ => "-1:-1:-1".to_owned(),
+        };
+
+        (id, src)
+    }
+
+    ///
+    /// Converts the location to the byte offset in the source code.
+    ///
+    /// The lexer advances the column by the byte length of each token, so the column is the
+    /// byte offset within the line.
+    ///
+    fn offset(&self, location: &Location) -> Option<usize> {
+        let line = location.line.checked_sub(1)?;
+        let column = location.column.checked_sub(1)?;
+        if column > self.lines.get(line)?.len() {
+            return None;
+        }
+        Some(self.line_offsets[line] + column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::statement::object::Object;
+
+    use super::CompactJson;
+
+    fn collect<'a>(
+        value: &serde_json::Value,
+        input: &'a str,
+        node_types: &mut BTreeSet<String>,
+        ids: &mut BTreeSet<u64>,
+        texts: &mut Vec<(String, &'a str)>,
+    ) {
+        match value {
+            serde_json::Value::Object(map) => {
+                if let Some(node_type) = map.get("nodeType").and_then(|value| value.as_str()) {
+                    let src = map
+                        .get("src")
+                        .and_then(|value| value.as_str())
+                        .unwrap_or_else(|| panic!("The node `{}` has no `src`", node_type));
+                    let src: Vec<usize> = src
+                        .split(':')
+                        .map(|part| part.parse().expect("Always valid"))
+                        .collect();
+                    assert_eq!(src[2], 0);
+                    texts.push((node_type.to_owned(), &input[src[0]..src[0] + src[1]]));
+
+                    let id = map
+                        .get("id")
+                        .and_then(|value| value.as_u64())
+                        .expect("Always exists");
+                    assert!(ids.insert(id), "The node ID {} is duplicated", id);
+                    node_types.insert(node_type.to_owned());
+                }
+                for value in map.values() {
+                    collect(value, input, node_types, ids, texts);
+                }
+            }
+            serde_json::Value::Array(values) => {
+                for value in values.iter() {
+                    collect(value, input, node_types, ids, texts);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn ok_node_types() {
+        let input = r#"object "Test" {
+    code {
+        {
+            let x := add(1, 2)
+            x := f(x)
+            if x { leave_loop() }
+            switch x
+            case 0 { revert(0, 0) }
+            default { return(0, 0) }
+            for { let i := 0 } lt(i, 10) { i := add(i, 1) } {
+                if i { break }
+                continue
+            }
+
+            function f(a) -> b {
+                b := a
+                leave
+            }
+
+            function leave_loop() {}
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                sstore(0, "value")
+            }
+        }
+    }
+}"#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Always valid");
+        let json = CompactJson::new(input, 0).object(&object);
+        let json: serde_json::Value =
+            serde_json::from_str(json.to_string().as_str()).expect("Always valid");
+
+        let mut node_types = BTreeSet::new();
+        let mut ids = BTreeSet::new();
+        let mut texts = Vec::new();
+        collect(&json, input, &mut node_types, &mut ids, &mut texts);
+
+        for node_type in [
+            "YulObject",
+            "YulBlock",
+            "YulFunctionDefinition",
+            "YulTypedName",
+            "YulVariableDeclaration",
+            "YulAssignment",
+            "YulExpressionStatement",
+            "YulFunctionCall",
+            "YulIdentifier",
+            "YulLiteral",
+            "YulIf",
+            "YulSwitch",
+            "YulCase",
+            "YulForLoop",
+            "YulBreak",
+            "YulContinue",
+            "YulLeave",
+        ] {
+            assert!(
+                node_types.contains(node_type),
+                "The node type `{}` is missing",
+                node_type
+            );
+        }
+        assert_eq!(json["src"], format!("0:{}:0", input.len()));
+        assert_eq!(json["objects"][0]["name"], "Test_deployed");
+
+        for (node_type, text) in [
+            ("YulVariableDeclaration", "x := add(1, 2)"),
+            ("YulAssignment", "x := f(x)"),
+            ("YulExpressionStatement", "revert(0, 0)"),
+            ("YulFunctionCall", "add(i, 1)"),
+            ("YulIdentifier", "leave_loop"),
+            ("YulLiteral", "\"value\""),
+            ("YulTypedName", "b"),
+            ("YulIf", "i { break }"),
+            ("YulBreak", "break"),
+            ("YulContinue", "continue"),
+            ("YulLeave", "leave"),
+            ("YulCase", "{ return(0, 0) }"),
+            (
+                "YulFunctionDefinition",
+                "f(a) -> b {\n                b := a\n                leave\n            }",
+            ),
+        ] {
+            assert!(
+                texts.contains(&(node_type.to_owned(), text)),
+                "The node `{}` with the source `{}` is missing",
+                node_type,
+                text
+            );
+        }
+        for (node_type, text) in texts.iter() {
+            match node_type.as_str() {
+                "YulBlock" => assert!(text.starts_with('{') && text.ends_with('}')),
+                "YulFunctionCall" => assert!(text.ends_with(')')),
+                "YulSwitch" => assert!(text.starts_with('x') && text.ends_with('}')),
+                "YulForLoop" => assert!(text.starts_with('{') && text.ends_with('}')),
+                _ => {}
+            }
+        }
+    }
+}
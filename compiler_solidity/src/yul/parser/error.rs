@@ -10,6 +10,8 @@ pub enum Error {
     InvalidToken {
         /// The invalid token location.
         location: Location,
+        /// The invalid token length, which is used to highlight the whole token.
+        length: usize,
         /// The list of expected tokens.
         expected: Vec<&'static str>,
         /// The invalid token.
@@ -33,4 +35,42 @@ pub enum Error {
         /// The actual number of arguments.
         found: usize,
     },
+    #[error("{location} The `leave` statement is only allowed inside a function body")]
+    LeaveOutsideFunction {
+        /// The invalid statement location.
+        location: Location,
+    },
+    #[error("{location} The for-loop condition must be an expression, found `{found}`")]
+    InvalidForLoopCondition {
+        /// The invalid condition location.
+        location: Location,
+        /// The invalid condition token.
+        found: String,
+    },
+    #[error("{location} Yul assigns values with `:=`, found `=`. Did you mean `:=`?")]
+    InvalidAssignmentSymbol {
+        /// The invalid symbol location.
+        location: Location,
+    },
+    #[error("{location} The object `{identifier}` is already defined at {previous}")]
+    DuplicateObject {
+        /// The duplicate object location.
+        location: Location,
+        /// The duplicate object identifier.
+        identifier: String,
+        /// The location of the object defined first.
+        previous: Location,
+    },
+    #[error("{location} The data `{name}` is not a valid hexadecimal string")]
+    InvalidHexData {
+        /// The invalid data value location.
+        location: Location,
+        /// The data entry name.
+        name: String,
+    },
+    #[error("{location} The literal exceeds the field size of 256 bits")]
+    LiteralOverflow {
+        /// The invalid literal location.
+        location: Location,
+    },
 }
@@ -2,24 +2,30 @@
 //! The YUL source code identifier.
 //!
 
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::r#type::Type;
 
 ///
 /// The YUL source code identifier.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Identifier {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The inner string.
+    #[serde(rename = "inner")]
     pub inner: String,
     /// The type, if it has been explicitly specified.
+    #[serde(rename = "type")]
     pub r#type: Option<Type>,
 }
 
@@ -46,6 +52,15 @@ impl Identifier {
         }
     }
 
+    ///
+    /// Returns the location right after the identifier name, excluding the type.
+    ///
+    pub fn end(&self) -> Location {
+        let mut end = self.location;
+        end.shift_right(self.inner.len());
+        end
+    }
+
     ///
     /// Parses the identifier list where the types cannot be specified.
     ///
@@ -82,6 +97,8 @@ impl Identifier {
     ///
     /// Parses the identifier list where the types may be optionally specified.
     ///
+    /// A trailing comma is an error, since an identifier is expected after it.
+    ///
     pub fn parse_typed_list(
         lexer: &mut Lexer,
         mut initial: Option<Token>,
@@ -117,8 +134,27 @@ impl Identifier {
                 } if expected_comma => {
                     expected_comma = false;
                 }
-                token => return Ok((result, Some(token))),
+                token if expected_comma || result.is_empty() => return Ok((result, Some(token))),
+                token => {
+                    return Err(ParserError::InvalidToken {
+                        location: token.location,
+                        length: token.length,
+                        expected: vec!["{identifier}"],
+                        found: token.lexeme.to_string(),
+                    }
+                    .into());
+                }
             }
         }
     }
 }
+
+impl std::fmt::Display for Identifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)?;
+        if let Some(r#type) = self.r#type.as_ref() {
+            write!(f, ":{}", r#type)?;
+        }
+        Ok(())
+    }
+}
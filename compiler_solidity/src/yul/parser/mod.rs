@@ -2,6 +2,7 @@
 //! The YUL code block.
 //!
 
+pub mod compact_json;
 pub mod error;
 pub mod identifier;
 pub mod statement;
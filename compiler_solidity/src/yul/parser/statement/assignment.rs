@@ -2,6 +2,8 @@
 //! The assignment expression statement.
 //!
 
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -15,13 +17,16 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 /// The Yul assignment expression statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Assignment {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The variable bindings.
+    #[serde(rename = "bindings")]
     pub bindings: Vec<Identifier>,
     /// The initializing expression.
+    #[serde(rename = "initializer")]
     pub initializer: Expression,
 }
 
@@ -41,6 +46,7 @@ impl Assignment {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["{identifier}"],
                     found: token.lexeme.to_string(),
                 }
@@ -62,6 +68,11 @@ impl Assignment {
                     initializer: Expression::parse(lexer, None)?,
                 })
             }
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Equals),
+                location,
+                ..
+            } => Err(ParserError::InvalidAssignmentSymbol { location }.into()),
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Comma),
                 ..
@@ -76,9 +87,15 @@ impl Assignment {
                         lexeme: Lexeme::Symbol(Symbol::Assignment),
                         ..
                     } => {}
+                    Token {
+                        lexeme: Lexeme::Symbol(Symbol::Equals),
+                        location,
+                        ..
+                    } => return Err(ParserError::InvalidAssignmentSymbol { location }.into()),
                     token => {
                         return Err(ParserError::InvalidToken {
                             location: token.location,
+                            length: token.length,
                             expected: vec![":="],
                             found: token.lexeme.to_string(),
                         }
@@ -94,6 +111,7 @@ impl Assignment {
             }
             token => Err(ParserError::InvalidToken {
                 location: token.location,
+                length: token.length,
                 expected: vec![":=", ","],
                 found: token.lexeme.to_string(),
             }
@@ -102,6 +120,13 @@ impl Assignment {
     }
 }
 
+impl std::fmt::Display for Assignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bindings: Vec<String> = self.bindings.iter().map(ToString::to_string).collect();
+        write!(f, "{} := {}", bindings.join(", "), self.initializer)
+    }
+}
+
 impl<D> compiler_llvm_context::WriteLLVM<D> for Assignment
 where
     D: compiler_llvm_context::Dependency,
@@ -168,3 +193,47 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::token::location::Location;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::error::Error;
+    use crate::yul::parser::statement::object::Object;
+
+    #[test]
+    fn error_invalid_assignment_symbol() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let x := 0
+                x = 1
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::InvalidAssignmentSymbol {
+                location: Location::new(12, 19),
+            }
+            .into())
+        );
+        assert!(result
+            .expect_err("Always exists")
+            .to_string()
+            .contains("Did you mean `:=`?"));
+    }
+}
@@ -2,6 +2,11 @@
 //! The source code block.
 //!
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -9,19 +14,28 @@ use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
+use crate::yul::parser::identifier::Identifier;
 use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
 use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
 use crate::yul::parser::statement::Statement;
 
 ///
 /// The Yul source code block.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Block {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The block statements.
+    #[serde(rename = "statements")]
     pub statements: Vec<Statement>,
+    /// The location right after the closing bracket.
+    #[serde(rename = "end")]
+    pub end: Location,
 }
 
 impl Block {
@@ -42,6 +56,7 @@ impl Block {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["{"],
                     found: token.lexeme.to_string(),
                 }
@@ -51,7 +66,7 @@ impl Block {
 
         let mut remaining = None;
 
-        loop {
+        let end = loop {
             match crate::yul::parser::take_or_next(remaining.take(), lexer)? {
                 token @ Token {
                     lexeme: Lexeme::Keyword(_),
@@ -81,7 +96,7 @@ impl Block {
                         );
                     }
                     Token {
-                        lexeme: Lexeme::Symbol(Symbol::Comma),
+                        lexeme: Lexeme::Symbol(Symbol::Comma | Symbol::Equals),
                         ..
                     } => {
                         statements.push(
@@ -98,26 +113,363 @@ impl Block {
                     lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
                     ..
                 } => statements.push(Block::parse(lexer, Some(token)).map(Statement::Block)?),
-                Token {
+                token @ Token {
                     lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
                     ..
-                } => break,
+                } => break token.end(),
                 token => {
                     return Err(ParserError::InvalidToken {
                         location: token.location,
+                        length: token.length,
                         expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
                         found: token.lexeme.to_string(),
                     }
                     .into());
                 }
             }
-        }
+        };
 
         Ok(Self {
             location,
             statements,
+            end,
         })
     }
+
+    ///
+    /// Calls the visitor for each function call in the block, including the nested ones.
+    ///
+    pub fn visit_function_calls<F>(&self, visitor: &mut F)
+    where
+        F: FnMut(&FunctionCall),
+    {
+        for statement in self.statements.iter() {
+            statement.visit_function_calls(visitor);
+        }
+    }
+
+    ///
+    /// Calls the visitor for each function call in the block, including the nested ones.
+    ///
+    pub fn visit_function_calls_mut<F>(&mut self, visitor: &mut F)
+    where
+        F: FnMut(&mut FunctionCall),
+    {
+        for statement in self.statements.iter_mut() {
+            statement.visit_function_calls_mut(visitor);
+        }
+    }
+
+    ///
+    /// Collects the locations of the functions defined in the block, including the nested blocks
+    /// and function bodies.
+    ///
+    pub fn function_locations(&self, locations: &mut BTreeMap<String, Location>) {
+        for statement in self.statements.iter() {
+            match statement {
+                Statement::FunctionDefinition(inner) => {
+                    locations.insert(inner.identifier.clone(), inner.location);
+                    inner.body.function_locations(locations);
+                }
+                Statement::Block(inner) => inner.function_locations(locations),
+                _ => {}
+            }
+        }
+    }
+
+    ///
+    /// Collects the functions defined in the block, including the nested blocks and function bodies.
+    ///
+    pub fn function_definitions<'a>(&'a self, definitions: &mut Vec<&'a FunctionDefinition>) {
+        for statement in self.statements.iter() {
+            match statement {
+                Statement::FunctionDefinition(inner) => {
+                    definitions.push(inner);
+                    inner.body.function_definitions(definitions);
+                }
+                Statement::Block(inner) => inner.function_definitions(definitions),
+                _ => {}
+            }
+        }
+    }
+
+    ///
+    /// Returns the location of the first `leave` statement which is not enclosed in a function
+    /// body, since there is no function to leave at the object top level.
+    ///
+    pub fn leave_outside_function(&self) -> Option<Location> {
+        self.statements
+            .iter()
+            .find_map(|statement| match statement {
+                Statement::Leave(location) => Some(*location),
+                Statement::Block(inner) => inner.leave_outside_function(),
+                Statement::IfConditional(inner) => inner.block.leave_outside_function(),
+                Statement::Switch(inner) => inner
+                    .cases
+                    .iter()
+                    .map(|case| &case.block)
+                    .chain(inner.default.iter())
+                    .find_map(Self::leave_outside_function),
+                Statement::ForLoop(inner) => inner
+                    .initializer
+                    .leave_outside_function()
+                    .or_else(|| inner.finalizer.leave_outside_function())
+                    .or_else(|| inner.body.leave_outside_function()),
+                _ => None,
+            })
+    }
+
+    ///
+    /// Collects the locations of the statements in the block in the source code order, including
+    /// the nested blocks and function bodies. The blocks and function definitions themselves are
+    /// skipped, as they do not produce any code at their own location.
+    ///
+    pub fn statement_locations(&self, locations: &mut Vec<Location>) {
+        for statement in self.statements.iter() {
+            match statement {
+                Statement::Block(inner) => inner.statement_locations(locations),
+                Statement::FunctionDefinition(inner) => inner.body.statement_locations(locations),
+                Statement::IfConditional(inner) => {
+                    locations.push(inner.location);
+                    inner.block.statement_locations(locations);
+                }
+                Statement::Switch(inner) => {
+                    locations.push(inner.location);
+                    for case in inner.cases.iter() {
+                        case.block.statement_locations(locations);
+                    }
+                    if let Some(block) = inner.default.as_ref() {
+                        block.statement_locations(locations);
+                    }
+                }
+                Statement::ForLoop(inner) => {
+                    inner.initializer.statement_locations(locations);
+                    locations.push(inner.location);
+                    inner.body.statement_locations(locations);
+                    inner.finalizer.statement_locations(locations);
+                }
+                statement => locations.push(statement.location()),
+            }
+        }
+    }
+
+    ///
+    /// Collects the variables declared in the `for` loop initializers which shadow a variable
+    /// of an enclosing scope, including the counters of the enclosing loops.
+    ///
+    /// The `scope` contains the variables visible at the beginning of the block. The function
+    /// bodies start with their arguments and results only, since Yul functions cannot access
+    /// the variables declared outside of them.
+    ///
+    pub fn shadowed_loop_variables(
+        &self,
+        scope: &BTreeSet<String>,
+        shadowed: &mut Vec<Identifier>,
+    ) {
+        let mut scope = scope.to_owned();
+        for statement in self.statements.iter() {
+            match statement {
+                Statement::VariableDeclaration(inner) => {
+                    scope.extend(
+                        inner
+                            .bindings
+                            .iter()
+                            .map(|binding| binding.inner.to_owned()),
+                    );
+                }
+                Statement::Block(inner) => inner.shadowed_loop_variables(&scope, shadowed),
+                Statement::FunctionDefinition(inner) => {
+                    let scope = inner
+                        .arguments
+                        .iter()
+                        .chain(inner.result.iter())
+                        .map(|identifier| identifier.inner.to_owned())
+                        .collect();
+                    inner.body.shadowed_loop_variables(&scope, shadowed);
+                }
+                Statement::IfConditional(inner) => {
+                    inner.block.shadowed_loop_variables(&scope, shadowed)
+                }
+                Statement::Switch(inner) => {
+                    for block in inner
+                        .cases
+                        .iter()
+                        .map(|case| &case.block)
+                        .chain(inner.default.iter())
+                    {
+                        block.shadowed_loop_variables(&scope, shadowed);
+                    }
+                }
+                Statement::ForLoop(inner) => {
+                    let mut loop_scope = scope.clone();
+                    for statement in inner.initializer.statements.iter() {
+                        if let Statement::VariableDeclaration(declaration) = statement {
+                            for binding in declaration.bindings.iter() {
+                                if scope.contains(&binding.inner) {
+                                    shadowed.push(binding.to_owned());
+                                }
+                                loop_scope.insert(binding.inner.to_owned());
+                            }
+                        }
+                    }
+                    inner
+                        .finalizer
+                        .shadowed_loop_variables(&loop_scope, shadowed);
+                    inner.body.shadowed_loop_variables(&loop_scope, shadowed);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    ///
+    /// Collects the names of the variables assigned with `:=` in the block, including the nested
+    /// blocks, but not the nested function bodies, which cannot access the outer variables.
+    ///
+    pub fn assigned_variables(&self, assigned: &mut BTreeSet<String>) {
+        for statement in self.statements.iter() {
+            match statement {
+                Statement::Assignment(inner) => assigned.extend(
+                    inner
+                        .bindings
+                        .iter()
+                        .map(|binding| binding.inner.to_owned()),
+                ),
+                Statement::Block(inner) => inner.assigned_variables(assigned),
+                Statement::IfConditional(inner) => inner.block.assigned_variables(assigned),
+                Statement::Switch(inner) => {
+                    for block in inner
+                        .cases
+                        .iter()
+                        .map(|case| &case.block)
+                        .chain(inner.default.iter())
+                    {
+                        block.assigned_variables(assigned);
+                    }
+                }
+                Statement::ForLoop(inner) => {
+                    inner.initializer.assigned_variables(assigned);
+                    inner.finalizer.assigned_variables(assigned);
+                    inner.body.assigned_variables(assigned);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    ///
+    /// Collects the calls to the user-defined functions which are not visible from the call site.
+    ///
+    /// The `scope` contains the functions visible at the beginning of the block. The functions
+    /// defined in a block are visible in the whole block, including the statements preceding
+    /// the definition, the nested blocks, and the nested function bodies.
+    ///
+    pub fn undefined_function_calls(
+        &self,
+        scope: &BTreeSet<String>,
+        undefined: &mut Vec<(String, Location)>,
+    ) {
+        let mut scope = scope.to_owned();
+        scope.extend(self.defined_functions());
+
+        for statement in self.statements.iter() {
+            match statement {
+                Statement::Block(inner) => inner.undefined_function_calls(&scope, undefined),
+                Statement::FunctionDefinition(inner) => {
+                    inner.body.undefined_function_calls(&scope, undefined)
+                }
+                Statement::IfConditional(inner) => {
+                    inner
+                        .condition
+                        .visit_function_calls(&mut |call: &FunctionCall| {
+                            Self::check_function_call(call, &scope, undefined)
+                        });
+                    inner.block.undefined_function_calls(&scope, undefined);
+                }
+                Statement::Switch(inner) => {
+                    inner
+                        .expression
+                        .visit_function_calls(&mut |call: &FunctionCall| {
+                            Self::check_function_call(call, &scope, undefined)
+                        });
+                    for block in inner
+                        .cases
+                        .iter()
+                        .map(|case| &case.block)
+                        .chain(inner.default.iter())
+                    {
+                        block.undefined_function_calls(&scope, undefined);
+                    }
+                }
+                Statement::ForLoop(inner) => {
+                    inner
+                        .initializer
+                        .undefined_function_calls(&scope, undefined);
+                    let mut scope = scope.to_owned();
+                    scope.extend(inner.initializer.defined_functions());
+                    inner
+                        .condition
+                        .visit_function_calls(&mut |call: &FunctionCall| {
+                            Self::check_function_call(call, &scope, undefined)
+                        });
+                    inner.finalizer.undefined_function_calls(&scope, undefined);
+                    inner.body.undefined_function_calls(&scope, undefined);
+                }
+                statement => statement.visit_function_calls(&mut |call: &FunctionCall| {
+                    Self::check_function_call(call, &scope, undefined)
+                }),
+            }
+        }
+    }
+
+    ///
+    /// Whether the block always ends the execution with a terminating instruction.
+    ///
+    /// The statements following a terminating one are unreachable, so it is enough for any
+    /// statement of the block to be terminating.
+    ///
+    pub fn is_terminating(&self, terminating_functions: &BTreeSet<String>) -> bool {
+        self.statements
+            .iter()
+            .any(|statement| statement.is_terminating(terminating_functions))
+    }
+
+    ///
+    /// Returns the names of the functions defined directly in the block.
+    ///
+    fn defined_functions(&self) -> impl Iterator<Item = String> + '_ {
+        self.statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::FunctionDefinition(inner) => Some(inner.identifier.to_owned()),
+                _ => None,
+            })
+    }
+
+    ///
+    /// Records the call if it refers to a user-defined function missing from the `scope`.
+    ///
+    fn check_function_call(
+        call: &FunctionCall,
+        scope: &BTreeSet<String>,
+        undefined: &mut Vec<(String, Location)>,
+    ) {
+        if let Name::UserDefined(ref name) = call.name {
+            if !scope.contains(name) {
+                undefined.push((name.to_owned(), call.location));
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Block {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for statement in self.statements.iter() {
+            write!(f, " {}", statement)?;
+        }
+        write!(f, " }}")
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Block
@@ -224,6 +576,7 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(11, 17),
+                length: 1,
                 expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
                 found: "(".to_owned(),
             }
@@ -257,6 +610,7 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(11, 17),
+                length: 2,
                 expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
                 found: ":=".to_owned(),
             }
@@ -2,6 +2,8 @@
 //! The YUL code.
 //!
 
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -14,11 +16,13 @@ use crate::yul::parser::statement::block::Block;
 ///
 /// The YUL code entity, which is the first block of the object.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Code {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The main block.
+    #[serde(rename = "block")]
     pub block: Block,
 }
 
@@ -38,6 +42,7 @@ impl Code {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["code"],
                     found: token.lexeme.to_string(),
                 }
@@ -51,6 +56,12 @@ impl Code {
     }
 }
 
+impl std::fmt::Display for Code {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "code {}", self.block)
+    }
+}
+
 impl<D> compiler_llvm_context::WriteLLVM<D> for Code
 where
     D: compiler_llvm_context::Dependency,
@@ -94,6 +105,7 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(3, 5),
+                length: 4,
                 expected: vec!["code"],
                 found: "data".to_owned(),
             }
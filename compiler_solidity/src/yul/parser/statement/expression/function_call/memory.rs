@@ -0,0 +1,280 @@
+//!
+//! Translates the memory instructions missing in the LLVM context.
+//!
+
+use inkwell::values::BasicValue;
+
+///
+/// Translates the memory-to-memory copying.
+///
+/// The overlapping regions are copied as if through an intermediate buffer. The memory is never
+/// read past the end of the source region, except for the scratch space below the offset `32`.
+///
+pub fn copy<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    source: inkwell::values::IntValue<'ctx>,
+    size: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let words_block = context.append_basic_block("mcopy_words");
+    let bytes_block = context.append_basic_block("mcopy_bytes");
+    let join_block = context.append_basic_block("mcopy_join");
+
+    let is_short = context.builder().build_int_compare(
+        inkwell::IntPredicate::ULT,
+        size,
+        context.field_const(compiler_common::SIZE_FIELD as u64),
+        "mcopy_is_short",
+    );
+    let is_empty = context.builder().build_int_compare(
+        inkwell::IntPredicate::EQ,
+        size,
+        context.field_const(0),
+        "mcopy_is_empty",
+    );
+    let short_block = context.append_basic_block("mcopy_short");
+    context.build_conditional_branch(is_short, short_block, words_block);
+
+    context.set_basic_block(short_block);
+    context.build_conditional_branch(is_empty, join_block, bytes_block);
+
+    context.set_basic_block(words_block);
+    copy_words(context, destination, source, size)?;
+    context.build_unconditional_branch(join_block);
+
+    context.set_basic_block(bytes_block);
+    copy_bytes(context, destination, source, size)?;
+    context.build_unconditional_branch(join_block);
+
+    context.set_basic_block(join_block);
+
+    Ok(None)
+}
+
+///
+/// Copies at least one word.
+///
+/// The full words are copied backwards if the destination is above the source. The last word of
+/// the source region is loaded before the copying and stored at the end of the destination region
+/// afterwards, which covers the trailing bytes not making up a full word.
+///
+fn copy_words<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    source: inkwell::values::IntValue<'ctx>,
+    size: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<()>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let condition_block = context.append_basic_block("mcopy_words_condition");
+    let body_block = context.append_basic_block("mcopy_words_body");
+    let tail_block = context.append_basic_block("mcopy_words_tail");
+
+    let word_size = context.field_const(compiler_common::SIZE_FIELD as u64);
+    let tail_offset = context
+        .builder()
+        .build_int_sub(size, word_size, "mcopy_tail_offset");
+    let source_tail = context
+        .builder()
+        .build_int_add(source, tail_offset, "mcopy_source_tail");
+    let tail = compiler_llvm_context::memory::load(context, source_tail)?
+        .expect("Always exists")
+        .into_int_value();
+
+    let is_backward = context.builder().build_int_compare(
+        inkwell::IntPredicate::UGT,
+        destination,
+        source,
+        "mcopy_is_backward",
+    );
+    let words = context
+        .builder()
+        .build_int_unsigned_div(size, word_size, "mcopy_words");
+    let index_pointer = context.build_alloca(context.field_type(), "mcopy_index_pointer");
+    context.build_store(index_pointer, context.field_const(0).as_basic_value_enum());
+    context.build_unconditional_branch(condition_block);
+
+    context.set_basic_block(condition_block);
+    let index = context
+        .build_load(index_pointer, "mcopy_index")
+        .into_int_value();
+    let condition = context.builder().build_int_compare(
+        inkwell::IntPredicate::ULT,
+        index,
+        words,
+        "mcopy_words_condition",
+    );
+    context.build_conditional_branch(condition, body_block, tail_block);
+
+    context.set_basic_block(body_block);
+    let index_reversed = context
+        .builder()
+        .build_int_sub(words, index, "mcopy_index_reversed");
+    let index_reversed = context.builder().build_int_sub(
+        index_reversed,
+        context.field_const(1),
+        "mcopy_index_reversed_last",
+    );
+    let position = context
+        .builder()
+        .build_select(is_backward, index_reversed, index, "mcopy_position")
+        .into_int_value();
+    let offset = context
+        .builder()
+        .build_int_mul(position, word_size, "mcopy_offset");
+    let source_position = context
+        .builder()
+        .build_int_add(source, offset, "mcopy_source_position");
+    let word = compiler_llvm_context::memory::load(context, source_position)?
+        .expect("Always exists")
+        .into_int_value();
+    let destination_position =
+        context
+            .builder()
+            .build_int_add(destination, offset, "mcopy_destination_position");
+    compiler_llvm_context::memory::store(context, destination_position, word)?;
+    let index_incremented =
+        context
+            .builder()
+            .build_int_add(index, context.field_const(1), "mcopy_index_incremented");
+    context.build_store(index_pointer, index_incremented.as_basic_value_enum());
+    context.build_unconditional_branch(condition_block);
+
+    context.set_basic_block(tail_block);
+    let destination_tail =
+        context
+            .builder()
+            .build_int_add(destination, tail_offset, "mcopy_destination_tail");
+    compiler_llvm_context::memory::store(context, destination_tail, tail)?;
+
+    Ok(())
+}
+
+///
+/// Copies less than one word.
+///
+/// The source bytes are loaded from the word ending at the end of the source region and merged
+/// into the word ending at the end of the destination region. If a region ends below the offset
+/// `32`, the word at the offset `0` is used instead.
+///
+fn copy_bytes<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    source: inkwell::values::IntValue<'ctx>,
+    size: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<()>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let byte_bits = context.field_const(compiler_common::BITLENGTH_BYTE as u64);
+    let word_size = context.field_const(compiler_common::SIZE_FIELD as u64);
+
+    let unused_size = context
+        .builder()
+        .build_int_sub(word_size, size, "mcopy_unused_size");
+    let unused_bits = context
+        .builder()
+        .build_int_mul(unused_size, byte_bits, "mcopy_unused_bits");
+    let mask = context.builder().build_left_shift(
+        context.field_type().const_all_ones(),
+        unused_bits,
+        "mcopy_mask",
+    );
+
+    let (source_base, source_shift) = word_base(context, source, size, "mcopy_source");
+    let source_word = compiler_llvm_context::memory::load(context, source_base)?
+        .expect("Always exists")
+        .into_int_value();
+    let value =
+        context
+            .builder()
+            .build_left_shift(source_word, source_shift, "mcopy_value_aligned");
+    let value = context.builder().build_and(value, mask, "mcopy_value");
+
+    let (destination_base, destination_shift) =
+        word_base(context, destination, size, "mcopy_destination");
+    let destination_word = compiler_llvm_context::memory::load(context, destination_base)?
+        .expect("Always exists")
+        .into_int_value();
+    let destination_mask = context.builder().build_right_shift(
+        mask,
+        destination_shift,
+        false,
+        "mcopy_destination_mask",
+    );
+    let destination_mask = context
+        .builder()
+        .build_not(destination_mask, "mcopy_destination_mask_inverted");
+    let destination_word = context.builder().build_and(
+        destination_word,
+        destination_mask,
+        "mcopy_destination_word_cleared",
+    );
+    let value =
+        context
+            .builder()
+            .build_right_shift(value, destination_shift, false, "mcopy_value_shifted");
+    let result = context
+        .builder()
+        .build_or(destination_word, value, "mcopy_destination_word");
+    compiler_llvm_context::memory::store(context, destination_base, result)?;
+
+    Ok(())
+}
+
+///
+/// Returns the offset of the word ending at the end of the region, and the shift in bits of the
+/// region start within the word.
+///
+fn word_base<'ctx, D>(
+    context: &compiler_llvm_context::Context<'ctx, D>,
+    offset: inkwell::values::IntValue<'ctx>,
+    size: inkwell::values::IntValue<'ctx>,
+    name: &str,
+) -> (
+    inkwell::values::IntValue<'ctx>,
+    inkwell::values::IntValue<'ctx>,
+)
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let word_size = context.field_const(compiler_common::SIZE_FIELD as u64);
+
+    let end = context
+        .builder()
+        .build_int_add(offset, size, format!("{}_end", name).as_str());
+    let is_scratch = context.builder().build_int_compare(
+        inkwell::IntPredicate::ULT,
+        end,
+        word_size,
+        format!("{}_is_scratch", name).as_str(),
+    );
+    let end_word =
+        context
+            .builder()
+            .build_int_sub(end, word_size, format!("{}_end_word", name).as_str());
+    let base = context
+        .builder()
+        .build_select(
+            is_scratch,
+            context.field_const(0),
+            end_word,
+            format!("{}_base", name).as_str(),
+        )
+        .into_int_value();
+    let shift =
+        context
+            .builder()
+            .build_int_sub(offset, base, format!("{}_shift_bytes", name).as_str());
+    let shift = context.builder().build_int_mul(
+        shift,
+        context.field_const(compiler_common::BITLENGTH_BYTE as u64),
+        format!("{}_shift", name).as_str(),
+    );
+
+    (base, shift)
+}
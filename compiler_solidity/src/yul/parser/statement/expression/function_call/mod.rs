@@ -2,13 +2,19 @@
 //! The function call subexpression.
 //!
 
+pub mod memory;
 pub mod name;
+pub mod verbatim_globals;
+pub mod verbatim_settings;
 
 use num::ToPrimitive;
 
 use inkwell::types::BasicType;
 use inkwell::values::BasicValue;
+use serde::Serialize;
 
+use crate::build::coverage::Coverage;
+use crate::lowering::return_data;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -19,21 +25,33 @@ use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::statement::expression::Expression;
 
 use self::name::Name;
+use self::verbatim_settings::VerbatimSettings;
 
 ///
 /// The Yul function call subexpression.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct FunctionCall {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The function name.
+    #[serde(rename = "name")]
     pub name: Name,
     /// The function arguments expression list.
+    #[serde(rename = "arguments")]
     pub arguments: Vec<Expression>,
+    /// The location right after the closing parenthesis.
+    #[serde(rename = "end")]
+    pub end: Location,
 }
 
 impl FunctionCall {
+    /// The gas value passed to the calls forwarding all the remaining gas.
+    ///
+    /// The far call ABI gas field is 32 bits wide, and the VM caps it at the available gas.
+    pub const FORWARD_ALL_GAS: u64 = u32::MAX as u64;
+
     ///
     /// The element parser.
     ///
@@ -49,6 +67,7 @@ impl FunctionCall {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["{identifier}"],
                     found: token.lexeme.to_string(),
                 }
@@ -57,12 +76,12 @@ impl FunctionCall {
         };
 
         let mut arguments = Vec::new();
-        loop {
+        let end = loop {
             let argument = match lexer.next()? {
-                Token {
+                token @ Token {
                     lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
                     ..
-                } => break,
+                } => break token.end(),
                 token => Expression::parse(lexer, Some(token))?,
             };
 
@@ -79,18 +98,16 @@ impl FunctionCall {
                 Token {
                     lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
                     ..
-                } => {
-                    lexer.next()?;
-                    break;
-                }
-                _ => break,
+                } => break lexer.next()?.end(),
+                _ => break arguments.last().expect("Always exists").end(),
             }
-        }
+        };
 
         Ok(Self {
             location,
             name,
             arguments,
+            end,
         })
     }
 
@@ -105,6 +122,9 @@ impl FunctionCall {
         D: compiler_llvm_context::Dependency,
     {
         let location = self.location;
+        if !matches!(self.name, Name::UserDefined(_)) {
+            Coverage::record_yul(&self.name);
+        }
 
         match self.name {
             Name::UserDefined(name)
@@ -120,6 +140,21 @@ impl FunctionCall {
                 })?;
                 let r#return = function.borrow().r#return();
 
+                let mut expected_arguments_count =
+                    function.borrow().inner().count_params() as usize + 1;
+                if let compiler_llvm_context::FunctionReturn::Compound { .. } = r#return {
+                    expected_arguments_count -= 1;
+                }
+                if expected_arguments_count != values.len() {
+                    anyhow::bail!(
+                        "{} Function `{}` expected {} arguments including the near call ABI data, found {}",
+                        location,
+                        name,
+                        expected_arguments_count,
+                        values.len()
+                    );
+                }
+
                 if let compiler_llvm_context::FunctionReturn::Compound { size, .. } = r#return {
                     let r#type =
                         context
@@ -144,17 +179,6 @@ impl FunctionCall {
                     function_pointer.into_pointer_value().as_basic_value_enum(),
                 );
 
-                let expected_arguments_count = function.borrow().inner().count_params() as usize;
-                if expected_arguments_count != (values.len() - 2) {
-                    anyhow::bail!(
-                        "{} Function `{}` expected {} arguments, found {}",
-                        location,
-                        name,
-                        expected_arguments_count,
-                        values.len()
-                    );
-                }
-
                 let return_value = context.build_invoke_near_call_abi(
                     function.borrow().inner(),
                     values,
@@ -465,6 +489,15 @@ impl FunctionCall {
                     arguments[1].into_int_value(),
                 )
             }
+            Name::MCopy => {
+                let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
+                memory::copy(
+                    context,
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                )
+            }
 
             Name::SLoad => {
                 let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
@@ -533,7 +566,19 @@ impl FunctionCall {
                     arguments[2].into_int_value(),
                 )
             }
-            Name::CodeSize => compiler_llvm_context::calldata::size(context),
+            Name::CodeSize => {
+                // The constructor arguments are appended to the deploy code on EVM, whereas on
+                // zkEVM they are passed in the calldata, which `CODECOPY` reads from, so the
+                // deploy code size is the calldata size. The runtime code cannot read itself.
+                if let compiler_llvm_context::CodeType::Runtime = context.code_type() {
+                    anyhow::bail!(
+                        "{} The `CODESIZE` instruction is not supported in the runtime code",
+                        location,
+                    );
+                }
+
+                compiler_llvm_context::calldata::size(context)
+            }
             Name::CodeCopy => {
                 if let compiler_llvm_context::CodeType::Runtime = context.code_type() {
                     anyhow::bail!(
@@ -560,6 +605,10 @@ impl FunctionCall {
                     arguments[2].into_int_value(),
                 )
             }
+            Name::ReturnDataLoad => {
+                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                return_data::load(context, arguments[0].into_int_value())
+            }
             Name::ExtCodeSize => {
                 let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
                 compiler_llvm_context::ext_code::size(context, arguments[0].into_int_value())
@@ -647,17 +696,17 @@ impl FunctionCall {
             }
 
             Name::Call => {
-                let mut arguments = self.pop_arguments::<D, 7>(context)?;
+                let gas = self.pop_gas_argument(context)?;
+                let mut arguments = self.pop_arguments::<D, 6>(context)?;
 
-                let gas = arguments[0].value.into_int_value();
-                let address = arguments[1].value.into_int_value();
-                let value = arguments[2].value.into_int_value();
-                let input_offset = arguments[3].value.into_int_value();
-                let input_size = arguments[4].value.into_int_value();
-                let output_offset = arguments[5].value.into_int_value();
-                let output_size = arguments[6].value.into_int_value();
+                let address = arguments[0].value.into_int_value();
+                let value = arguments[1].value.into_int_value();
+                let input_offset = arguments[2].value.into_int_value();
+                let input_size = arguments[3].value.into_int_value();
+                let output_offset = arguments[4].value.into_int_value();
+                let output_size = arguments[5].value.into_int_value();
 
-                let simulation_address = arguments[1]
+                let simulation_address = arguments[0]
                     .constant
                     .take()
                     .and_then(|value| value.to_u16());
@@ -680,16 +729,16 @@ impl FunctionCall {
                 Ok(Some(context.field_const(0).as_basic_value_enum()))
             }
             Name::StaticCall => {
-                let mut arguments = self.pop_arguments::<D, 6>(context)?;
+                let gas = self.pop_gas_argument(context)?;
+                let mut arguments = self.pop_arguments::<D, 5>(context)?;
 
-                let gas = arguments[0].value.into_int_value();
-                let address = arguments[1].value.into_int_value();
-                let input_offset = arguments[2].value.into_int_value();
-                let input_size = arguments[3].value.into_int_value();
-                let output_offset = arguments[4].value.into_int_value();
-                let output_size = arguments[5].value.into_int_value();
+                let address = arguments[0].value.into_int_value();
+                let input_offset = arguments[1].value.into_int_value();
+                let input_size = arguments[2].value.into_int_value();
+                let output_offset = arguments[3].value.into_int_value();
+                let output_size = arguments[4].value.into_int_value();
 
-                let simulation_address = arguments[1]
+                let simulation_address = arguments[0]
                     .constant
                     .take()
                     .and_then(|value| value.to_u16());
@@ -857,7 +906,9 @@ impl FunctionCall {
 
                 compiler_llvm_context::contract_context::block_hash(context, index)
             }
-            Name::Difficulty => compiler_llvm_context::contract_context::difficulty(context),
+            Name::Difficulty | Name::Prevrandao => {
+                compiler_llvm_context::contract_context::difficulty(context)
+            }
             Name::CoinBase => compiler_llvm_context::contract_context::coinbase(context),
             Name::BaseFee => compiler_llvm_context::contract_context::basefee(context),
             Name::MSize => compiler_llvm_context::contract_context::msize(context),
@@ -1396,53 +1447,20 @@ impl FunctionCall {
                             );
                         }
 
-                        let index = match identifier
+                        let index = identifier
                             .strip_prefix(compiler_llvm_context::verbatim::GLOBAL_GETTER_PREFIX)
-                        {
-                            Some(identifier)
-                                if identifier == compiler_llvm_context::GLOBAL_CALLDATA_POINTER =>
-                            {
-                                compiler_llvm_context::GLOBAL_INDEX_CALLDATA_ABI
-                            }
-                            Some(identifier)
-                                if identifier == compiler_llvm_context::GLOBAL_CALL_FLAGS =>
-                            {
-                                compiler_llvm_context::GLOBAL_INDEX_CALL_FLAGS
-                            }
-                            Some(identifier)
-                                if identifier
-                                    .starts_with(compiler_llvm_context::GLOBAL_EXTRA_ABI_DATA) =>
-                            {
-                                match identifier
-                                    .strip_prefix(compiler_llvm_context::GLOBAL_EXTRA_ABI_DATA)
-                                {
-                                    Some("_1") => {
-                                        compiler_llvm_context::GLOBAL_INDEX_EXTRA_ABI_DATA_1
-                                    }
-
-                                    Some("_2") => {
-                                        compiler_llvm_context::GLOBAL_INDEX_EXTRA_ABI_DATA_2
-                                    }
-
-                                    suffix => anyhow::bail!(
-                                        "{} Invalid extra ABI data suffix `{:?}`",
-                                        location,
-                                        suffix
-                                    ),
-                                }
-                            }
-                            Some(identifier)
-                                if identifier
-                                    == compiler_llvm_context::GLOBAL_RETURN_DATA_POINTER =>
-                            {
-                                compiler_llvm_context::GLOBAL_INDEX_RETURN_DATA_ABI
-                            }
-                            identifier => anyhow::bail!(
-                                "{} Invalid global variable identifier `{:?}`",
-                                location,
-                                identifier
-                            ),
-                        };
+                            .and_then(|name| {
+                                VerbatimSettings::with_current(|settings| {
+                                    settings.globals.get(name)
+                                })
+                            })
+                            .ok_or_else(|| {
+                                anyhow::anyhow!(
+                                    "{} Invalid global variable identifier `{}`",
+                                    location,
+                                    identifier
+                                )
+                            })?;
 
                         compiler_llvm_context::contract::simulation::get_global(context, index)
                             .map(Some)
@@ -1570,6 +1588,21 @@ impl FunctionCall {
 
                         compiler_llvm_context::verbatim::throw(context)
                     }
+                    _identifier
+                        if VerbatimSettings::with_current(|settings| {
+                            settings.is_unknown_allowed
+                        }) =>
+                    {
+                        compiler_llvm_context::r#return::revert(
+                            context,
+                            context.field_const(0),
+                            context.field_const(0),
+                        )?;
+                        Ok(match output_size {
+                            1 => Some(context.field_const(0).as_basic_value_enum()),
+                            _ => None,
+                        })
+                    }
                     identifier => anyhow::bail!(
                         "{} Found unknown internal function `{}`",
                         location,
@@ -1578,20 +1611,13 @@ impl FunctionCall {
                 }
             }
 
-            Name::Pc => anyhow::bail!("{} The `PC` instruction is not supported", location),
-            Name::ExtCodeCopy => {
-                let _arguments = self.pop_arguments_llvm::<D, 4>(context)?;
-                anyhow::bail!(
-                    "{} The `EXTCODECOPY` instruction is not supported",
-                    location
-                )
-            }
-            Name::SelfDestruct => {
-                let _arguments = self.pop_arguments_llvm::<D, 1>(context)?;
-                anyhow::bail!(
-                    "{} The `SELFDESTRUCT` instruction is not supported",
-                    location
-                )
+            name @ (Name::Pc | Name::ExtCodeCopy | Name::SelfDestruct) => {
+                Err(Error::UnknownBuiltin {
+                    location,
+                    name: name.to_string(),
+                    is_unsupported: true,
+                }
+                .into())
             }
         }
     }
@@ -1614,6 +1640,42 @@ impl FunctionCall {
         Ok(arguments.try_into().expect("Always successful"))
     }
 
+    ///
+    /// Checks whether the gas argument of the call is `gas()`, that is, all the remaining gas
+    /// is forwarded, so the gas query can be replaced with a constant.
+    ///
+    pub fn is_forwarding_all_gas(&self) -> bool {
+        matches!(
+            self.arguments.first(),
+            Some(Expression::FunctionCall(FunctionCall {
+                name: Name::Gas,
+                ..
+            }))
+        )
+    }
+
+    ///
+    /// Pops the gas argument of the call, converted into its LLVM value.
+    ///
+    /// The `gas()` argument is dropped without being lowered, and the constant forwarding all
+    /// the remaining gas is returned instead.
+    ///
+    fn pop_gas_argument<'ctx, D>(
+        &mut self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+    ) -> anyhow::Result<inkwell::values::IntValue<'ctx>>
+    where
+        D: compiler_llvm_context::Dependency,
+    {
+        if self.is_forwarding_all_gas() {
+            self.arguments.remove(0);
+            return Ok(context.field_const(Self::FORWARD_ALL_GAS));
+        }
+
+        let [gas] = self.pop_arguments_llvm::<D, 1>(context)?;
+        Ok(gas.into_int_value())
+    }
+
     ///
     /// Pops the specified number of arguments.
     ///
@@ -1654,3 +1716,490 @@ impl FunctionCall {
         Ok(arguments.try_into().expect("Always successful"))
     }
 }
+
+impl std::fmt::Display for FunctionCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arguments: Vec<String> = self.arguments.iter().map(ToString::to_string).collect();
+        write!(f, "{}({})", self.name, arguments.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::build::Build;
+    use crate::project::Project;
+    use crate::yul::lexer::token::location::Location;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::statement::object::Object;
+
+    use super::verbatim_globals::VerbatimGlobals;
+
+    const UNKNOWN_VERBATIM: &str = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let x := verbatim_0i_1o("unknown_simulation")
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+    fn compile(input: &str, allow_unknown_verbatim: bool) -> anyhow::Result<Build> {
+        let mut project = Project::try_from_test_yul(input, &semver::Version::new(0, 8, 17))?;
+        project.allow_unknown_verbatim = allow_unknown_verbatim;
+        project.compile_test(compiler_llvm_context::OptimizerSettings::none())
+    }
+
+    #[test]
+    fn error_unknown_verbatim() {
+        let error = compile(UNKNOWN_VERBATIM, false).expect_err("Must be an error");
+        assert!(error
+            .to_string()
+            .contains("Found unknown internal function `unknown_simulation`"));
+    }
+
+    #[test]
+    fn ok_unknown_verbatim_allowed() {
+        assert!(compile(UNKNOWN_VERBATIM, true).is_ok());
+    }
+
+    #[test]
+    fn error_near_call_arguments_mismatch() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let x := ZKSYNC_NEAR_CALL_test(gas())
+                return(0, 0)
+            }
+
+            function ZKSYNC_NEAR_CALL_test(abi_data, a) -> r {
+                r := a
+            }
+
+            function ZKSYNC_CATCH_NEAR_CALL() {
+                revert(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let error = compile(input, false).expect_err("Must be an error");
+        assert!(error.to_string().contains(
+            "Function `ZKSYNC_NEAR_CALL_test` expected 2 arguments including the near call ABI data, found 1"
+        ));
+    }
+
+    #[test]
+    fn error_runtime_codecopy() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                codecopy(0, calldatasize(), 20)
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let error = compile(input, false).expect_err("Must be an error");
+        assert!(error
+            .to_string()
+            .contains("11:17 The `CODECOPY` instruction is not supported in the runtime code"));
+    }
+
+    #[test]
+    fn ok_deploy_codesize() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            let size := sub(codesize(), 32)
+            codecopy(0, 32, size)
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        assert!(compile(input, false).is_ok());
+    }
+
+    #[test]
+    fn error_runtime_codesize() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                pop(codesize())
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let error = compile(input, false).expect_err("Must be an error");
+        assert!(error
+            .to_string()
+            .contains("11:21 The `CODESIZE` instruction is not supported in the runtime code"));
+    }
+
+    #[test]
+    fn ok_mcopy() {
+        let copies = [
+            (288, 256, 64),
+            (128, 160, 64),
+            (416, 384, 32),
+            (59, 27, 5),
+            (123, 3, 5),
+            (480, 448, 0),
+        ];
+
+        let mut memory: Vec<u8> = (0..512).map(|index| (index * 7 + 3) as u8).collect();
+        let mut statements: Vec<String> = memory
+            .chunks(compiler_common::SIZE_FIELD)
+            .enumerate()
+            .map(|(index, word)| {
+                format!(
+                    "mstore({}, 0x{})",
+                    index * compiler_common::SIZE_FIELD,
+                    hex::encode(word)
+                )
+            })
+            .collect();
+        for (destination, source, size) in copies.into_iter() {
+            memory.copy_within(source..source + size, destination);
+            statements.push(format!("mcopy({}, {}, {})", destination, source, size));
+        }
+        let checks: Vec<String> = memory
+            .chunks(compiler_common::SIZE_FIELD)
+            .enumerate()
+            .map(|(index, word)| {
+                format!(
+                    "if iszero(eq(mload({}), 0x{})) {{ revert(0, 0) }}",
+                    index * compiler_common::SIZE_FIELD,
+                    hex::encode(word)
+                )
+            })
+            .collect();
+
+        let input = |statements: &[String]| {
+            format!(
+                r#"
+object "Test" {{
+    code {{
+        {{
+            return(0, 0)
+        }}
+    }}
+    object "Test_deployed" {{
+        code {{
+            {{
+                {}
+                return(0, {})
+            }}
+        }}
+    }}
+}}
+    "#,
+                statements.join("\n                "),
+                memory.len()
+            )
+        };
+        let bytecode = |input: String| {
+            Project::try_from_test_yul(input.as_str(), &semver::Version::new(0, 8, 17))
+                .expect("Always valid")
+                .compile_test(compiler_llvm_context::OptimizerSettings::cycles())
+                .expect("Always valid")
+                .contracts
+                .remove("Test")
+                .expect("Always exists")
+                .build
+                .bytecode
+        };
+
+        let unchecked = input(statements.as_slice());
+        let mut lexer = Lexer::new(unchecked.clone());
+        let object = Object::parse(&mut lexer, None).expect("Always valid");
+        assert!(object
+            .to_source()
+            .expect("Always valid")
+            .contains("mcopy(59, 27, 5)"));
+
+        // The checks are folded away only if the copy results match the reference model, so the
+        // bytecode must be the same as without them.
+        statements.extend(checks);
+        let checked = input(statements.as_slice());
+        assert_eq!(bytecode(checked), bytecode(unchecked));
+    }
+
+    #[test]
+    fn ok_registered_verbatim_global() {
+        let input = format!(
+            r#"
+object "Test" {{
+    code {{
+        {{
+            return(0, 0)
+        }}
+    }}
+    object "Test_deployed" {{
+        code {{
+            {{
+                let x := verbatim_0i_1o("{}custom_global")
+                return(0, 0)
+            }}
+        }}
+    }}
+}}
+    "#,
+            compiler_llvm_context::verbatim::GLOBAL_GETTER_PREFIX
+        );
+
+        let error = Project::compile_test_yul(input.as_str()).expect_err("Must be an error");
+        assert!(error
+            .to_string()
+            .contains("Invalid global variable identifier"));
+
+        let mut project =
+            Project::try_from_test_yul(input.as_str(), &semver::Version::new(0, 8, 17))
+                .expect("Always valid");
+        let mut globals = VerbatimGlobals::default();
+        globals.register(
+            "custom_global".to_owned(),
+            compiler_llvm_context::GLOBAL_INDEX_CALL_FLAGS,
+        );
+        project.verbatim_globals = Arc::new(globals);
+        assert!(project
+            .compile_test(compiler_llvm_context::OptimizerSettings::none())
+            .is_ok());
+    }
+
+    #[test]
+    fn prevrandao_lowered_as_difficulty() {
+        assert_eq!(super::Name::from("prevrandao"), super::Name::Prevrandao);
+
+        let bytecodes: Vec<Vec<u8>> = ["difficulty", "prevrandao"]
+            .into_iter()
+            .map(|name| {
+                let input = format!(
+                    r#"
+object "Test" {{
+    code {{
+        {{
+            return(0, 0)
+        }}
+    }}
+    object "Test_deployed" {{
+        code {{
+            {{
+                mstore(0, {}())
+                return(0, 32)
+            }}
+        }}
+    }}
+}}
+    "#,
+                    name
+                );
+                let mut build = compile(input.as_str(), false).expect("Always valid");
+                build
+                    .contracts
+                    .remove("Test")
+                    .expect("Always exists")
+                    .build
+                    .bytecode
+            })
+            .collect();
+        assert_eq!(bytecodes[0], bytecodes[1]);
+    }
+
+    #[test]
+    fn ok_returndataload() {
+        assert_eq!(
+            super::Name::from("returndataload"),
+            super::Name::ReturnDataLoad
+        );
+
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let success := call(gas(), 0x1234, 0, 0, 0, 0, 0)
+                mstore(0, returndataload(calldataload(0)))
+                return(0, 32)
+            }
+        }
+    }
+}
+    "#;
+        assert!(compile(input, false).is_ok());
+    }
+
+    #[test]
+    fn ok_call_forwarding_all_gas() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let a := staticcall(gas(), 0x1234, 0, 0, 0, 0)
+                let b := call(gas(), 0x1234, 0, 0, 0, 0, 0)
+                let c := staticcall(5000, 0x1234, 0, 0, 0, 0)
+                let d := call(sub(gas(), 5000), 0x1234, 0, 0, 0, 0, 0)
+                mstore(0, add(add(a, b), add(c, d)))
+                return(0, 32)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Always valid");
+        let mut forwarding = Vec::new();
+        object.visit_function_calls(&mut |call| {
+            if matches!(call.name, super::Name::Call | super::Name::StaticCall) {
+                forwarding.push(call.is_forwarding_all_gas());
+            }
+        });
+        assert_eq!(forwarding, vec![true, true, false, false]);
+
+        assert!(compile(input, false).is_ok());
+
+        // The `gas()` argument is not lowered, so the unoptimized code is the same as with the
+        // constant, without the gas query.
+        let bytecode = |gas: &str| {
+            let input = format!(
+                r#"
+object "Test" {{
+    code {{
+        {{
+            return(0, 0)
+        }}
+    }}
+    object "Test_deployed" {{
+        code {{
+            {{
+                let a := staticcall({0}, 0x1234, 0, 0, 0, 0)
+                let b := call({0}, 0x1234, 0, 0, 0, 0, 0)
+                mstore(0, add(a, b))
+                return(0, 32)
+            }}
+        }}
+    }}
+}}
+    "#,
+                gas
+            );
+            Project::compile_test_yul(input.as_str())
+                .expect("Always valid")
+                .contracts
+                .remove("Test")
+                .expect("Always exists")
+                .build
+                .bytecode
+        };
+        let forwarding_all_gas = bytecode("gas()");
+        assert_eq!(
+            forwarding_all_gas,
+            bytecode(super::FunctionCall::FORWARD_ALL_GAS.to_string().as_str())
+        );
+        assert_ne!(forwarding_all_gas, bytecode("add(gas(), 0)"));
+    }
+
+    #[test]
+    fn ok_keccak256_scratch_space() {
+        let input = format!(
+            r#"
+object "Test" {{
+    code {{
+        {{
+            return(0, 0)
+        }}
+    }}
+    object "Test_deployed" {{
+        code {{
+            {{
+                mstore({scratch}, calldataload(0))
+                mstore(add({scratch}, 32), calldataload(32))
+                let scratch_hash := keccak256({scratch}, 64)
+                let reserved_hash := keccak256({scratch}, {non_reserved})
+                mstore({non_reserved}, scratch_hash)
+                mstore(add({non_reserved}, 32), reserved_hash)
+                mstore(add({non_reserved}, 64), mload({scratch}))
+                return({non_reserved}, 96)
+            }}
+        }}
+    }}
+}}
+    "#,
+            scratch = crate::r#const::OFFSET_SCRATCH_SPACE,
+            non_reserved = crate::r#const::OFFSET_NON_RESERVED,
+        );
+
+        let object = Object::parse(&mut Lexer::new(input.clone()), None).expect("Always valid");
+        assert_eq!(object.reserved_memory_hashes(), vec![Location::new(14, 38)]);
+
+        for optimizer_settings in [
+            compiler_llvm_context::OptimizerSettings::none(),
+            compiler_llvm_context::OptimizerSettings::cycles(),
+        ] {
+            let build = Project::try_from_test_yul(input.as_str(), &semver::Version::new(0, 8, 17))
+                .expect("Always valid")
+                .compile_test(optimizer_settings)
+                .expect("Always valid");
+            assert!(!build.contracts["Test"].build.bytecode.is_empty());
+        }
+    }
+}
@@ -75,6 +75,8 @@ pub enum Name {
     MStore,
     /// `mem[p] := v & 0xff` (only modifies a single byte)
     MStore8,
+    /// copy `s` bytes from mem at position `f` to mem at position `t`
+    MCopy,
 
     /// `storage[p]`
     SLoad,
@@ -103,6 +105,8 @@ pub enum Name {
     ReturnDataSize,
     /// copy `s` bytes from returndata at position `f` to mem at position `t`
     ReturnDataCopy,
+    /// returndata starting from position `p` (32 bytes)
+    ReturnDataLoad,
 
     /// end execution, return data `mem[p…(p+s))`
     Return,
@@ -186,6 +190,8 @@ pub enum Name {
     BlockHash,
     /// difficulty of the current block
     Difficulty,
+    /// the `difficulty` replacement since the Paris upgrade
+    Prevrandao,
     /// current mining beneficiary
     CoinBase,
     /// size of memory, i.e. largest accessed memory index
@@ -211,6 +217,30 @@ pub enum Name {
 }
 
 impl Name {
+    ///
+    /// The EVM builtins deliberately not supported on zkEVM.
+    ///
+    /// The transient storage and blob builtins are not recognized by the parser, as they are not
+    /// reserved in the older Yul dialects. Their calls are only reported as unsupported if no
+    /// function with the same name is declared in the scope.
+    ///
+    pub const UNSUPPORTED: [&'static str; 7] = [
+        "pc",
+        "extcodecopy",
+        "selfdestruct",
+        "tload",
+        "tstore",
+        "blobhash",
+        "blobbasefee",
+    ];
+
+    ///
+    /// Whether the name is an EVM builtin, which is deliberately not supported on zkEVM.
+    ///
+    pub fn is_unsupported(&self) -> bool {
+        matches!(self, Self::Pc | Self::ExtCodeCopy | Self::SelfDestruct)
+    }
+
     ///
     /// Tries parsing the verbatim instruction.
     ///
@@ -269,6 +299,7 @@ impl From<&str> for Name {
             "mload" => Self::MLoad,
             "mstore" => Self::MStore,
             "mstore8" => Self::MStore8,
+            "mcopy" => Self::MCopy,
 
             "sload" => Self::SLoad,
             "sstore" => Self::SStore,
@@ -283,6 +314,7 @@ impl From<&str> for Name {
             "extcodesize" => Self::ExtCodeSize,
             "returndatasize" => Self::ReturnDataSize,
             "returndatacopy" => Self::ReturnDataCopy,
+            "returndataload" => Self::ReturnDataLoad,
 
             "return" => Self::Return,
             "revert" => Self::Revert,
@@ -325,6 +357,7 @@ impl From<&str> for Name {
             "blockhash" => Self::BlockHash,
 
             "difficulty" => Self::Difficulty,
+            "prevrandao" => Self::Prevrandao,
             "pc" => Self::Pc,
             "balance" => Self::Balance,
             "selfbalance" => Self::SelfBalance,
@@ -338,3 +371,114 @@ impl From<&str> for Name {
         }
     }
 }
+
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserDefined(inner) => write!(f, "{}", inner),
+            Self::Verbatim {
+                input_size,
+                output_size,
+                ..
+            } => write!(f, "verbatim_{}i_{}o", input_size, output_size),
+            Self::Add => write!(f, "add"),
+            Self::Sub => write!(f, "sub"),
+            Self::Mul => write!(f, "mul"),
+            Self::Div => write!(f, "div"),
+            Self::Mod => write!(f, "mod"),
+            Self::Sdiv => write!(f, "sdiv"),
+            Self::Smod => write!(f, "smod"),
+            Self::Lt => write!(f, "lt"),
+            Self::Gt => write!(f, "gt"),
+            Self::Eq => write!(f, "eq"),
+            Self::IsZero => write!(f, "iszero"),
+            Self::Slt => write!(f, "slt"),
+            Self::Sgt => write!(f, "sgt"),
+            Self::Or => write!(f, "or"),
+            Self::Xor => write!(f, "xor"),
+            Self::Not => write!(f, "not"),
+            Self::And => write!(f, "and"),
+            Self::Shl => write!(f, "shl"),
+            Self::Shr => write!(f, "shr"),
+            Self::Sar => write!(f, "sar"),
+            Self::Byte => write!(f, "byte"),
+            Self::Pop => write!(f, "pop"),
+            Self::AddMod => write!(f, "addmod"),
+            Self::MulMod => write!(f, "mulmod"),
+            Self::Exp => write!(f, "exp"),
+            Self::SignExtend => write!(f, "signextend"),
+            Self::Keccak256 => write!(f, "keccak256"),
+            Self::MLoad => write!(f, "mload"),
+            Self::MStore => write!(f, "mstore"),
+            Self::MStore8 => write!(f, "mstore8"),
+            Self::MCopy => write!(f, "mcopy"),
+            Self::SLoad => write!(f, "sload"),
+            Self::SStore => write!(f, "sstore"),
+            Self::LoadImmutable => write!(f, "loadimmutable"),
+            Self::SetImmutable => write!(f, "setimmutable"),
+            Self::CallDataLoad => write!(f, "calldataload"),
+            Self::CallDataSize => write!(f, "calldatasize"),
+            Self::CallDataCopy => write!(f, "calldatacopy"),
+            Self::CodeSize => write!(f, "codesize"),
+            Self::CodeCopy => write!(f, "codecopy"),
+            Self::ExtCodeSize => write!(f, "extcodesize"),
+            Self::ReturnDataSize => write!(f, "returndatasize"),
+            Self::ReturnDataCopy => write!(f, "returndatacopy"),
+            Self::ReturnDataLoad => write!(f, "returndataload"),
+            Self::Return => write!(f, "return"),
+            Self::Revert => write!(f, "revert"),
+            Self::Log0 => write!(f, "log0"),
+            Self::Log1 => write!(f, "log1"),
+            Self::Log2 => write!(f, "log2"),
+            Self::Log3 => write!(f, "log3"),
+            Self::Log4 => write!(f, "log4"),
+            Self::Call => write!(f, "call"),
+            Self::CallCode => write!(f, "callcode"),
+            Self::DelegateCall => write!(f, "delegatecall"),
+            Self::StaticCall => write!(f, "staticcall"),
+            Self::Create => write!(f, "create"),
+            Self::Create2 => write!(f, "create2"),
+            Self::DataSize => write!(f, "datasize"),
+            Self::DataOffset => write!(f, "dataoffset"),
+            Self::DataCopy => write!(f, "datacopy"),
+            Self::Stop => write!(f, "stop"),
+            Self::Invalid => write!(f, "invalid"),
+            Self::LinkerSymbol => write!(f, "linkersymbol"),
+            Self::MemoryGuard => write!(f, "memoryguard"),
+            Self::Address => write!(f, "address"),
+            Self::Caller => write!(f, "caller"),
+            Self::Timestamp => write!(f, "timestamp"),
+            Self::Number => write!(f, "number"),
+            Self::Gas => write!(f, "gas"),
+            Self::GasLimit => write!(f, "gaslimit"),
+            Self::GasPrice => write!(f, "gasprice"),
+            Self::CallValue => write!(f, "callvalue"),
+            Self::MSize => write!(f, "msize"),
+            Self::Origin => write!(f, "origin"),
+            Self::ChainId => write!(f, "chainid"),
+            Self::BlockHash => write!(f, "blockhash"),
+            Self::Difficulty => write!(f, "difficulty"),
+            Self::Prevrandao => write!(f, "prevrandao"),
+            Self::Pc => write!(f, "pc"),
+            Self::Balance => write!(f, "balance"),
+            Self::SelfBalance => write!(f, "selfbalance"),
+            Self::CoinBase => write!(f, "coinbase"),
+            Self::BaseFee => write!(f, "basefee"),
+            Self::ExtCodeCopy => write!(f, "extcodecopy"),
+            Self::ExtCodeHash => write!(f, "extcodehash"),
+            Self::SelfDestruct => write!(f, "selfdestruct"),
+        }
+    }
+}
+
+impl serde::Serialize for Name {
+    ///
+    /// Serializes the name as it is spelled in the Yul source code.
+    ///
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
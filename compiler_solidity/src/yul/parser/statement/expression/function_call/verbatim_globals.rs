@@ -0,0 +1,61 @@
+//!
+//! The `verbatim` global variable getters lookup table.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The `verbatim` global variable getters lookup table.
+///
+/// Maps the global variable names following the getter prefix to the global variable indexes.
+/// The default table contains the globals supported by the compiler, and newer ones can be
+/// registered without changing the `verbatim` lowering.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerbatimGlobals {
+    /// The global variable indexes.
+    indexes: BTreeMap<String, usize>,
+}
+
+impl VerbatimGlobals {
+    ///
+    /// Registers the global variable, replacing the index of an existing one.
+    ///
+    pub fn register(&mut self, name: String, index: usize) {
+        self.indexes.insert(name, index);
+    }
+
+    ///
+    /// Returns the index of the global variable, if it is registered.
+    ///
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.indexes.get(name).copied()
+    }
+}
+
+impl Default for VerbatimGlobals {
+    fn default() -> Self {
+        let mut indexes = BTreeMap::new();
+        indexes.insert(
+            compiler_llvm_context::GLOBAL_CALLDATA_POINTER.to_owned(),
+            compiler_llvm_context::GLOBAL_INDEX_CALLDATA_ABI,
+        );
+        indexes.insert(
+            compiler_llvm_context::GLOBAL_CALL_FLAGS.to_owned(),
+            compiler_llvm_context::GLOBAL_INDEX_CALL_FLAGS,
+        );
+        indexes.insert(
+            format!("{}_1", compiler_llvm_context::GLOBAL_EXTRA_ABI_DATA),
+            compiler_llvm_context::GLOBAL_INDEX_EXTRA_ABI_DATA_1,
+        );
+        indexes.insert(
+            format!("{}_2", compiler_llvm_context::GLOBAL_EXTRA_ABI_DATA),
+            compiler_llvm_context::GLOBAL_INDEX_EXTRA_ABI_DATA_2,
+        );
+        indexes.insert(
+            compiler_llvm_context::GLOBAL_RETURN_DATA_POINTER.to_owned(),
+            compiler_llvm_context::GLOBAL_INDEX_RETURN_DATA_ABI,
+        );
+        Self { indexes }
+    }
+}
@@ -0,0 +1,78 @@
+//!
+//! The `verbatim` simulations lowering settings.
+//!
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use crate::yul::parser::statement::expression::function_call::verbatim_globals::VerbatimGlobals;
+
+thread_local! {
+    /// The stack of the settings entered on the current thread, where the last ones are current.
+    static ENTERED: RefCell<Vec<VerbatimSettings>> = RefCell::new(Vec::new());
+}
+
+///
+/// The `verbatim` simulations lowering settings, which are taken from the project.
+///
+/// The LLVM context does not carry the project settings, so they are entered for the duration
+/// of a contract compilation on its thread, and read by the `verbatim` lowering. The dependency
+/// contracts are compiled on the same thread, so the settings are stacked.
+///
+#[derive(Debug, Default, Clone)]
+pub struct VerbatimSettings {
+    /// Whether the unknown simulations are lowered to a revert instead of a compile-time error.
+    pub is_unknown_allowed: bool,
+    /// The global variable getters table.
+    pub globals: Arc<VerbatimGlobals>,
+}
+
+impl VerbatimSettings {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(is_unknown_allowed: bool, globals: Arc<VerbatimGlobals>) -> Self {
+        Self {
+            is_unknown_allowed,
+            globals,
+        }
+    }
+
+    ///
+    /// Makes the settings current on this thread until the returned guard is dropped.
+    ///
+    pub fn enter(self) -> Guard {
+        ENTERED.with(|entered| entered.borrow_mut().push(self));
+        Guard { _private: () }
+    }
+
+    ///
+    /// Calls `f` with the current settings, or with the default ones if none are entered.
+    ///
+    pub fn with_current<F, R>(f: F) -> R
+    where
+        F: FnOnce(&Self) -> R,
+    {
+        ENTERED.with(|entered| match entered.borrow().last() {
+            Some(settings) => f(settings),
+            None => f(&Self::default()),
+        })
+    }
+}
+
+///
+/// Restores the previously entered settings on drop.
+///
+#[must_use = "The settings are only current until the guard is dropped"]
+pub struct Guard {
+    /// Prevents the construction outside of `VerbatimSettings::enter`.
+    _private: (),
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        ENTERED.with(|entered| {
+            entered.borrow_mut().pop();
+        });
+    }
+}
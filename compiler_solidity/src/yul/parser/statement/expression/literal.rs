@@ -6,6 +6,7 @@ use inkwell::values::BasicValue;
 use num::Num;
 use num::One;
 use num::Zero;
+use serde::Serialize;
 
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::literal::boolean::Boolean as BooleanLiteral;
@@ -22,14 +23,20 @@ use crate::yul::parser::r#type::Type;
 ///
 /// Represents a literal in YUL without differentiating its type.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Literal {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The lexical literal.
+    #[serde(rename = "inner")]
     pub inner: LexicalLiteral,
     /// The type, if it has been explicitly specified.
+    #[serde(rename = "type")]
     pub yul_type: Option<Type>,
+    /// The location right after the literal, including the type.
+    #[serde(rename = "end")]
+    pub end: Location,
 }
 
 impl Literal {
@@ -38,6 +45,7 @@ impl Literal {
     ///
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
+        let mut end = token.end();
 
         let (location, literal) = match token {
             Token {
@@ -48,6 +56,7 @@ impl Literal {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["{literal}"],
                     found: token.lexeme.to_string(),
                 }
@@ -55,13 +64,21 @@ impl Literal {
             }
         };
 
+        if let LexicalLiteral::Integer(ref integer) = literal {
+            if Self::integer_value(integer).bits() > compiler_common::BITLENGTH_FIELD as u64 {
+                return Err(ParserError::LiteralOverflow { location }.into());
+            }
+        }
+
         let yul_type = match lexer.peek()? {
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Colon),
                 ..
             } => {
                 lexer.next()?;
-                Some(Type::parse(lexer, None)?)
+                let token = lexer.next()?;
+                end = token.end();
+                Some(Type::parse(lexer, Some(token))?)
             }
             _ => None,
         };
@@ -70,6 +87,7 @@ impl Literal {
             location,
             inner: literal,
             yul_type,
+            end,
         })
     }
 
@@ -120,16 +138,7 @@ impl Literal {
                 .expect("The value is valid")
                 .as_basic_value_enum();
 
-                let constant = match inner {
-                    IntegerLiteral::Decimal { ref inner } => {
-                        num::BigUint::from_str_radix(inner.as_str(), compiler_common::BASE_DECIMAL)
-                    }
-                    IntegerLiteral::Hexadecimal { ref inner } => num::BigUint::from_str_radix(
-                        &inner["0x".len()..],
-                        compiler_common::BASE_HEXADECIMAL,
-                    ),
-                }
-                .expect("Always valid");
+                let constant = Self::integer_value(&inner);
 
                 compiler_llvm_context::Argument::new_with_constant(value, constant)
             }
@@ -186,4 +195,85 @@ impl Literal {
             }
         }
     }
+
+    ///
+    /// Returns the numeric value if the literal is an integer one.
+    ///
+    pub fn integer(&self) -> Option<num::BigUint> {
+        match self.inner {
+            LexicalLiteral::Integer(ref integer) => Some(Self::integer_value(integer)),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the numeric value of the integer literal.
+    ///
+    fn integer_value(integer: &IntegerLiteral) -> num::BigUint {
+        match integer {
+            IntegerLiteral::Decimal { inner } => {
+                num::BigUint::from_str_radix(inner.as_str(), compiler_common::BASE_DECIMAL)
+            }
+            IntegerLiteral::Hexadecimal { inner } => num::BigUint::from_str_radix(
+                &inner["0x".len()..],
+                compiler_common::BASE_HEXADECIMAL,
+            ),
+        }
+        .expect("Always valid")
+    }
+}
+
+impl std::fmt::Display for Literal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.inner {
+            LexicalLiteral::String(ref inner) if inner.is_hexadecimal => {
+                write!(f, "hex\"{}\"", inner)?
+            }
+            LexicalLiteral::String(ref inner) => write!(f, "\"{}\"", inner)?,
+            ref inner => write!(f, "{}", inner)?,
+        }
+        if let Some(yul_type) = self.yul_type.as_ref() {
+            write!(f, ":{}", yul_type)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::token::location::Location;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::error::Error;
+    use crate::yul::parser::statement::object::Object;
+
+    #[test]
+    fn error_literal_overflow() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let x := 0x10000000000000000000000000000000000000000000000000000000000000000
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::LiteralOverflow {
+                location: Location::new(11, 26),
+            }
+            .into())
+        );
+    }
 }
@@ -5,6 +5,10 @@
 pub mod function_call;
 pub mod literal;
 
+use serde::Serialize;
+
+use crate::failure::Failure;
+use crate::project::Project;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -20,13 +24,16 @@ use self::literal::Literal;
 ///
 /// The Yul expression statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum Expression {
     /// The function call subexpression.
+    #[serde(rename = "FunctionCall")]
     FunctionCall(FunctionCall),
     /// The identifier operand.
+    #[serde(rename = "Identifier")]
     Identifier(Identifier),
     /// The literal operand.
+    #[serde(rename = "Literal")]
     Literal(Literal),
 }
 
@@ -50,6 +57,7 @@ impl Expression {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["{literal}", "{identifier}"],
                     found: token.lexeme.to_string(),
                 }
@@ -87,6 +95,47 @@ impl Expression {
         }
     }
 
+    ///
+    /// Returns the location right after the expression.
+    ///
+    pub fn end(&self) -> Location {
+        match self {
+            Self::FunctionCall(inner) => inner.end,
+            Self::Identifier(inner) => inner.end(),
+            Self::Literal(inner) => inner.end,
+        }
+    }
+
+    ///
+    /// Calls the visitor for each function call in the expression, including the nested ones.
+    ///
+    pub fn visit_function_calls<F>(&self, visitor: &mut F)
+    where
+        F: FnMut(&FunctionCall),
+    {
+        if let Self::FunctionCall(call) = self {
+            visitor(call);
+            for argument in call.arguments.iter() {
+                argument.visit_function_calls(visitor);
+            }
+        }
+    }
+
+    ///
+    /// Calls the visitor for each function call in the expression, including the nested ones.
+    ///
+    pub fn visit_function_calls_mut<F>(&mut self, visitor: &mut F)
+    where
+        F: FnMut(&mut FunctionCall),
+    {
+        if let Self::FunctionCall(call) = self {
+            visitor(call);
+            for argument in call.arguments.iter_mut() {
+                argument.visit_function_calls_mut(visitor);
+            }
+        }
+    }
+
     ///
     /// Converts the expression into an LLVM value.
     ///
@@ -132,4 +181,109 @@ impl Expression {
                 .map(compiler_llvm_context::Argument::new)),
         }
     }
+
+    ///
+    /// Parses the Yul expression and lowers it into the body of a standalone function,
+    /// returning the LLVM IR of the module.
+    ///
+    /// The lexical and syntax errors are returned as `Error`, and the lowering failures are
+    /// tagged with `Failure::LLVM`.
+    ///
+    /// Only for unit testing purposes.
+    ///
+    pub fn try_lower_test(input: &str) -> anyhow::Result<String> {
+        let mut lexer = Lexer::new(input.to_owned());
+        let expression = Self::parse(&mut lexer, None)?;
+        expression
+            .lower_test()
+            .map_err(|error| Failure::LLVM.wrap(error))
+    }
+
+    ///
+    /// Lowers the expression into the body of a standalone function, returning the LLVM IR of
+    /// the module.
+    ///
+    fn lower_test(self) -> anyhow::Result<String> {
+        compiler_llvm_context::initialize_target();
+        let llvm = inkwell::context::Context::create();
+        let optimizer_settings = compiler_llvm_context::OptimizerSettings::none();
+        let target_machine = compiler_llvm_context::TargetMachine::new(&optimizer_settings)?;
+        let optimizer = compiler_llvm_context::Optimizer::new(target_machine, optimizer_settings);
+        let mut context = compiler_llvm_context::Context::<Project>::new(
+            &llvm,
+            "test",
+            optimizer,
+            None,
+            compiler_llvm_context::DumpFlag::initialize(false, false, false, false, false, false),
+        );
+
+        let function_type = context.function_type(0, vec![]);
+        let function = context.add_function(
+            "test",
+            function_type,
+            0,
+            Some(inkwell::module::Linkage::Private),
+        )?;
+        function
+            .borrow_mut()
+            .set_yul_data(compiler_llvm_context::FunctionYulData::default());
+        context.set_current_function("test")?;
+        context.set_basic_block(context.current_function().borrow().entry_block());
+        self.into_llvm(&mut context)?;
+
+        Ok(context.module().print_to_string().to_string())
+    }
+}
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FunctionCall(inner) => write!(f, "{}", inner),
+            Self::Identifier(inner) => write!(f, "{}", inner),
+            Self::Literal(inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::failure::Failure;
+    use crate::yul::error::Error;
+
+    use super::Expression;
+
+    #[test]
+    fn lower_add() {
+        // The IR builder folds the constant operands, so one of them is made a runtime value.
+        let ir = Expression::try_lower_test("add(address(), 2)").expect("Always valid");
+        assert!(ir.contains(" = add i256 "));
+    }
+
+    #[test]
+    fn error_classified_as_lexer() {
+        let error = Expression::try_lower_test("add(1, #)").expect_err("Must be an error");
+        let error = error.downcast_ref::<Error>().expect("Must be a Yul error");
+        assert!(error.as_lexer_error().is_some());
+        assert!(error.as_parser_error().is_none());
+    }
+
+    #[test]
+    fn error_classified_as_parser() {
+        let error = Expression::try_lower_test("add(1, 2").expect_err("Must be an error");
+        assert_eq!(Failure::of(&error), Some(Failure::Parsing));
+        let error = error.downcast_ref::<Error>().expect("Must be a Yul error");
+        assert!(error.as_lexer_error().is_none());
+        assert!(error.as_parser_error().is_some());
+    }
+
+    #[test]
+    fn error_classified_as_llvm() {
+        let error =
+            Expression::try_lower_test("undeclared_function()").expect_err("Must be an error");
+        assert!(error.downcast_ref::<Error>().is_none());
+        assert_eq!(Failure::of(&error), Some(Failure::LLVM));
+        assert!(error
+            .to_string()
+            .contains("Undeclared function `undeclared_function`"));
+    }
 }
@@ -2,27 +2,36 @@
 //! The for-loop statement.
 //!
 
+use serde::Serialize;
+
 use crate::yul::error::Error;
+use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::expression::Expression;
 
 ///
 /// The Yul for-loop statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ForLoop {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The index variables initialization block.
+    #[serde(rename = "initializer")]
     pub initializer: Block,
     /// The continue condition block.
+    #[serde(rename = "condition")]
     pub condition: Expression,
     /// The index variables mutating block.
+    #[serde(rename = "finalizer")]
     pub finalizer: Block,
     /// The loop body.
+    #[serde(rename = "body")]
     pub body: Block,
 }
 
@@ -36,7 +45,19 @@ impl ForLoop {
 
         let initializer = Block::parse(lexer, Some(token))?;
 
-        let condition = Expression::parse(lexer, None)?;
+        let condition = match lexer.next()? {
+            token @ Token {
+                lexeme: Lexeme::Literal(_) | Lexeme::Identifier(_),
+                ..
+            } => Expression::parse(lexer, Some(token))?,
+            token => {
+                return Err(ParserError::InvalidForLoopCondition {
+                    location: token.location,
+                    found: token.lexeme.to_string(),
+                }
+                .into());
+            }
+        };
 
         let finalizer = Block::parse(lexer, None)?;
 
@@ -52,6 +73,16 @@ impl ForLoop {
     }
 }
 
+impl std::fmt::Display for ForLoop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "for {} {} {} {}",
+            self.initializer, self.condition, self.finalizer, self.body
+        )
+    }
+}
+
 impl<D> compiler_llvm_context::WriteLLVM<D> for ForLoop
 where
     D: compiler_llvm_context::Dependency,
@@ -101,3 +132,57 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::token::location::Location;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::error::Error;
+    use crate::yul::parser::statement::object::Object;
+
+    fn parse(body: &str) -> Result<Object, crate::yul::error::Error> {
+        let input = format!(
+            r#"
+object "Test" {{
+    code {{
+        {{
+            return(0, 0)
+        }}
+    }}
+    object "Test_deployed" {{
+        code {{
+            {{
+{}
+            }}
+        }}
+    }}
+}}
+    "#,
+            body
+        );
+
+        let mut lexer = Lexer::new(input);
+        Object::parse(&mut lexer, None)
+    }
+
+    #[test]
+    fn ok_conditions() {
+        assert!(parse("                for {} 1 {} { break }").is_ok());
+        assert!(parse("                for { let i := 0 } i { i := sub(i, 1) } {}").is_ok());
+        assert!(
+            parse("                for { let i := 0 } lt(i, 10) { i := add(i, 1) } {}").is_ok()
+        );
+    }
+
+    #[test]
+    fn error_block_condition() {
+        assert_eq!(
+            parse("                for {} {} {} {}"),
+            Err(Error::InvalidForLoopCondition {
+                location: Location::new(11, 24),
+                found: "{".to_owned(),
+            }
+            .into())
+        );
+    }
+}
@@ -2,7 +2,10 @@
 //! The function definition statement.
 //!
 
+use std::collections::BTreeSet;
+
 use inkwell::types::BasicType;
+use serde::Serialize;
 
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
@@ -22,17 +25,22 @@ use crate::yul::parser::statement::expression::function_call::name::Name as Func
 /// 1. The hoisted declaration
 /// 2. The definition, which now has the access to all function signatures
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct FunctionDefinition {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The function identifier.
+    #[serde(rename = "identifier")]
     pub identifier: String,
     /// The function formal arguments.
+    #[serde(rename = "arguments")]
     pub arguments: Vec<Identifier>,
     /// The function return variables.
+    #[serde(rename = "result")]
     pub result: Vec<Identifier>,
     /// The function body block.
+    #[serde(rename = "body")]
     pub body: Block,
 }
 
@@ -52,6 +60,7 @@ impl FunctionDefinition {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["{identifier}"],
                     found: token.lexeme.to_string(),
                 }
@@ -78,6 +87,7 @@ impl FunctionDefinition {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["("],
                     found: token.lexeme.to_string(),
                 }
@@ -124,6 +134,7 @@ impl FunctionDefinition {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec![")"],
                     found: token.lexeme.to_string(),
                 }
@@ -137,7 +148,18 @@ impl FunctionDefinition {
                 ..
             } => {
                 lexer.next()?;
-                Identifier::parse_typed_list(lexer, None)?
+                let (result, next) = Identifier::parse_typed_list(lexer, None)?;
+                if result.is_empty() {
+                    let token = crate::yul::parser::take_or_next(next, lexer)?;
+                    return Err(ParserError::InvalidToken {
+                        location: token.location,
+                        length: token.length,
+                        expected: vec!["{identifier}"],
+                        found: token.lexeme.to_string(),
+                    }
+                    .into());
+                }
+                (result, next)
             }
             Token {
                 lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
@@ -146,6 +168,7 @@ impl FunctionDefinition {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["->", "{"],
                     found: token.lexeme.to_string(),
                 }
@@ -163,6 +186,37 @@ impl FunctionDefinition {
             body,
         })
     }
+
+    ///
+    /// Returns the return variables which are never assigned in the function body, so the
+    /// function always returns zero in them.
+    ///
+    /// The functions which never return, e.g. always revert, are not checked.
+    ///
+    pub fn unassigned_results(&self) -> Vec<&Identifier> {
+        if self.result.is_empty() || self.body.is_terminating(&BTreeSet::new()) {
+            return vec![];
+        }
+
+        let mut assigned = BTreeSet::new();
+        self.body.assigned_variables(&mut assigned);
+        self.result
+            .iter()
+            .filter(|result| !assigned.contains(&result.inner))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for FunctionDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arguments: Vec<String> = self.arguments.iter().map(ToString::to_string).collect();
+        write!(f, "function {}({})", self.identifier, arguments.join(", "))?;
+        if !self.result.is_empty() {
+            let result: Vec<String> = self.result.iter().map(ToString::to_string).collect();
+            write!(f, " -> {}", result.join(", "))?;
+        }
+        write!(f, " {}", self.body)
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for FunctionDefinition
@@ -337,6 +391,7 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(14, 22),
+                length: 3,
                 expected: vec!["{identifier}"],
                 found: "256".to_owned(),
             }
@@ -373,6 +428,7 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(14, 26),
+                length: 1,
                 expected: vec!["("],
                 found: "{".to_owned(),
             }
@@ -409,6 +465,7 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(14, 27),
+                length: 1,
                 expected: vec![")"],
                 found: "}".to_owned(),
             }
@@ -445,6 +502,7 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(14, 29),
+                length: 2,
                 expected: vec!["->", "{"],
                 found: ":=".to_owned(),
             }
@@ -560,4 +618,106 @@ object "Test" {
             .into())
         );
     }
+
+    #[test]
+    fn ok_empty_typed_lists() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+
+            function test() {
+                mstore(0, 42)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn error_empty_result_list() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+
+            function test() -> {
+                mstore(0, 42)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::InvalidToken {
+                location: Location::new(14, 32),
+                length: 1,
+                expected: vec!["{identifier}"],
+                found: "{".to_owned(),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn error_trailing_comma_argument_list() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+
+            function test(a, ) -> result {
+                result := a
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::InvalidToken {
+                location: Location::new(14, 30),
+                length: 1,
+                expected: vec!["{identifier}"],
+                found: ")".to_owned(),
+            }
+            .into())
+        );
+    }
 }
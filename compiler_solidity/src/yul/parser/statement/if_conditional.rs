@@ -2,6 +2,8 @@
 //! The if-conditional statement.
 //!
 
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
@@ -12,13 +14,16 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 /// The Yul if-conditional statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct IfConditional {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The condition expression.
+    #[serde(rename = "condition")]
     pub condition: Expression,
     /// The conditional block.
+    #[serde(rename = "block")]
     pub block: Block,
 }
 
@@ -42,6 +47,12 @@ impl IfConditional {
     }
 }
 
+impl std::fmt::Display for IfConditional {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "if {} {}", self.condition, self.block)
+    }
+}
+
 impl<D> compiler_llvm_context::WriteLLVM<D> for IfConditional
 where
     D: compiler_llvm_context::Dependency,
@@ -13,6 +13,10 @@ pub mod object;
 pub mod switch;
 pub mod variable_declaration;
 
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -24,6 +28,8 @@ use crate::yul::parser::error::Error as ParserError;
 use self::assignment::Assignment;
 use self::block::Block;
 use self::code::Code;
+use self::expression::function_call::name::Name;
+use self::expression::function_call::FunctionCall;
 use self::expression::Expression;
 use self::for_loop::ForLoop;
 use self::function_definition::FunctionDefinition;
@@ -35,33 +41,46 @@ use self::variable_declaration::VariableDeclaration;
 ///
 /// The Yul block statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum Statement {
     /// The object element.
+    #[serde(rename = "Object")]
     Object(Object),
     /// The code element.
+    #[serde(rename = "Code")]
     Code(Code),
     /// The code block.
+    #[serde(rename = "Block")]
     Block(Block),
     /// The expression.
+    #[serde(rename = "Expression")]
     Expression(Expression),
     /// The `function` statement.
+    #[serde(rename = "FunctionDefinition")]
     FunctionDefinition(FunctionDefinition),
     /// The `let` statement.
+    #[serde(rename = "VariableDeclaration")]
     VariableDeclaration(VariableDeclaration),
     /// The `:=` existing variables reassignment statement.
+    #[serde(rename = "Assignment")]
     Assignment(Assignment),
     /// The `if` statement.
+    #[serde(rename = "IfConditional")]
     IfConditional(IfConditional),
     /// The `switch` statement.
+    #[serde(rename = "Switch")]
     Switch(Switch),
     /// The `for` statement.
+    #[serde(rename = "ForLoop")]
     ForLoop(ForLoop),
     /// The `continue` statement.
+    #[serde(rename = "Continue")]
     Continue(Location),
     /// The `break` statement.
+    #[serde(rename = "Break")]
     Break(Location),
     /// The `leave` statement.
+    #[serde(rename = "Leave")]
     Leave(Location),
 }
 
@@ -130,6 +149,7 @@ impl Statement {
             } => Ok((Statement::Leave(location), None)),
             token => Err(ParserError::InvalidToken {
                 location: token.location,
+                length: token.length,
                 expected: vec![
                     "object", "code", "function", "let", "if", "switch", "for", "continue",
                     "break", "leave",
@@ -160,4 +180,175 @@ impl Statement {
             Self::Leave(location) => *location,
         }
     }
+
+    ///
+    /// Returns the location right after the statement.
+    ///
+    pub fn end(&self) -> Location {
+        let keyword_end = |location: &Location, keyword: Keyword| {
+            let mut end = *location;
+            end.shift_right(keyword.to_string().len());
+            end
+        };
+
+        match self {
+            Self::Object(inner) => inner.end,
+            Self::Code(inner) => inner.block.end,
+            Self::Block(inner) => inner.end,
+            Self::Expression(inner) => inner.end(),
+            Self::FunctionDefinition(inner) => inner.body.end,
+            Self::VariableDeclaration(inner) => match inner.expression {
+                Some(ref expression) => expression.end(),
+                None => inner
+                    .bindings
+                    .last()
+                    .map(|binding| binding.end())
+                    .unwrap_or(inner.location),
+            },
+            Self::Assignment(inner) => inner.initializer.end(),
+            Self::IfConditional(inner) => inner.block.end,
+            Self::Switch(inner) => match (inner.default.as_ref(), inner.cases.last()) {
+                (Some(default), _) => default.end,
+                (None, Some(case)) => case.block.end,
+                (None, None) => inner.expression.end(),
+            },
+            Self::ForLoop(inner) => inner.body.end,
+            Self::Continue(location) => keyword_end(location, Keyword::Continue),
+            Self::Break(location) => keyword_end(location, Keyword::Break),
+            Self::Leave(location) => keyword_end(location, Keyword::Leave),
+        }
+    }
+
+    ///
+    /// Calls the visitor for each function call in the statement, including the nested ones.
+    ///
+    pub fn visit_function_calls<F>(&self, visitor: &mut F)
+    where
+        F: FnMut(&FunctionCall),
+    {
+        match self {
+            Self::Object(inner) => inner.visit_function_calls(visitor),
+            Self::Code(inner) => inner.block.visit_function_calls(visitor),
+            Self::Block(inner) => inner.visit_function_calls(visitor),
+            Self::Expression(inner) => inner.visit_function_calls(visitor),
+            Self::FunctionDefinition(inner) => inner.body.visit_function_calls(visitor),
+            Self::VariableDeclaration(inner) => {
+                if let Some(expression) = inner.expression.as_ref() {
+                    expression.visit_function_calls(visitor);
+                }
+            }
+            Self::Assignment(inner) => inner.initializer.visit_function_calls(visitor),
+            Self::IfConditional(inner) => {
+                inner.condition.visit_function_calls(visitor);
+                inner.block.visit_function_calls(visitor);
+            }
+            Self::Switch(inner) => {
+                inner.expression.visit_function_calls(visitor);
+                for case in inner.cases.iter() {
+                    case.block.visit_function_calls(visitor);
+                }
+                if let Some(block) = inner.default.as_ref() {
+                    block.visit_function_calls(visitor);
+                }
+            }
+            Self::ForLoop(inner) => {
+                inner.initializer.visit_function_calls(visitor);
+                inner.condition.visit_function_calls(visitor);
+                inner.finalizer.visit_function_calls(visitor);
+                inner.body.visit_function_calls(visitor);
+            }
+            Self::Continue(_) | Self::Break(_) | Self::Leave(_) => {}
+        }
+    }
+
+    ///
+    /// Calls the visitor for each function call in the statement, including the nested ones.
+    ///
+    pub fn visit_function_calls_mut<F>(&mut self, visitor: &mut F)
+    where
+        F: FnMut(&mut FunctionCall),
+    {
+        match self {
+            Self::Object(inner) => inner.visit_function_calls_mut(visitor),
+            Self::Code(inner) => inner.block.visit_function_calls_mut(visitor),
+            Self::Block(inner) => inner.visit_function_calls_mut(visitor),
+            Self::Expression(inner) => inner.visit_function_calls_mut(visitor),
+            Self::FunctionDefinition(inner) => inner.body.visit_function_calls_mut(visitor),
+            Self::VariableDeclaration(inner) => {
+                if let Some(expression) = inner.expression.as_mut() {
+                    expression.visit_function_calls_mut(visitor);
+                }
+            }
+            Self::Assignment(inner) => inner.initializer.visit_function_calls_mut(visitor),
+            Self::IfConditional(inner) => {
+                inner.condition.visit_function_calls_mut(visitor);
+                inner.block.visit_function_calls_mut(visitor);
+            }
+            Self::Switch(inner) => {
+                inner.expression.visit_function_calls_mut(visitor);
+                for case in inner.cases.iter_mut() {
+                    case.block.visit_function_calls_mut(visitor);
+                }
+                if let Some(block) = inner.default.as_mut() {
+                    block.visit_function_calls_mut(visitor);
+                }
+            }
+            Self::ForLoop(inner) => {
+                inner.initializer.visit_function_calls_mut(visitor);
+                inner.condition.visit_function_calls_mut(visitor);
+                inner.finalizer.visit_function_calls_mut(visitor);
+                inner.body.visit_function_calls_mut(visitor);
+            }
+            Self::Continue(_) | Self::Break(_) | Self::Leave(_) => {}
+        }
+    }
+
+    ///
+    /// Whether the statement always ends the execution with a terminating instruction.
+    ///
+    /// The `terminating_functions` are the user-defined functions known to always terminate.
+    ///
+    pub fn is_terminating(&self, terminating_functions: &BTreeSet<String>) -> bool {
+        match self {
+            Self::Expression(Expression::FunctionCall(call)) => match call.name {
+                Name::Return | Name::Revert | Name::Stop | Name::Invalid | Name::SelfDestruct => {
+                    true
+                }
+                Name::UserDefined(ref name) => terminating_functions.contains(name),
+                _ => false,
+            },
+            Self::Block(inner) => inner.is_terminating(terminating_functions),
+            Self::Switch(inner) => match inner.default.as_ref() {
+                Some(default) => {
+                    default.is_terminating(terminating_functions)
+                        && inner
+                            .cases
+                            .iter()
+                            .all(|case| case.block.is_terminating(terminating_functions))
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for Statement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Object(inner) => write!(f, "{}", inner),
+            Self::Code(inner) => write!(f, "{}", inner),
+            Self::Block(inner) => write!(f, "{}", inner),
+            Self::Expression(inner) => write!(f, "{}", inner),
+            Self::FunctionDefinition(inner) => write!(f, "{}", inner),
+            Self::VariableDeclaration(inner) => write!(f, "{}", inner),
+            Self::Assignment(inner) => write!(f, "{}", inner),
+            Self::IfConditional(inner) => write!(f, "{}", inner),
+            Self::Switch(inner) => write!(f, "{}", inner),
+            Self::ForLoop(inner) => write!(f, "{}", inner),
+            Self::Continue(_) => write!(f, "continue"),
+            Self::Break(_) => write!(f, "break"),
+            Self::Leave(_) => write!(f, "leave"),
+        }
+    }
 }
@@ -2,8 +2,12 @@
 //! The YUL object.
 //!
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashSet;
 
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::literal::Literal;
@@ -13,25 +17,45 @@ use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal as ExpressionLiteral;
+use crate::yul::parser::statement::expression::Expression;
 
 ///
 /// The upper-level YUL object, representing the deploy code.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Object {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The identifier.
+    #[serde(rename = "identifier")]
     pub identifier: String,
     /// The code.
+    #[serde(rename = "code")]
     pub code: Code,
     /// The optional inner object, representing the runtime code.
+    #[serde(rename = "innerObject")]
     pub inner_object: Option<Box<Self>>,
     /// The factory dependency objects, which are represented by nested Yul object. The nested
     /// objects are duplicates of the upper-level objects describing the dependencies, so only
     /// their identifiers are preserved. The identifiers are used to address upper-level objects.
+    #[serde(
+        rename = "factoryDependencies",
+        serialize_with = "Object::serialize_factory_dependencies"
+    )]
     pub factory_dependencies: HashSet<String>,
+    /// The decoded data entries, e.g. the metadata, keyed by their names.
+    #[serde(rename = "data", serialize_with = "Object::serialize_data")]
+    pub data: BTreeMap<String, Vec<u8>>,
+    /// The location right after the closing bracket.
+    #[serde(rename = "end")]
+    pub end: Location,
 }
 
 impl Object {
@@ -50,6 +74,7 @@ impl Object {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["object"],
                     found: token.lexeme.to_string(),
                 }
@@ -65,6 +90,7 @@ impl Object {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["{string}"],
                     found: token.lexeme.to_string(),
                 }
@@ -81,6 +107,7 @@ impl Object {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["{"],
                     found: token.lexeme.to_string(),
                 }
@@ -89,6 +116,9 @@ impl Object {
         }
 
         let code = Code::parse(lexer, None)?;
+        if let Some(location) = code.block.leave_outside_function() {
+            return Err(ParserError::LeaveOutsideFunction { location }.into());
+        }
         let mut inner_object = None;
         let mut factory_dependencies = HashSet::new();
 
@@ -104,26 +134,16 @@ impl Object {
                 }
                 _ => None,
             };
-
-            if let Token {
-                lexeme: Lexeme::Identifier(identifier),
-                ..
-            } = lexer.peek()?
-            {
-                if identifier.inner.as_str() == "data" {
-                    let _data = lexer.next()?;
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
-                }
-            };
         }
 
-        loop {
+        let mut data = BTreeMap::new();
+
+        let end = loop {
             match lexer.next()? {
-                Token {
+                token @ Token {
                     lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
                     ..
-                } => break,
+                } => break token.end(),
                 token @ Token {
                     lexeme: Lexeme::Keyword(Keyword::Object),
                     ..
@@ -135,19 +155,20 @@ impl Object {
                     lexeme: Lexeme::Identifier(identifier),
                     ..
                 } if identifier.inner.as_str() == "data" => {
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
+                    let (name, value) = Self::parse_data(lexer)?;
+                    data.insert(name, value);
                 }
                 token => {
                     return Err(ParserError::InvalidToken {
                         location: token.location,
-                        expected: vec!["object", "}"],
+                        length: token.length,
+                        expected: vec!["object", "data", "}"],
                         found: token.lexeme.to_string(),
                     }
                     .into());
                 }
             }
-        }
+        };
 
         Ok(Self {
             location,
@@ -155,8 +176,537 @@ impl Object {
             code,
             inner_object,
             factory_dependencies,
+            data,
+            end,
         })
     }
+
+    ///
+    /// Parses all top-level objects until the end of file.
+    ///
+    pub fn parse_all(lexer: &mut Lexer) -> Result<Vec<Self>, Error> {
+        let mut objects = Vec::new();
+        Self::parse_each(lexer, &mut |object| objects.push(object))?;
+        Ok(objects)
+    }
+
+    ///
+    /// Parses all top-level objects until the end of file, passing each of them to `callback`
+    /// as soon as it is parsed, so the objects do not have to be kept in memory together.
+    ///
+    /// On error, the objects preceding the invalid one have already been passed to `callback`.
+    /// The objects with an identifier already used in the file are rejected, since they cannot
+    /// be addressed unambiguously.
+    ///
+    pub fn parse_each(lexer: &mut Lexer, callback: &mut dyn FnMut(Self)) -> Result<(), Error> {
+        let mut locations = BTreeMap::new();
+        loop {
+            match lexer.peek()? {
+                Token {
+                    lexeme: Lexeme::EndOfFile,
+                    ..
+                } => return Ok(()),
+                _ => {
+                    let object = Self::parse(lexer, None)?;
+                    if let Some(previous) =
+                        locations.insert(object.identifier.clone(), object.location)
+                    {
+                        return Err(ParserError::DuplicateObject {
+                            location: object.location,
+                            identifier: object.identifier,
+                            previous,
+                        }
+                        .into());
+                    }
+                    callback(object);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Returns the JSON representation of the object AST, e.g. for the external tooling.
+    ///
+    /// The field names are fixed by the `serde` attributes and do not follow the Rust ones.
+    /// Every node except the lexical literals has the `location` field, which is
+    /// `{"line": number, "column": number}`. The other fields are:
+    ///
+    /// - object: `identifier`, `code`, `innerObject` (an object or `null`),
+    ///   `factoryDependencies` (sorted identifiers), `data` (names to hexadecimal strings), `end`
+    /// - code: `block`
+    /// - block: `statements`, `end`
+    /// - function definition: `identifier`, `arguments`, `result`, `body`
+    /// - variable declaration: `bindings`, `expression` (or `null`)
+    /// - assignment: `bindings`, `initializer`
+    /// - if conditional: `condition`, `block`
+    /// - switch: `expression`, `cases`, `default` (a block or `null`)
+    /// - switch case: `literal`, `block`
+    /// - for loop: `initializer`, `condition`, `finalizer`, `body`
+    /// - function call: `name` (spelled as in the Yul source), `arguments`, `end`
+    /// - identifier: `inner`, `type` (or `null`)
+    /// - literal: `inner` (the lexical literal), `type` (or `null`), `end`
+    ///
+    /// The `end` field is the location right after the last token of the node.
+    ///
+    /// The statements, expressions, lexical literals, and types are tagged with their kind,
+    /// e.g. `{"FunctionDefinition": {...}}`, `{"Integer": {"Decimal": {"inner": "42"}}}`,
+    /// and `{"String": {"inner": "...", "isHexadecimal": false}}`. The kinds without contents,
+    /// such as `"Break"` or `"Bool"`, are plain strings.
+    ///
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("Always valid")
+    }
+
+    ///
+    /// Serializes the factory dependencies in the sorted order, so the JSON is deterministic.
+    ///
+    fn serialize_factory_dependencies<S>(
+        factory_dependencies: &HashSet<String>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(factory_dependencies.iter().collect::<BTreeSet<&String>>())
+    }
+
+    ///
+    /// Serializes the data entries as hexadecimal strings.
+    ///
+    fn serialize_data<S>(data: &BTreeMap<String, Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(data.iter().map(|(name, value)| (name, hex::encode(value))))
+    }
+
+    ///
+    /// Parses the `data` entry name and value, with the `data` keyword already consumed.
+    ///
+    /// The value is decoded from the `hex"..."` literal, or taken as is from the plain string one.
+    ///
+    fn parse_data(lexer: &mut Lexer) -> Result<(String, Vec<u8>), Error> {
+        let mut strings = Vec::with_capacity(2);
+        for _ in 0..2 {
+            match lexer.next()? {
+                Token {
+                    lexeme: Lexeme::Literal(Literal::String(literal)),
+                    location,
+                    ..
+                } => strings.push((location, literal)),
+                token => {
+                    return Err(ParserError::InvalidToken {
+                        location: token.location,
+                        length: token.length,
+                        expected: vec!["{string}"],
+                        found: token.lexeme.to_string(),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        let (location, value) = strings.pop().expect("Always exists");
+        let (_, name) = strings.pop().expect("Always exists");
+        let name = name.inner;
+        let value = if value.is_hexadecimal {
+            match hex::decode(value.inner.as_str()) {
+                Ok(value) => value,
+                Err(_) => return Err(ParserError::InvalidHexData { location, name }.into()),
+            }
+        } else {
+            value.inner.into_bytes()
+        };
+        Ok((name, value))
+    }
+
+    ///
+    /// Returns the main block of the deploy code.
+    ///
+    /// If the object itself is the runtime one, its own block is returned, as there is no
+    /// deploy code to resolve.
+    ///
+    pub fn deploy_block(&self) -> &Block {
+        &self.code.block
+    }
+
+    ///
+    /// Returns the main block of the runtime code, resolving the `_deployed` inner object.
+    ///
+    /// Returns `None` if the object has no runtime code.
+    ///
+    pub fn runtime_block(&self) -> Option<&Block> {
+        if self.is_runtime_code() {
+            return Some(&self.code.block);
+        }
+
+        self.inner_object
+            .as_ref()
+            .filter(|object| object.is_runtime_code())
+            .map(|object| &object.code.block)
+    }
+
+    ///
+    /// Calls the visitor for each function call in the object code, including the inner object.
+    ///
+    pub fn visit_function_calls<F>(&self, visitor: &mut F)
+    where
+        F: FnMut(&FunctionCall),
+    {
+        self.code.block.visit_function_calls(visitor);
+        if let Some(object) = self.inner_object.as_ref() {
+            object.visit_function_calls(visitor);
+        }
+    }
+
+    ///
+    /// Calls the visitor for each function call in the object code, including the inner object.
+    ///
+    pub fn visit_function_calls_mut<F>(&mut self, visitor: &mut F)
+    where
+        F: FnMut(&mut FunctionCall),
+    {
+        self.code.block.visit_function_calls_mut(visitor);
+        if let Some(object) = self.inner_object.as_mut() {
+            object.visit_function_calls_mut(visitor);
+        }
+    }
+
+    ///
+    /// Returns the locations of the functions defined in the object and its inner object.
+    ///
+    pub fn function_locations(&self) -> BTreeMap<String, Location> {
+        let mut locations = BTreeMap::new();
+        self.code.block.function_locations(&mut locations);
+        if let Some(object) = self.inner_object.as_ref() {
+            locations.extend(object.function_locations());
+        }
+        locations
+    }
+
+    ///
+    /// Returns the calls to the user-defined functions which are not defined in the object code,
+    /// including the inner object, whose functions are not visible from the outer one.
+    ///
+    pub fn undefined_function_calls(&self) -> Vec<(String, Location)> {
+        let mut undefined = Vec::new();
+        self.code
+            .block
+            .undefined_function_calls(&BTreeSet::new(), &mut undefined);
+        if let Some(object) = self.inner_object.as_ref() {
+            undefined.extend(object.undefined_function_calls());
+        }
+        undefined
+    }
+
+    ///
+    /// Returns the errors for the calls to the undeclared functions and to the builtins
+    /// unsupported on zkEVM, ordered by their locations.
+    ///
+    pub fn unknown_builtins(&self) -> Vec<Error> {
+        let mut calls: Vec<(Location, String, bool)> = self
+            .undefined_function_calls()
+            .into_iter()
+            .map(|(name, location)| {
+                let is_unsupported = Name::UNSUPPORTED.contains(&name.as_str());
+                (location, name, is_unsupported)
+            })
+            .collect();
+        self.visit_function_calls(&mut |call| {
+            if call.name.is_unsupported() {
+                calls.push((call.location, call.name.to_string(), true));
+            }
+        });
+        calls.sort_by_key(|(location, _, _)| (location.line, location.column));
+
+        calls
+            .into_iter()
+            .map(|(location, name, is_unsupported)| Error::UnknownBuiltin {
+                location,
+                name,
+                is_unsupported,
+            })
+            .collect()
+    }
+
+    ///
+    /// Whether every path of the runtime code ends with a terminating instruction, that is,
+    /// `return`, `revert`, `stop`, `invalid`, `selfdestruct`, or a call to a function which
+    /// always terminates.
+    ///
+    /// The paths falling off the end of the runtime code usually indicate a bug in the source.
+    ///
+    pub fn is_runtime_code_terminating(&self) -> bool {
+        let block = match self.runtime_block() {
+            Some(block) => block,
+            None => return true,
+        };
+
+        let mut definitions = Vec::new();
+        block.function_definitions(&mut definitions);
+
+        let mut terminating_functions = BTreeSet::new();
+        loop {
+            let mut is_changed = false;
+            for definition in definitions.iter() {
+                if !terminating_functions.contains(&definition.identifier)
+                    && definition.body.is_terminating(&terminating_functions)
+                {
+                    terminating_functions.insert(definition.identifier.clone());
+                    is_changed = true;
+                }
+            }
+            if !is_changed {
+                break;
+            }
+        }
+
+        block.is_terminating(&terminating_functions)
+    }
+
+    ///
+    /// Whether the object represents the runtime code.
+    ///
+    pub fn is_runtime_code(&self) -> bool {
+        self.identifier.ends_with("_deployed")
+    }
+
+    ///
+    /// Returns the `for` loop variables of the deploy and runtime code which shadow a variable
+    /// of an enclosing scope.
+    ///
+    pub fn shadowed_loop_variables(&self) -> Vec<Identifier> {
+        let mut shadowed = Vec::new();
+        self.code
+            .block
+            .shadowed_loop_variables(&BTreeSet::new(), &mut shadowed);
+        if let Some(object) = self.inner_object.as_ref() {
+            shadowed.extend(object.shadowed_loop_variables());
+        }
+        shadowed
+    }
+
+    ///
+    /// Returns the return variables of the deploy and runtime code functions which are never
+    /// assigned, along with the function names.
+    ///
+    pub fn unassigned_function_results(&self) -> Vec<(String, Identifier)> {
+        let mut definitions = Vec::new();
+        self.code.block.function_definitions(&mut definitions);
+        let mut results: Vec<(String, Identifier)> = definitions
+            .into_iter()
+            .flat_map(|definition| {
+                definition
+                    .unassigned_results()
+                    .into_iter()
+                    .map(|result| (definition.identifier.to_owned(), result.to_owned()))
+            })
+            .collect();
+        if let Some(object) = self.inner_object.as_ref() {
+            results.extend(object.unassigned_function_results());
+        }
+        results
+    }
+
+    ///
+    /// Whether the deploy and runtime code look swapped.
+    ///
+    /// The deploy code copies the runtime code by referencing the runtime object with
+    /// `dataoffset` or `datasize`, which the runtime code itself never needs to do. So the pair
+    /// is considered swapped if the runtime code references its own object, while the deploy
+    /// code does not.
+    ///
+    pub fn is_likely_swapped(&self) -> bool {
+        let runtime = match self.inner_object.as_ref() {
+            Some(runtime) => runtime,
+            None => return false,
+        };
+
+        let deploy_references = Self::data_references(&self.code.block);
+        let runtime_references = Self::data_references(&runtime.code.block);
+        runtime_references.contains(&runtime.identifier)
+            && !deploy_references.contains(&runtime.identifier)
+    }
+
+    ///
+    /// Returns the locations of the `stop` calls in the deploy code.
+    ///
+    /// The deploy code must return the runtime code, so stopping it deploys an empty contract,
+    /// which is almost always a bug.
+    ///
+    pub fn deploy_code_stops(&self) -> Vec<Location> {
+        let mut locations = Vec::new();
+        if self.is_runtime_code() {
+            return locations;
+        }
+
+        self.code.block.visit_function_calls(&mut |call| {
+            if call.name == Name::Stop {
+                locations.push(call.location);
+            }
+        });
+        locations
+    }
+
+    ///
+    /// Returns the constant input sizes of the `create` and `create2` calls in the object and its
+    /// inner object, which exceed the warning threshold.
+    ///
+    /// Such sizes are most likely a bug, and the input would not fit into the heap anyway.
+    ///
+    pub fn large_create_input_sizes(&self) -> Vec<(num::BigUint, Location)> {
+        let threshold = num::BigUint::from(crate::r#const::CREATE_INPUT_SIZE_WARNING_THRESHOLD);
+        let mut sizes = Vec::new();
+        self.visit_function_calls(&mut |call| {
+            if !matches!(call.name, Name::Create | Name::Create2) {
+                return;
+            }
+            if let Some(Expression::Literal(literal)) = call.arguments.get(2) {
+                if let Some(size) = literal.integer().filter(|size| size > &threshold) {
+                    sizes.push((size, call.location));
+                }
+            }
+        });
+        sizes
+    }
+
+    ///
+    /// Returns the locations of the `keccak256` calls in the object and its inner object, whose
+    /// constant memory range overlaps the memory pointer or the empty slot.
+    ///
+    /// Only the scratch space below the memory pointer is meant to be hashed in place, so hashing
+    /// the reserved slots is most likely a bug.
+    ///
+    pub fn reserved_memory_hashes(&self) -> Vec<Location> {
+        let reserved_start = num::BigUint::from(crate::r#const::OFFSET_MEMORY_POINTER);
+        let reserved_end = num::BigUint::from(crate::r#const::OFFSET_NON_RESERVED);
+        let mut locations = Vec::new();
+        self.visit_function_calls(&mut |call| {
+            if call.name != Name::Keccak256 {
+                return;
+            }
+            if let (Some(Expression::Literal(offset)), Some(Expression::Literal(size))) =
+                (call.arguments.first(), call.arguments.get(1))
+            {
+                if let (Some(offset), Some(size)) = (offset.integer(), size.integer()) {
+                    if size > num::BigUint::from(0u32)
+                        && offset < reserved_end
+                        && offset + size > reserved_start
+                    {
+                        locations.push(call.location);
+                    }
+                }
+            }
+        });
+        locations
+    }
+
+    ///
+    /// Returns the pairs of the `mstore8` and `mstore` calls in the object and its inner object,
+    /// whose constant offsets make the stored byte overlap the stored word.
+    ///
+    /// The deploy and runtime code have separate memory, so their stores are never paired.
+    ///
+    pub fn overlapping_byte_and_word_stores(&self) -> Vec<(Location, Location)> {
+        let mut byte_stores = Vec::new();
+        let mut word_stores = Vec::new();
+        self.code.block.visit_function_calls(&mut |call| {
+            let stores = match call.name {
+                Name::MStore8 => &mut byte_stores,
+                Name::MStore => &mut word_stores,
+                _ => return,
+            };
+            if let Some(Expression::Literal(literal)) = call.arguments.first() {
+                if let Some(offset) = literal.integer() {
+                    stores.push((offset, call.location));
+                }
+            }
+        });
+
+        let word_size = num::BigUint::from(compiler_common::SIZE_FIELD);
+        let mut overlapping = Vec::new();
+        for (byte_offset, byte_location) in byte_stores.iter() {
+            for (word_offset, word_location) in word_stores.iter() {
+                if word_offset <= byte_offset && byte_offset < &(word_offset + &word_size) {
+                    overlapping.push((*byte_location, *word_location));
+                }
+            }
+        }
+        if let Some(object) = self.inner_object.as_ref() {
+            overlapping.extend(object.overlapping_byte_and_word_stores());
+        }
+        overlapping
+    }
+
+    ///
+    /// Returns the fully qualified paths of the libraries referenced by `linkersymbol` in the
+    /// object and its inner objects.
+    ///
+    pub fn library_references(&self) -> BTreeSet<String> {
+        let mut references = BTreeSet::new();
+        self.visit_function_calls(&mut |call| {
+            if call.name != Name::LinkerSymbol {
+                return;
+            }
+            if let Some(Expression::Literal(ExpressionLiteral {
+                inner: Literal::String(path),
+                ..
+            })) = call.arguments.first()
+            {
+                references.insert(path.inner.clone());
+            }
+        });
+        references
+    }
+
+    ///
+    /// Emits the object as single-line Yul source, which can be parsed back.
+    ///
+    /// The factory dependency objects are not preserved by the parser, so the objects having
+    /// them cannot be emitted.
+    ///
+    pub fn to_source(&self) -> anyhow::Result<String> {
+        if !self.factory_dependencies.is_empty() {
+            let mut factory_dependencies: Vec<&String> = self.factory_dependencies.iter().collect();
+            factory_dependencies.sort();
+            anyhow::bail!(
+                "Yul object `{}` source cannot be emitted, as its factory dependencies {:?} are not preserved",
+                self.identifier,
+                factory_dependencies,
+            );
+        }
+
+        let mut source = format!("object \"{}\" {{ {}", self.identifier, self.code);
+        if let Some(object) = self.inner_object.as_ref() {
+            source.push(' ');
+            source.push_str(object.to_source()?.as_str());
+        }
+        for (name, value) in self.data.iter() {
+            source.push_str(format!(" data \"{}\" hex\"{}\"", name, hex::encode(value)).as_str());
+        }
+        source.push_str(" }");
+        Ok(source)
+    }
+
+    ///
+    /// Returns the object identifiers referenced by `dataoffset` and `datasize` in the block.
+    ///
+    fn data_references(block: &Block) -> BTreeSet<String> {
+        let mut references = BTreeSet::new();
+        block.visit_function_calls(&mut |call| {
+            if !matches!(call.name, Name::DataOffset | Name::DataSize) {
+                return;
+            }
+            if let Some(Expression::Literal(ExpressionLiteral {
+                inner: Literal::String(identifier),
+                ..
+            })) = call.arguments.first()
+            {
+                references.insert(identifier.inner.clone());
+            }
+        });
+        references
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Object
@@ -196,7 +746,7 @@ where
     }
 
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        if self.identifier.ends_with("_deployed") {
+        if self.is_runtime_code() {
             compiler_llvm_context::RuntimeCodeFunction::new(self.code).into_llvm(context)?;
         } else {
             compiler_llvm_context::DeployCodeFunction::new(self.code).into_llvm(context)?;
@@ -212,10 +762,13 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::yul::error::Error as YulError;
     use crate::yul::lexer::token::location::Location;
     use crate::yul::lexer::Lexer;
     use crate::yul::parser::error::Error;
+    use crate::yul::parser::statement::expression::function_call::name::Name;
     use crate::yul::parser::statement::object::Object;
+    use crate::yul::parser::statement::Statement;
 
     #[test]
     fn error_invalid_token_object() {
@@ -242,6 +795,7 @@ class "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(2, 1),
+                length: 5,
                 expected: vec!["object"],
                 found: "class".to_owned(),
             }
@@ -274,6 +828,7 @@ object 256 {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(2, 8),
+                length: 3,
                 expected: vec!["{string}"],
                 found: "256".to_owned(),
             }
@@ -306,6 +861,7 @@ object "Test" (
             result,
             Err(Error::InvalidToken {
                 location: Location::new(2, 15),
+                length: 1,
                 expected: vec!["{"],
                 found: "(".to_owned(),
             }
@@ -338,10 +894,574 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(8, 5),
-                expected: vec!["object", "}"],
+                length: 5,
+                expected: vec!["object", "data", "}"],
                 found: "class".to_owned(),
             }
             .into())
         );
     }
+
+    #[test]
+    fn ok_deploy_and_runtime_blocks() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            mstore(64, 128)
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Must be valid");
+
+        assert_eq!(object.deploy_block().location, Location::new(3, 10));
+        assert_eq!(object.deploy_block().statements.len(), 1);
+
+        let runtime_block = object.runtime_block().expect("Must exist");
+        assert_eq!(runtime_block.location, Location::new(9, 14));
+        assert_eq!(runtime_block.statements.len(), 1);
+    }
+
+    #[test]
+    fn error_invalid_token_span() {
+        let input = r#"
+class_definition "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::InvalidToken {
+                location: Location::new(2, 1),
+                length: "class_definition".len(),
+                expected: vec!["object"],
+                found: "class_definition".to_owned(),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn error_leave_outside_function() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                if calldatasize() {
+                    leave
+                }
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::LeaveOutsideFunction {
+                location: Location::new(12, 21),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn ok_multiple_data_entries() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+        data ".metadata" hex"a2646970667358"
+        data "table" "lookup"
+        data "empty" hex""
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Always valid");
+        let data = &object.inner_object.expect("Always exists").data;
+        assert_eq!(data.len(), 3);
+        assert_eq!(
+            data.get(".metadata").map(Vec::as_slice),
+            Some([0xa2, 0x64, 0x69, 0x70, 0x66, 0x73, 0x58].as_slice())
+        );
+        assert_eq!(
+            data.get("table").map(Vec::as_slice),
+            Some(b"lookup".as_slice())
+        );
+        assert_eq!(data.get("empty").map(Vec::as_slice), Some([].as_slice()));
+    }
+
+    #[test]
+    fn error_invalid_hex_data() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+        data ".metadata" hex"a2646"
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::InvalidHexData {
+                location: Location::new(14, 26),
+                name: ".metadata".to_owned(),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn ok_parse_all() {
+        let input = r#"
+object "First" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "First_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+
+object "Second" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Second_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let objects = Object::parse_all(&mut lexer).expect("Always valid");
+        let identifiers: Vec<&str> = objects
+            .iter()
+            .map(|object| object.identifier.as_str())
+            .collect();
+        assert_eq!(identifiers, vec!["First", "Second"]);
+    }
+
+    #[test]
+    fn ok_parse_each() {
+        let input = r#"
+object "First" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+}
+
+object "Second" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+}
+
+object "Third" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let mut identifiers = Vec::new();
+        Object::parse_each(&mut lexer, &mut |object| {
+            identifiers.push(object.identifier)
+        })
+        .expect("Always valid");
+        assert_eq!(identifiers, vec!["First", "Second", "Third"]);
+    }
+
+    #[test]
+    fn error_parse_each_after_valid_object() {
+        let input = r#"
+object "First" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+}
+
+object "Second" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    invalid
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let mut identifiers = Vec::new();
+        let result = Object::parse_each(&mut lexer, &mut |object| {
+            identifiers.push(object.identifier)
+        });
+        assert!(result.is_err());
+        assert_eq!(identifiers, vec!["First"]);
+    }
+
+    #[test]
+    fn error_duplicate_object() {
+        let input = r#"
+object "A" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+}
+
+object "A" {
+    code {
+        {
+            revert(0, 0)
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse_all(&mut lexer);
+        assert_eq!(
+            result,
+            Err(Error::DuplicateObject {
+                location: Location::new(10, 1),
+                identifier: "A".to_owned(),
+                previous: Location::new(2, 1),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn ok_to_json() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let x := double(calldataload(0))
+                if gt(x, 0x10) {
+                    x := 16
+                }
+                mstore(0, x)
+                return(0, 32)
+            }
+
+            function double(a) -> b {
+                b := add(a, a)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Must be valid");
+        let json = object.to_json();
+
+        assert_eq!(json["identifier"], "Test");
+        assert_eq!(
+            json["location"],
+            serde_json::json!({ "line": 2, "column": 1 })
+        );
+
+        let runtime_statements = &json["innerObject"]["code"]["block"]["statements"];
+        let function = &runtime_statements[1]["FunctionDefinition"];
+        assert_eq!(function["identifier"], "double");
+        assert_eq!(
+            function["location"],
+            serde_json::json!({ "line": 19, "column": 22 })
+        );
+        assert_eq!(function["arguments"][0]["inner"], "a");
+        assert_eq!(function["result"][0]["inner"], "b");
+
+        let declaration = &runtime_statements[0]["Block"]["statements"][0]["VariableDeclaration"];
+        let call = &declaration["expression"]["FunctionCall"];
+        assert_eq!(call["name"], "double");
+        assert_eq!(
+            call["location"],
+            serde_json::json!({ "line": 11, "column": 26 })
+        );
+        assert_eq!(call["arguments"][0]["FunctionCall"]["name"], "calldataload");
+        assert_eq!(
+            call["arguments"][0]["FunctionCall"]["arguments"][0]["Literal"]["inner"],
+            serde_json::json!({ "Integer": { "Decimal": { "inner": "0" } } })
+        );
+        assert_eq!(json["factoryDependencies"], serde_json::json!([]));
+        assert_eq!(json["innerObject"]["innerObject"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn ok_display_round_trip() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let x := add_one(calldataload(0))
+                mstore(0, x)
+                return(0, 32)
+            }
+
+            function add_one(a) -> b {
+                b := add(a, 1)
+            }
+        }
+    }
+    data "Metadata" "abcd"
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let mut object = Object::parse(&mut lexer, None).expect("Must be valid");
+
+        let runtime_code = object.inner_object.as_mut().expect("Must exist");
+        for statement in runtime_code.code.block.statements.iter_mut() {
+            if let Statement::FunctionDefinition(function) = statement {
+                if function.identifier == "add_one" {
+                    function.identifier = "increment".to_owned();
+                }
+            }
+        }
+        object.visit_function_calls_mut(&mut |call| {
+            if call.name == Name::UserDefined("add_one".to_owned()) {
+                call.name = Name::UserDefined("increment".to_owned());
+            }
+        });
+
+        let output = object.to_source().expect("Always valid");
+        let mut lexer = Lexer::new(output.clone());
+        let reparsed = Object::parse(&mut lexer, None).expect("Must be valid");
+
+        assert!(!output.contains("add_one"));
+        assert!(reparsed.function_locations().contains_key("increment"));
+        assert!(!reparsed.function_locations().contains_key("add_one"));
+        assert_eq!(reparsed.data, object.data);
+        assert_eq!(reparsed.to_source().expect("Always valid"), output);
+    }
+
+    #[test]
+    fn error_display_factory_dependency() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let size := datasize("Dependency")
+                datacopy(0, dataoffset("Dependency"), size)
+                pop(create(0, 0, size))
+                return(0, 0)
+            }
+        }
+        object "Dependency" {
+            code {
+                {
+                    return(0, 0)
+                }
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Must be valid");
+
+        let error = object.to_source().expect_err("Must be an error");
+        assert!(error
+            .to_string()
+            .contains(r#"factory dependencies ["Dependency"]"#));
+    }
+
+    #[test]
+    fn error_undefined_function_calls() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                first_missing()
+                mstore(0, defined(second_missing(1)))
+                return(0, 32)
+            }
+
+            function defined(a) -> b {
+                b := a
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Must be valid");
+
+        assert_eq!(
+            object.undefined_function_calls(),
+            vec![
+                ("first_missing".to_owned(), Location::new(12, 17)),
+                ("second_missing".to_owned(), Location::new(13, 35)),
+            ]
+        );
+    }
+
+    #[test]
+    fn error_unknown_builtins() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                mstroe(0, 1)
+                pop(pc())
+                tstore(0, tload(1))
+                return(0, 32)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Must be valid");
+
+        let errors = object.unknown_builtins();
+        assert_eq!(
+            errors,
+            vec![
+                YulError::UnknownBuiltin {
+                    location: Location::new(12, 17),
+                    name: "mstroe".to_owned(),
+                    is_unsupported: false,
+                },
+                YulError::UnknownBuiltin {
+                    location: Location::new(13, 21),
+                    name: "pc".to_owned(),
+                    is_unsupported: true,
+                },
+                YulError::UnknownBuiltin {
+                    location: Location::new(14, 17),
+                    name: "tstore".to_owned(),
+                    is_unsupported: true,
+                },
+                YulError::UnknownBuiltin {
+                    location: Location::new(14, 27),
+                    name: "tload".to_owned(),
+                    is_unsupported: true,
+                },
+            ]
+        );
+        assert_eq!(errors[0].to_string(), "12:17 Undeclared function `mstroe`");
+        assert_eq!(
+            errors[1].to_string(),
+            "13:21 The `PC` instruction is not supported"
+        );
+        assert_eq!(
+            errors[2].to_string(),
+            "14:17 The `TSTORE` instruction is not supported"
+        );
+
+        let error = YulError::UnknownBuiltins(errors);
+        assert_eq!(
+            error.to_string(),
+            "12:17 Undeclared function `mstroe`\n13:21 The `PC` instruction is not supported\n14:17 The `TSTORE` instruction is not supported\n14:27 The `TLOAD` instruction is not supported"
+        );
+    }
 }
@@ -2,6 +2,8 @@
 //! The switch statement case.
 //!
 
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
@@ -14,13 +16,16 @@ use crate::yul::parser::statement::expression::literal::Literal;
 ///
 /// The Yul switch statement case.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Case {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The matched constant.
+    #[serde(rename = "literal")]
     pub literal: Literal,
     /// The case block.
+    #[serde(rename = "block")]
     pub block: Block,
 }
 
@@ -40,6 +45,7 @@ impl Case {
             token => {
                 return Err(ParserError::InvalidToken {
                     location: token.location,
+                    length: token.length,
                     expected: vec!["{literal}"],
                     found: token.lexeme.to_string(),
                 }
@@ -57,6 +63,12 @@ impl Case {
     }
 }
 
+impl std::fmt::Display for Case {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "case {} {}", self.literal, self.block)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::yul::lexer::token::location::Location;
@@ -92,6 +104,7 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(12, 26),
+                length: 1,
                 expected: vec!["{literal}"],
                 found: "x".to_owned(),
             }
@@ -4,6 +4,8 @@
 
 pub mod case;
 
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -19,15 +21,19 @@ use self::case::Case;
 ///
 /// The Yul switch statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Switch {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The expression being matched.
+    #[serde(rename = "expression")]
     pub expression: Expression,
     /// The non-default cases.
+    #[serde(rename = "cases")]
     pub cases: Vec<Case>,
     /// The optional default case, if `cases` do not cover all possible values.
+    #[serde(rename = "default")]
     pub default: Option<Block>,
 }
 
@@ -94,6 +100,7 @@ impl Switch {
         if cases.is_empty() && default.is_none() {
             return Err(ParserError::InvalidToken {
                 location: token.location,
+                length: token.length,
                 expected: vec!["case", "default"],
                 found: token.lexeme.to_string(),
             }
@@ -109,6 +116,19 @@ impl Switch {
     }
 }
 
+impl std::fmt::Display for Switch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "switch {}", self.expression)?;
+        for case in self.cases.iter() {
+            write!(f, " {}", case)?;
+        }
+        if let Some(block) = self.default.as_ref() {
+            write!(f, " default {}", block)?;
+        }
+        Ok(())
+    }
+}
+
 impl<D> compiler_llvm_context::WriteLLVM<D> for Switch
 where
     D: compiler_llvm_context::Dependency,
@@ -201,6 +221,7 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(12, 21),
+                length: 6,
                 expected: vec!["case", "default"],
                 found: "branch".to_owned(),
             }
@@ -4,6 +4,7 @@
 
 use inkwell::types::BasicType;
 use inkwell::values::BasicValue;
+use serde::Serialize;
 
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
@@ -19,13 +20,16 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 /// The Yul variable declaration statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct VariableDeclaration {
     /// The location.
+    #[serde(rename = "location")]
     pub location: Location,
     /// The variable bindings list.
+    #[serde(rename = "bindings")]
     pub bindings: Vec<Identifier>,
     /// The variable initializing expression.
+    #[serde(rename = "expression")]
     pub expression: Option<Expression>,
 }
 
@@ -59,6 +63,11 @@ impl VariableDeclaration {
                 lexeme: Lexeme::Symbol(Symbol::Assignment),
                 ..
             } => {}
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Equals),
+                location,
+                ..
+            } => return Err(ParserError::InvalidAssignmentSymbol { location }.into()),
             token => {
                 return Ok((
                     Self {
@@ -84,6 +93,17 @@ impl VariableDeclaration {
     }
 }
 
+impl std::fmt::Display for VariableDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bindings: Vec<String> = self.bindings.iter().map(ToString::to_string).collect();
+        write!(f, "let {}", bindings.join(", "))?;
+        if let Some(expression) = self.expression.as_ref() {
+            write!(f, " := {}", expression)?;
+        }
+        Ok(())
+    }
+}
+
 impl<D> compiler_llvm_context::WriteLLVM<D> for VariableDeclaration
 where
     D: compiler_llvm_context::Dependency,
@@ -248,4 +268,35 @@ object "Test" {
             .into())
         );
     }
+
+    #[test]
+    fn error_invalid_assignment_symbol() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let x = 1
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::InvalidAssignmentSymbol {
+                location: Location::new(11, 23),
+            }
+            .into())
+        );
+    }
 }
@@ -2,6 +2,8 @@
 //! The YUL source code type.
 //!
 
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -14,15 +16,19 @@ use crate::yul::parser::error::Error as ParserError;
 ///
 /// The type is not currently in use, so all values have the `uint256` type by default.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum Type {
     /// The `bool` type.
+    #[serde(rename = "Bool")]
     Bool,
     /// The `int{N}` type.
+    #[serde(rename = "Int")]
     Int(usize),
     /// The `uint{N}` type.
+    #[serde(rename = "UInt")]
     UInt(usize),
     /// The custom user-defined type.
+    #[serde(rename = "Custom")]
     Custom(String),
 }
 
@@ -58,6 +64,7 @@ impl Type {
             } => Ok(Self::Custom(identifier.inner)),
             token => Err(ParserError::InvalidToken {
                 location: token.location,
+                length: token.length,
                 expected: vec!["{type}"],
                 found: token.lexeme.to_string(),
             }
@@ -83,3 +90,14 @@ impl Type {
         }
     }
 }
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool => write!(f, "bool"),
+            Self::Int(bitlength) => write!(f, "int{}", bitlength),
+            Self::UInt(bitlength) => write!(f, "uint{}", bitlength),
+            Self::Custom(inner) => write!(f, "{}", inner),
+        }
+    }
+}
@@ -50,10 +50,21 @@ pub struct Arguments {
     #[structopt(long = "overwrite")]
     pub overwrite: bool,
 
+    /// Mirror the source directory structure in the output directory,
+    /// e.g. `src/Token.sol:Token` is written to `<dir>/src/Token.sol/Token.*`
+    /// (used together with -o).
+    #[structopt(long = "mirror-source-paths")]
+    pub mirror_source_paths: bool,
+
     /// Enable the LLVM bytecode optimizer.
     #[structopt(long = "optimize")]
     pub optimize: bool,
 
+    /// Set the expected number of contract runs for the optimizer (used together with
+    /// --optimize). A number lower than 200 makes the optimizer favor the bytecode size.
+    #[structopt(long = "optimize-runs")]
+    pub optimize_runs: Option<usize>,
+
     /// Sets the LLVM optimizer options.
     #[structopt(long = "llvm-opt")]
     pub llvm_options: Option<String>,
@@ -72,18 +83,100 @@ pub struct Arguments {
     /// Output a single json document containing the specified information.
     /// Available arguments: abi, hashes
     /// Example: solc --combined-json abi,hashes
+    /// Takes precedence over --abi and --hashes, which are added to the
+    /// requested information instead of being printed separately.
     #[structopt(long = "combined-json")]
     pub combined_json: Option<String>,
 
+    /// Downgrade the `solc` errors with the given error code to warnings.
+    /// Can be used multiple times. Use with care, as the compilation
+    /// proceeds even if `solc` has produced an incomplete output.
+    #[structopt(long = "remap-solc-errors")]
+    pub remap_solc_errors: Vec<String>,
+
+    /// Suppress the `solc` warnings located in files outside of the project
+    /// root, which is the base path if specified, or the current directory.
+    #[structopt(long = "disable-warnings-for-dependencies")]
+    pub disable_warnings_for_dependencies: bool,
+
+    /// Print the errors and warnings to stderr as `solc` standard JSON error
+    /// objects, one per line, instead of the formatted text.
+    #[structopt(long = "json-errors")]
+    pub json_errors: bool,
+
+    /// Suppress the informational messages printed to stderr, such as the
+    /// successful compilation notice. The errors and warnings are still printed.
+    #[structopt(long = "quiet")]
+    pub quiet: bool,
+
+    /// Compile the project without producing any output, e.g. in a pre-commit hook.
+    /// Any compilation error makes the compiler exit with a non-zero code.
+    #[structopt(long = "check")]
+    pub check: bool,
+
     /// Switch to Standard JSON input / output mode.
     /// Reads from stdin, result is written to stdout.
     #[structopt(long = "standard-json")]
     pub standard_json: bool,
 
+    /// Pass the `solc` AST of the source files through to the standard JSON output.
+    /// Only valid with `--standard-json` or `--no-solc`, as the AST is large and rarely needed.
+    #[structopt(long = "output-ast")]
+    pub output_ast: bool,
+
+    /// Switch to the no-solc mode, where the `solc` standard JSON output
+    /// is read from stdin and compiled without spawning `solc`.
+    /// The result is written to stdout in the standard JSON format.
+    #[structopt(long = "no-solc")]
+    pub no_solc: bool,
+
     /// Switch to Yul mode.
     #[structopt(long = "yul")]
     pub yul: bool,
 
+    /// List the top-level object identifiers of the Yul file without
+    /// compiling it. Only valid in Yul mode.
+    #[structopt(long = "list-objects")]
+    pub list_objects: bool,
+
+    /// Output the AST of the Yul file in the `solc` compact JSON format
+    /// without compiling it. Only valid in Yul mode.
+    #[structopt(long = "ast-compact-json")]
+    pub ast_compact_json: bool,
+
+    /// Compile only the specified contract and its dependencies. The contract
+    /// is specified by its fully qualified name, e.g. `file.sol:Contract`, or
+    /// by its Yul object identifier.
+    #[structopt(long = "contract")]
+    pub contract: Option<String>,
+
+    /// Override the LLVM data layout of the contract modules, e.g. to experiment
+    /// with the type alignments. The target data layout is used by default.
+    #[structopt(long = "data-layout")]
+    pub data_layout: Option<String>,
+
+    /// Lower the unknown `verbatim` simulations to a revert instead of
+    /// emitting a compile-time error. Only relevant for the Yul pipeline.
+    #[structopt(long = "allow-unknown-verbatim")]
+    pub allow_unknown_verbatim: bool,
+
+    /// Compile the libraries missing in `--libraries` with placeholder
+    /// addresses, which are reported as link references to be linked later.
+    /// By default, such libraries cause a compile-time error.
+    #[structopt(long = "allow-unlinked-libraries")]
+    pub allow_unlinked_libraries: bool,
+
+    /// Abort the compilation on the first Yul object error. By default, the
+    /// errors of all objects are reported and the artifacts of the successfully
+    /// compiled ones are still written. Only valid in Yul mode.
+    #[structopt(long = "halt-on-first-error")]
+    pub halt_on_first_error: bool,
+
+    /// Reject the Yul identifiers longer than the given number of bytes.
+    /// By default, the identifier length is not limited. Only valid in Yul mode.
+    #[structopt(long = "max-identifier-length")]
+    pub max_identifier_length: Option<usize>,
+
     /// Sets the EVM legacy assembly pipeline forcibly.
     #[structopt(long = "force-evmla")]
     pub force_evmla: bool,
@@ -100,10 +193,71 @@ pub struct Arguments {
     #[structopt(long = "asm")]
     pub output_assembly: bool,
 
+    /// Annotate the zkEVM assembly with comments pointing to the Yul
+    /// source locations. Only relevant for the Yul pipeline.
+    #[structopt(long = "annotate-assembly")]
+    pub annotate_assembly: bool,
+
+    /// Keep the unused functions from being eliminated by the optimizer,
+    /// so they can be inspected in the zkEVM assembly.
+    #[structopt(long = "preserve-unused-functions")]
+    pub preserve_unused_functions: bool,
+
+    /// Write the LLVM bitcode of the contract modules before optimization
+    /// to `.bc` files in the output directory (used together with -o).
+    #[structopt(long = "emit-llvm-bc")]
+    pub emit_llvm_bc: bool,
+
+    /// Write the `solc`-format source maps of the deploy and runtime code to the
+    /// standard JSON or combined JSON output. They do not map the zkEVM bytecode,
+    /// so they are written under their own keys: `yulSourceMap` with one entry
+    /// per statement pointing into the Yul source of the contract for the Yul
+    /// pipeline, and `legacyAssemblySourceMap` with one entry per EVM legacy
+    /// assembly instruction for the EVM legacy assembly pipeline.
+    #[structopt(long = "emit-source-map")]
+    pub emit_source_map: bool,
+
     /// Output zkEVM bytecode of the contracts.
     #[structopt(long = "bin")]
     pub output_binary: bool,
 
+    /// Print zkEVM deploy bytecode of the contracts to stdout.
+    /// The zkEVM deploy and runtime code are compiled into a single bytecode,
+    /// so it is the same as the one printed by --bin and --bin-runtime.
+    #[structopt(long = "bin-deploy")]
+    pub output_deploy_binary: bool,
+
+    /// Print zkEVM runtime bytecode of the contracts to stdout.
+    /// The zkEVM deploy and runtime code are compiled into a single bytecode,
+    /// so it is the same as the one printed by --bin and --bin-deploy.
+    #[structopt(long = "bin-runtime")]
+    pub output_runtime_binary: bool,
+
+    /// Compile the contracts and check that the bytecode of the specified contract
+    /// matches the expected one, exiting with an error on mismatch.
+    /// Syntax: <contractPath>=<bytecodeFile>
+    /// The contract path may be shortened to its suffix starting after a `/`, if unambiguous.
+    /// The bytecode file may contain either raw or hexadecimal bytecode.
+    #[structopt(long = "verify")]
+    pub verify: Option<String>,
+
+    /// Fail the compilation if the bytecode of any contract exceeds the given
+    /// number of bytes, listing the contracts that exceed it.
+    #[structopt(long = "max-bytecode-size")]
+    pub max_bytecode_size: Option<usize>,
+
+    /// Fail the compilation if any contract references more than the given
+    /// number of factory dependencies, i.e. the contracts it deploys.
+    #[structopt(long = "max-factory-dependencies")]
+    pub max_factory_dependencies: Option<usize>,
+
+    /// Write the Yul built-in functions and EVM legacy assembly instructions
+    /// lowered in all contracts to the given file, as the `yul` and `evmla`
+    /// arrays of a JSON object.
+    /// Intended for checking the opcode coverage of test suites.
+    #[structopt(long = "coverage-output", parse(from_os_str))]
+    pub coverage_output: Option<PathBuf>,
+
     /// Dump the Yul Intermediate Representation (IR) of all contracts.
     #[structopt(long = "dump-yul")]
     pub dump_yul: bool,
@@ -116,6 +270,14 @@ pub struct Arguments {
     #[structopt(long = "dump-ethir")]
     pub dump_ethir: bool,
 
+    /// Dump the deploy code Ethereal Intermediate Representation (IR) of all contracts.
+    #[structopt(long = "dump-ethir-deploy")]
+    pub dump_ethir_deploy: bool,
+
+    /// Dump the runtime code Ethereal Intermediate Representation (IR) of all contracts.
+    #[structopt(long = "dump-ethir-runtime")]
+    pub dump_ethir_runtime: bool,
+
     /// Dump the LLVM Intermediate Representation (IR) of all contracts.
     #[structopt(long = "dump-llvm")]
     pub dump_llvm: bool,
@@ -123,6 +285,17 @@ pub struct Arguments {
     /// Dump the zkEVM assembly of all contracts.
     #[structopt(long = "dump-assembly")]
     pub dump_assembly: bool,
+
+    /// Report the time spent in the compilation passes of each contract
+    /// to stderr. Also enables the LLVM per-pass timing report.
+    #[structopt(long = "time-passes")]
+    pub time_passes: bool,
+
+    /// Report the LLVM instruction counts by opcode and the basic block
+    /// count of each contract as JSON to stderr. The statistics are
+    /// collected before the LLVM optimizer runs.
+    #[structopt(long = "print-ir-stats")]
+    pub print_ir_stats: bool,
 }
 
 impl Arguments {
@@ -133,6 +306,28 @@ impl Arguments {
         Self::from_args()
     }
 
+    ///
+    /// Returns the `--combined-json` selectors with `abi` and `hashes` added if requested by
+    /// `--abi` and `--hashes`, so the data is queried from `solc` once.
+    ///
+    /// Returns `None` if the combined JSON output is not requested.
+    ///
+    pub fn combined_json_selectors(&self) -> Option<String> {
+        let combined_json = self.combined_json.as_ref()?;
+
+        let mut selectors: Vec<&str> = combined_json
+            .split(',')
+            .map(str::trim)
+            .filter(|selector| !selector.is_empty())
+            .collect();
+        for (requested, selector) in [(self.output_abi, "abi"), (self.output_hashes, "hashes")] {
+            if requested && !selectors.contains(&selector) {
+                selectors.push(selector);
+            }
+        }
+        Some(selectors.join(","))
+    }
+
     ///
     /// Validates the arguments.
     ///
@@ -152,6 +347,186 @@ impl Arguments {
             }
         }
 
+        // The --abi and --hashes data is merged into --combined-json, which is then the only
+        // output printed, so the options do not conflict.
+        if self.combined_json_selectors().as_deref() == Some("") {
+            anyhow::bail!("The option --combined-json requires at least one selector.");
+        }
+
+        if self.emit_source_map
+            && !(self.standard_json || self.no_solc || self.combined_json.is_some())
+        {
+            anyhow::bail!(
+                "The option --emit-source-map is only valid with --standard-json, --combined-json or --no-solc."
+            );
+        }
+
+        if self.emit_llvm_bc && self.output_directory.is_none() {
+            anyhow::bail!("The option --emit-llvm-bc is only valid with --output-dir.");
+        }
+
+        if self.output_directory.is_some() {
+            let mut invalid_options = vec![];
+            if self.output_deploy_binary {
+                invalid_options.push("--bin-deploy");
+            }
+            if self.output_runtime_binary {
+                invalid_options.push("--bin-runtime");
+            }
+            if !invalid_options.is_empty() {
+                anyhow::bail!(
+                    "The following options are invalid with --output-dir, where the single zkEVM bytecode is written by --bin: {}.",
+                    invalid_options.join(", ")
+                );
+            }
+        }
+
+        if self.list_objects && !self.yul {
+            anyhow::bail!("The option --list-objects is only valid in Yul mode.");
+        }
+
+        if self.halt_on_first_error && !self.yul {
+            anyhow::bail!("The option --halt-on-first-error is only valid in Yul mode.");
+        }
+
+        if self.max_identifier_length.is_some() && !self.yul {
+            anyhow::bail!("The option --max-identifier-length is only valid in Yul mode.");
+        }
+
+        if self.ast_compact_json && !self.yul {
+            anyhow::bail!("The option --ast-compact-json is only valid in Yul mode.");
+        }
+
+        if self.output_ast && !(self.standard_json || self.no_solc) {
+            anyhow::bail!(
+                "The option --output-ast is only valid with --standard-json or --no-solc."
+            );
+        }
+
+        if self.standard_json {
+            let mut invalid_options = Vec::new();
+            if self.combined_json.is_some() {
+                invalid_options.push("--combined-json");
+            }
+            if self.output_assembly {
+                invalid_options.push("--asm");
+            }
+            if self.output_binary {
+                invalid_options.push("--bin");
+            }
+            if self.output_deploy_binary {
+                invalid_options.push("--bin-deploy");
+            }
+            if self.output_runtime_binary {
+                invalid_options.push("--bin-runtime");
+            }
+            if self.output_abi {
+                invalid_options.push("--abi");
+            }
+            if self.output_hashes {
+                invalid_options.push("--hashes");
+            }
+            if self.output_directory.is_some() {
+                invalid_options.push("--output-dir");
+            }
+            if self.json_errors {
+                invalid_options.push("--json-errors");
+            }
+            if self.contract.is_some() {
+                invalid_options.push("--contract");
+            }
+            // The optimizer settings are taken from the standard JSON input.
+            if self.optimize {
+                invalid_options.push("--optimize");
+            }
+            if self.optimize_runs.is_some() {
+                invalid_options.push("--optimize-runs");
+            }
+            if !invalid_options.is_empty() {
+                anyhow::bail!(
+                    "The following options are invalid in standard JSON mode: {}.",
+                    invalid_options.join(", ")
+                );
+            }
+        }
+
+        if self.check {
+            let mut invalid_options = Vec::new();
+            if self.standard_json {
+                invalid_options.push("--standard-json");
+            }
+            if self.no_solc {
+                invalid_options.push("--no-solc");
+            }
+            if self.combined_json.is_some() {
+                invalid_options.push("--combined-json");
+            }
+            if self.output_assembly {
+                invalid_options.push("--asm");
+            }
+            if self.output_binary {
+                invalid_options.push("--bin");
+            }
+            if self.output_deploy_binary {
+                invalid_options.push("--bin-deploy");
+            }
+            if self.output_runtime_binary {
+                invalid_options.push("--bin-runtime");
+            }
+            if self.output_abi {
+                invalid_options.push("--abi");
+            }
+            if self.output_hashes {
+                invalid_options.push("--hashes");
+            }
+            if self.output_directory.is_some() {
+                invalid_options.push("--output-dir");
+            }
+            if self.coverage_output.is_some() {
+                invalid_options.push("--coverage-output");
+            }
+            if self.verify.is_some() {
+                invalid_options.push("--verify");
+            }
+            if self.list_objects {
+                invalid_options.push("--list-objects");
+            }
+            if self.ast_compact_json {
+                invalid_options.push("--ast-compact-json");
+            }
+            if !invalid_options.is_empty() {
+                anyhow::bail!(
+                    "The following options are invalid in the check mode: {}.",
+                    invalid_options.join(", ")
+                );
+            }
+        }
+
+        if self.verify.is_some() && self.standard_json {
+            anyhow::bail!(
+                "The following options are invalid in the verification mode: --standard-json."
+            );
+        }
+
+        if self.no_solc {
+            if self.yul {
+                anyhow::bail!("The following options are invalid in the no-solc mode: --yul.");
+            }
+            if self.standard_json {
+                anyhow::bail!(
+                    "The following options are invalid in the no-solc mode: --standard-json."
+                );
+            }
+            if self.combined_json.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in the no-solc mode: --combined-json."
+                );
+            }
+            if !self.input_files.is_empty() {
+                anyhow::bail!("The no-solc mode only accepts the `solc` output from stdin.");
+            }
+        }
+
         Ok(())
     }
 }
@@ -161,3 +536,141 @@ impl Default for Arguments {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use structopt::StructOpt;
+
+    use super::Arguments;
+
+    #[test]
+    fn standard_json_only() {
+        let arguments = Arguments::from_iter(["zksolc", "--standard-json"]);
+        assert!(arguments.validate().is_ok());
+    }
+
+    #[test]
+    fn quiet() {
+        let arguments = Arguments::from_iter(["zksolc", "--quiet", "--json-errors", "test.sol"]);
+        assert!(arguments.validate().is_ok());
+        assert!(arguments.quiet);
+        assert!(arguments.json_errors);
+    }
+
+    #[test]
+    fn error_check_with_output_options() {
+        let arguments = Arguments::from_iter([
+            "zksolc",
+            "--check",
+            "--bin",
+            "--output-dir",
+            "build",
+            "test.sol",
+        ]);
+        let error = arguments.validate().expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            "The following options are invalid in the check mode: --bin, --output-dir."
+        );
+    }
+
+    #[test]
+    fn error_check_with_no_solc() {
+        let arguments = Arguments::from_iter(["zksolc", "--check", "--no-solc"]);
+        let error = arguments.validate().expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            "The following options are invalid in the check mode: --no-solc."
+        );
+    }
+
+    #[test]
+    fn error_output_dir_with_deploy_and_runtime_binary() {
+        let arguments = Arguments::from_iter([
+            "zksolc",
+            "--bin-deploy",
+            "--bin-runtime",
+            "--output-dir",
+            "build",
+            "test.sol",
+        ]);
+        let error = arguments.validate().expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            "The following options are invalid with --output-dir, where the single zkEVM bytecode is written by --bin: --bin-deploy, --bin-runtime."
+        );
+    }
+
+    #[test]
+    fn error_standard_json_with_output_options() {
+        let arguments = Arguments::from_iter([
+            "zksolc",
+            "--standard-json",
+            "--asm",
+            "--combined-json",
+            "abi",
+        ]);
+        let error = arguments.validate().expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            "The following options are invalid in standard JSON mode: --combined-json, --asm."
+        );
+    }
+
+    #[test]
+    fn error_standard_json_with_optimize() {
+        let arguments = Arguments::from_iter([
+            "zksolc",
+            "--standard-json",
+            "--optimize",
+            "--optimize-runs",
+            "1000",
+        ]);
+        let error = arguments.validate().expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            "The following options are invalid in standard JSON mode: --optimize, --optimize-runs."
+        );
+    }
+
+    #[test]
+    fn error_output_ast_without_standard_json() {
+        let arguments = Arguments::from_iter(["zksolc", "--output-ast"]);
+        let error = arguments.validate().expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            "The option --output-ast is only valid with --standard-json or --no-solc."
+        );
+
+        let arguments = Arguments::from_iter(["zksolc", "--no-solc", "--output-ast"]);
+        assert!(arguments.validate().is_ok());
+    }
+
+    #[test]
+    fn combined_json_with_abi_and_hashes() {
+        let arguments =
+            Arguments::from_iter(["zksolc", "--combined-json", "abi", "--abi", "--hashes"]);
+        assert!(arguments.validate().is_ok());
+        assert_eq!(
+            arguments.combined_json_selectors().as_deref(),
+            Some("abi,hashes")
+        );
+    }
+
+    #[test]
+    fn combined_json_selectors_absent() {
+        let arguments = Arguments::from_iter(["zksolc", "--abi", "--hashes"]);
+        assert!(arguments.validate().is_ok());
+        assert_eq!(arguments.combined_json_selectors(), None);
+    }
+
+    #[test]
+    fn error_combined_json_empty() {
+        let arguments = Arguments::from_iter(["zksolc", "--combined-json", ","]);
+        let error = arguments.validate().expect_err("Must be an error");
+        assert_eq!(
+            error.to_string(),
+            "The option --combined-json requires at least one selector."
+        );
+    }
+}
@@ -4,6 +4,11 @@
 
 pub mod arguments;
 
+use std::io::Write;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
 use self::arguments::Arguments;
 
 #[cfg(target_env = "musl")]
@@ -14,25 +19,83 @@ static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 /// The application entry point.
 ///
 fn main() {
-    std::process::exit(match main_inner() {
+    let arguments = Arguments::new();
+    let json_errors = arguments.json_errors;
+
+    let cancellation_token = Arc::new(AtomicBool::new(false));
+    let handler_token = cancellation_token.clone();
+    let result = ctrlc::set_handler(move || handler_token.store(true, Ordering::SeqCst))
+        .map_err(|error| anyhow::anyhow!("Interrupt handler setting error: {}", error))
+        .and_then(|()| main_inner(arguments, cancellation_token, &mut std::io::stderr()));
+
+    std::process::exit(match result {
         Ok(()) => compiler_common::EXIT_CODE_SUCCESS,
         Err(error) => {
-            eprintln!("{}", error);
-            compiler_common::EXIT_CODE_FAILURE
+            print_error(
+                &mut std::io::stderr(),
+                &compiler_solidity::SolcStandardJsonOutputError::error_compilation(
+                    error.to_string(),
+                ),
+                json_errors,
+            )
+            .expect("Stderr writing error");
+            compiler_solidity::Failure::of(&error)
+                .map(|failure| failure.exit_code())
+                .unwrap_or(compiler_common::EXIT_CODE_FAILURE)
         }
     })
 }
 
+///
+/// Prints the error or warning to `stderr`, either as formatted text or as a `solc` standard
+/// JSON error object if `json_errors` is set.
+///
+fn print_error(
+    stderr: &mut dyn Write,
+    error: &compiler_solidity::SolcStandardJsonOutputError,
+    json_errors: bool,
+) -> std::io::Result<()> {
+    if json_errors {
+        writeln!(
+            stderr,
+            "{}",
+            serde_json::to_string(error).expect("Always valid")
+        )
+    } else {
+        writeln!(stderr, "{}", error)
+    }
+}
+
+///
+/// Prints the informational message to `stderr`, unless `quiet` is set.
+///
+/// The errors and warnings are printed with `print_error`, which ignores `quiet`.
+///
+fn print_info(stderr: &mut dyn Write, message: &str, quiet: bool) -> std::io::Result<()> {
+    if !quiet {
+        writeln!(stderr, "{}", message)?;
+    }
+    Ok(())
+}
+
 ///
 /// The auxiliary `main` function to facilitate the `?` error conversion operator.
 ///
-fn main_inner() -> anyhow::Result<()> {
-    let mut arguments = Arguments::new();
+/// The `cancellation_token` is set by the interrupt handler, which is installed by `main`, as
+/// it can only be installed once per process. The errors, warnings, and informational messages
+/// are printed to `stderr`.
+///
+fn main_inner(
+    mut arguments: Arguments,
+    cancellation_token: Arc<AtomicBool>,
+    stderr: &mut dyn Write,
+) -> anyhow::Result<()> {
     arguments.validate()?;
 
     let dump_flags = compiler_solidity::DumpFlag::from_booleans(
         arguments.dump_yul,
-        arguments.dump_ethir,
+        arguments.dump_ethir || arguments.dump_ethir_deploy,
+        arguments.dump_ethir || arguments.dump_ethir_runtime,
         arguments.dump_evm,
         arguments.dump_llvm,
         arguments.dump_assembly,
@@ -42,11 +105,93 @@ fn main_inner() -> anyhow::Result<()> {
         *path = path.canonicalize()?;
     }
 
+    let zksolc_version = semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid");
+
+    compiler_llvm_context::initialize_target();
+    let mut llvm_options = match arguments.llvm_options.as_deref() {
+        Some(llvm_options) => shell_words::split(llvm_options)
+            .map_err(|error| anyhow::anyhow!("LLVM options parsing error: {}", error))?,
+        None => vec![],
+    };
+    if arguments.time_passes {
+        if llvm_options.is_empty() {
+            llvm_options.push("zksolc".to_owned());
+        }
+        llvm_options.push("-time-passes".to_owned());
+    }
+    if !llvm_options.is_empty() {
+        let llvm_options = Vec::from_iter(llvm_options.iter().map(String::as_str));
+        inkwell::support::parse_command_line_options(
+            llvm_options.len() as i32,
+            llvm_options.as_slice(),
+            "",
+        );
+    }
+
+    if arguments.no_solc {
+        let mut solc_output: compiler_solidity::SolcStandardJsonOutput =
+            serde_json::from_reader(std::io::BufReader::new(std::io::stdin()))?;
+        if let Some(errors) = solc_output.errors.as_deref() {
+            if errors
+                .iter()
+                .any(|error| error.severity.as_str() == "error")
+            {
+                anyhow::bail!("Error(s) found in the solc output. Compilation aborted");
+            }
+        }
+
+        let solc_version = match solc_output.version.as_deref() {
+            Some(version) => semver::Version::parse(version)?,
+            None => compiler_solidity::SolcCompiler::LAST_SUPPORTED_VERSION,
+        };
+        let solc_version = compiler_solidity::SolcVersion::new(
+            solc_output
+                .long_version
+                .clone()
+                .unwrap_or_else(|| solc_version.to_string()),
+            solc_version,
+        );
+        let pipeline = solc_output.pipeline().ok_or_else(|| {
+            anyhow::anyhow!("The solc output contains neither Yul nor EVM legacy assembly")
+        })?;
+        let libraries = compiler_solidity::SolcStandardJsonInputSettings::parse_libraries(
+            arguments.libraries.clone(),
+        )?;
+
+        let mut project = solc_output
+            .try_to_project(
+                libraries,
+                pipeline,
+                &solc_version.default,
+                dump_flags.as_slice(),
+            )
+            .map_err(|error| compiler_solidity::Failure::Parsing.wrap(error))?;
+        if !arguments.output_ast {
+            solc_output.sources = None;
+        }
+        configure_project(&mut project, &arguments, cancellation_token.clone())?;
+        let optimizer_settings =
+            compiler_solidity::SolcStandardJsonInputSettingsOptimizer::new_with_runs(
+                arguments.optimize,
+                arguments.optimize_runs,
+            )
+            .settings();
+        let target_machine = compiler_llvm_context::TargetMachine::new(&optimizer_settings)
+            .map_err(|error| compiler_solidity::Failure::LLVM.wrap(error))?;
+        let build = project.compile_all(target_machine, optimizer_settings, dump_flags)?;
+        report_build(&build, &arguments, Some(&mut solc_output))?;
+        build.write_to_standard_json(&mut solc_output, &solc_version, &zksolc_version)?;
+        serde_json::to_writer(std::io::stdout(), &solc_output)?;
+        return Ok(());
+    }
+
     let solc =
-        compiler_solidity::SolcCompiler::new(arguments.solc.unwrap_or_else(|| {
+        compiler_solidity::SolcCompiler::new(arguments.solc.clone().unwrap_or_else(|| {
             compiler_solidity::SolcCompiler::DEFAULT_EXECUTABLE_NAME.to_owned()
         }));
-    let solc_version = solc.version()?;
+    let solc_version = solc
+        .version()
+        .map_err(|error| compiler_solidity::Failure::Solc.wrap(error))?;
     if solc_version.default > compiler_solidity::SolcCompiler::LAST_SUPPORTED_VERSION {
         anyhow::bail!(
             "solc versions >{} are not supported yet, found {}",
@@ -55,8 +200,6 @@ fn main_inner() -> anyhow::Result<()> {
         );
     }
 
-    let zksolc_version = semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid");
-
     let pipeline = if solc_version.default < compiler_solidity::SolcCompiler::FIRST_YUL_VERSION
         || arguments.force_evmla
     {
@@ -65,18 +208,6 @@ fn main_inner() -> anyhow::Result<()> {
         compiler_solidity::SolcPipeline::Yul
     };
 
-    compiler_llvm_context::initialize_target();
-    if let Some(llvm_options) = arguments.llvm_options {
-        let llvm_options = shell_words::split(llvm_options.as_str())
-            .map_err(|error| anyhow::anyhow!("LLVM options parsing error: {}", error))?;
-        let llvm_options = Vec::from_iter(llvm_options.iter().map(String::as_str));
-        inkwell::support::parse_command_line_options(
-            llvm_options.len() as i32,
-            llvm_options.as_slice(),
-            "",
-        );
-    }
-
     let build = if arguments.yul {
         let path = match arguments.input_files.len() {
             1 => arguments.input_files.remove(0),
@@ -87,15 +218,50 @@ fn main_inner() -> anyhow::Result<()> {
             ),
         };
 
-        let project =
-            compiler_solidity::Project::try_from_default_yul(&path, &solc_version.default)?;
-        let optimizer_settings = if arguments.optimize {
-            compiler_llvm_context::OptimizerSettings::cycles()
-        } else {
-            compiler_llvm_context::OptimizerSettings::none()
-        };
-        let target_machine = compiler_llvm_context::TargetMachine::new(&optimizer_settings)?;
-        project.compile_all(target_machine, optimizer_settings, dump_flags)
+        if arguments.list_objects {
+            for identifier in compiler_solidity::Project::yul_object_identifiers(&path)
+                .map_err(|error| compiler_solidity::Failure::Parsing.wrap(error))?
+            {
+                println!("{}", identifier);
+            }
+            return Ok(());
+        }
+
+        if arguments.ast_compact_json {
+            let ast = compiler_solidity::Project::yul_compact_json(&path)
+                .map_err(|error| compiler_solidity::Failure::Parsing.wrap(error))?;
+            println!("{}", ast);
+            return Ok(());
+        }
+
+        let mut project = compiler_solidity::Project::try_from_default_yul(
+            &path,
+            &solc_version.default,
+            arguments.max_identifier_length,
+        )
+        .map_err(|error| compiler_solidity::Failure::Parsing.wrap(error))?;
+        for warning in project.warnings.iter() {
+            print_error(stderr, warning, arguments.json_errors)?;
+        }
+        configure_project(&mut project, &arguments, cancellation_token.clone())?;
+        let optimizer_settings =
+            compiler_solidity::SolcStandardJsonInputSettingsOptimizer::new_with_runs(
+                arguments.optimize,
+                arguments.optimize_runs,
+            )
+            .settings();
+        project.halt_on_first_error = arguments.halt_on_first_error;
+        let target_machine = compiler_llvm_context::TargetMachine::new(&optimizer_settings)
+            .map_err(|error| compiler_solidity::Failure::LLVM.wrap(error))?;
+        let build = project.compile_all(target_machine, optimizer_settings, dump_flags)?;
+        for (path, error) in build.errors.iter() {
+            let mut error = compiler_solidity::SolcStandardJsonOutputError::error_compilation(
+                error.to_string(),
+            );
+            error.push_contract_path(path.as_str());
+            print_error(stderr, &error, arguments.json_errors)?;
+        }
+        Ok(build)
     } else {
         let output_selection =
             compiler_solidity::SolcStandardJsonInputSettings::get_output_selection(
@@ -106,33 +272,54 @@ fn main_inner() -> anyhow::Result<()> {
                     .collect(),
                 pipeline,
             );
-        let solc_input = if arguments.standard_json {
+        let mut solc_input = if arguments.standard_json {
             let mut input: compiler_solidity::SolcStandardJsonInput =
                 serde_json::from_reader(std::io::BufReader::new(std::io::stdin()))?;
-            input.settings.output_selection = output_selection;
+            input.settings.extend_output_selection(output_selection);
             input
         } else {
-            compiler_solidity::SolcStandardJsonInput::try_from_paths(
+            let mut input = compiler_solidity::SolcStandardJsonInput::try_from_paths(
                 compiler_solidity::SolcStandardJsonInputLanguage::Solidity,
                 arguments.input_files.as_slice(),
-                arguments.libraries,
+                arguments.libraries.clone(),
                 output_selection,
                 true,
-            )?
+            )?;
+            input.settings.optimizer.runs = arguments.optimize_runs;
+            input
         };
 
         let libraries = solc_input.settings.libraries.clone().unwrap_or_default();
-        let optimize = if arguments.standard_json {
-            solc_input.settings.optimizer.enabled
+        let optimizer_overrides = solc_input
+            .settings
+            .optimizer_overrides
+            .take()
+            .unwrap_or_default();
+        let optimizer_settings = if arguments.standard_json {
+            solc_input.settings.optimizer.settings()
         } else {
-            arguments.optimize
+            compiler_solidity::SolcStandardJsonInputSettingsOptimizer::new_with_runs(
+                arguments.optimize,
+                arguments.optimize_runs,
+            )
+            .settings()
         };
-        let mut solc_output = solc.standard_json(
-            solc_input,
-            arguments.base_path,
-            arguments.include_paths,
-            arguments.allow_paths,
-        )?;
+        let mut solc_output = solc
+            .standard_json(
+                solc_input,
+                arguments.base_path.clone(),
+                arguments.include_paths.clone(),
+                arguments.allow_paths.clone(),
+            )
+            .map_err(|error| compiler_solidity::Failure::Solc.wrap(error))?;
+        solc_output.remap_errors(arguments.remap_solc_errors.as_slice());
+        if arguments.disable_warnings_for_dependencies {
+            let project_root = match arguments.base_path.as_deref() {
+                Some(base_path) => std::path::PathBuf::from(base_path),
+                None => std::env::current_dir()?,
+            };
+            solc_output.remove_dependency_warnings(project_root.as_path());
+        }
 
         if let Some(errors) = solc_output.errors.as_deref() {
             let mut cannot_compile = false;
@@ -146,7 +333,7 @@ fn main_inner() -> anyhow::Result<()> {
                 }
 
                 if !arguments.standard_json && arguments.combined_json.is_none() {
-                    eprintln!("{}", error);
+                    print_error(stderr, error, arguments.json_errors)?;
                 }
             }
 
@@ -155,53 +342,114 @@ fn main_inner() -> anyhow::Result<()> {
             }
         }
 
-        let project = solc_output.try_to_project(
-            libraries,
-            pipeline,
-            &solc_version.default,
-            dump_flags.as_slice(),
-        )?;
-        let optimizer_settings = if optimize {
-            compiler_llvm_context::OptimizerSettings::cycles()
-        } else {
-            compiler_llvm_context::OptimizerSettings::none()
-        };
-        let target_machine = compiler_llvm_context::TargetMachine::new(&optimizer_settings)?;
+        let mut project = solc_output
+            .try_to_project(
+                libraries,
+                pipeline,
+                &solc_version.default,
+                dump_flags.as_slice(),
+            )
+            .map_err(|error| compiler_solidity::Failure::Parsing.wrap(error))?;
+        if !arguments.output_ast {
+            solc_output.sources = None;
+        }
+        configure_project(&mut project, &arguments, cancellation_token.clone())?;
+        project.optimizer_overrides = optimizer_overrides;
+        let target_machine = compiler_llvm_context::TargetMachine::new(&optimizer_settings)
+            .map_err(|error| compiler_solidity::Failure::LLVM.wrap(error))?;
         let build = project.compile_all(target_machine, optimizer_settings, dump_flags)?;
         if arguments.standard_json {
+            report_build(&build, &arguments, Some(&mut solc_output))?;
             build.write_to_standard_json(&mut solc_output, &solc_version, &zksolc_version)?;
             serde_json::to_writer(std::io::stdout(), &solc_output)?;
             return Ok(());
         }
         Ok(build)
     }?;
+    let failed_contracts = build.errors.len();
+    let failure = build.errors.values().next().map(|error| {
+        compiler_solidity::Failure::of(error).unwrap_or(compiler_solidity::Failure::LLVM)
+    });
+    for warning in build.warnings.iter() {
+        print_error(stderr, warning, arguments.json_errors)?;
+    }
+    report_build(&build, &arguments, None)?;
 
-    let combined_json = if let Some(combined_json) = arguments.combined_json {
-        Some(solc.combined_json(arguments.input_files.as_slice(), combined_json.as_str())?)
-    } else {
-        None
+    if let Some(verify) = arguments.verify.as_deref() {
+        let (contract_path, bytecode_path) = verify.split_once('=').ok_or_else(|| {
+            anyhow::anyhow!(
+                "The verified contract must be specified as `<contractPath>=<bytecodeFile>`"
+            )
+        })?;
+        let expected = std::fs::read(bytecode_path).map_err(|error| {
+            compiler_solidity::Failure::IO.wrap(anyhow::anyhow!(
+                "Bytecode file {:?} reading error: {}",
+                bytecode_path,
+                error
+            ))
+        })?;
+        let decoded = std::str::from_utf8(expected.as_slice())
+            .ok()
+            .map(str::trim)
+            .and_then(|hexadecimal| {
+                hex::decode(hexadecimal.strip_prefix("0x").unwrap_or(hexadecimal)).ok()
+            });
+        let expected = decoded.unwrap_or(expected);
+
+        let contract = build.find_contract(contract_path)?;
+        contract.verify(expected.as_slice())?;
+
+        print_info(
+            stderr,
+            format!(
+                "Contract `{}` bytecode verified successfully.",
+                contract.path
+            )
+            .as_str(),
+            arguments.quiet,
+        )?;
+    }
+
+    let combined_json = match arguments.combined_json_selectors() {
+        Some(selectors) if !arguments.check && arguments.verify.is_none() => Some(
+            solc.combined_json(arguments.input_files.as_slice(), selectors.as_str())
+                .map_err(|error| compiler_solidity::Failure::Solc.wrap(error))?,
+        ),
+        _ => None,
     };
 
-    if let Some(output_directory) = arguments.output_directory {
+    if arguments.check || arguments.verify.is_some() {
+        // The check and verify modes only report the errors, which are handled below.
+    } else if let Some(output_directory) = arguments.output_directory {
         std::fs::create_dir_all(&output_directory)?;
 
         if let Some(mut combined_json) = combined_json {
             build.write_to_combined_json(&mut combined_json, &solc_version, &zksolc_version)?;
-            combined_json.write_to_directory(&output_directory, arguments.overwrite)?;
+            combined_json
+                .write_to_directory(&output_directory, arguments.overwrite)
+                .map_err(|error| compiler_solidity::Failure::IO.wrap(error))?;
         } else {
-            build.write_to_directory(
-                &output_directory,
-                arguments.output_assembly,
-                arguments.output_binary,
-                arguments.output_abi,
-                arguments.overwrite,
-            )?;
+            build
+                .write_to_directory(
+                    &output_directory,
+                    arguments.output_assembly,
+                    arguments.output_binary,
+                    arguments.output_abi,
+                    arguments.overwrite,
+                    arguments.mirror_source_paths,
+                )
+                .map_err(|error| compiler_solidity::Failure::IO.wrap(error))?;
         }
 
-        eprintln!(
-            "Compiler run successful. Artifact(s) can be found in directory {:?}.",
-            output_directory
-        );
+        print_info(
+            stderr,
+            format!(
+                "Compiler run successful. Artifact(s) can be found in directory {:?}.",
+                output_directory
+            )
+            .as_str(),
+            arguments.quiet,
+        )?;
     } else if let Some(mut combined_json) = combined_json {
         build.write_to_combined_json(&mut combined_json, &solc_version, &zksolc_version)?;
         println!(
@@ -210,6 +458,8 @@ fn main_inner() -> anyhow::Result<()> {
         );
     } else if arguments.output_assembly
         || arguments.output_binary
+        || arguments.output_deploy_binary
+        || arguments.output_runtime_binary
         || arguments.output_hashes
         || arguments.output_abi
     {
@@ -220,26 +470,535 @@ fn main_inner() -> anyhow::Result<()> {
                     path, contract.build.assembly_text
                 );
             }
-            if arguments.output_binary {
-                println!(
-                    "Contract `{}` bytecode: 0x{}",
-                    path,
-                    hex::encode(contract.build.bytecode)
-                );
-            }
+            print!(
+                "{}",
+                contract.binary_output(
+                    arguments.output_binary,
+                    arguments.output_deploy_binary,
+                    arguments.output_runtime_binary,
+                )
+            );
         }
 
         if arguments.output_abi || arguments.output_hashes {
-            let extra_output = solc.extra_output(
-                arguments.input_files.as_slice(),
-                arguments.output_abi,
-                arguments.output_hashes,
-            )?;
+            let extra_output = solc
+                .extra_output(
+                    arguments.input_files.as_slice(),
+                    arguments.output_abi,
+                    arguments.output_hashes,
+                )
+                .map_err(|error| compiler_solidity::Failure::Solc.wrap(error))?;
             print!("{}", extra_output);
         }
     } else {
-        eprintln!("Compiler run successful. No output requested. Use --asm and --bin flags.");
+        print_info(
+            stderr,
+            "Compiler run successful. No output requested. Use --asm and --bin flags.",
+            arguments.quiet,
+        )?;
+    }
+
+    if let Some(failure) = failure {
+        return Err(failure.wrap(anyhow::anyhow!(
+            "{} contract(s) failed to compile",
+            failed_contracts
+        )));
     }
 
     Ok(())
 }
+
+///
+/// Applies the project options shared by the no-solc, Yul, and Solidity modes.
+///
+fn configure_project(
+    project: &mut compiler_solidity::Project,
+    arguments: &Arguments,
+    cancellation_token: Arc<AtomicBool>,
+) -> anyhow::Result<()> {
+    project.allow_unknown_verbatim = arguments.allow_unknown_verbatim;
+    if let Some(contract) = arguments.contract.as_deref() {
+        project.select_contract(contract)?;
+    }
+    if let Some(data_layout) = arguments.data_layout.as_deref() {
+        project.set_data_layout(data_layout)?;
+    }
+    project.max_factory_dependencies = arguments.max_factory_dependencies;
+    project.allow_unlinked_libraries = arguments.allow_unlinked_libraries;
+    project.cancellation_token = Some(cancellation_token);
+    project.annotate_assembly = arguments.annotate_assembly;
+    project.preserve_unused_functions = arguments.preserve_unused_functions;
+    project.emit_llvm_bc = arguments.emit_llvm_bc;
+    project.emit_coverage = arguments.coverage_output.is_some();
+    project.emit_ir_stats = arguments.print_ir_stats;
+    project.emit_source_map = arguments.emit_source_map;
+    Ok(())
+}
+
+///
+/// Checks the bytecode size limit and prints the requested build reports.
+///
+/// In the standard JSON mode, the size limit violations are reported via the output `errors`.
+///
+fn report_build(
+    build: &compiler_solidity::Build,
+    arguments: &Arguments,
+    solc_output: Option<&mut compiler_solidity::SolcStandardJsonOutput>,
+) -> anyhow::Result<()> {
+    if let Some(limit) = arguments.max_bytecode_size {
+        if let Err(error) = build.check_bytecode_size(limit) {
+            match solc_output {
+                Some(solc_output) => solc_output.errors.get_or_insert_with(Vec::new).push(
+                    compiler_solidity::SolcStandardJsonOutputError::error_compilation(
+                        error.to_string(),
+                    ),
+                ),
+                None => return Err(error),
+            }
+        }
+    }
+    if arguments.time_passes {
+        eprint!("{}", build.pass_timings_report());
+    }
+    if arguments.print_ir_stats {
+        eprintln!("{}", build.ir_stats_json());
+    }
+    if let Some(path) = arguments.coverage_output.as_ref() {
+        build
+            .write_coverage(path)
+            .map_err(|error| compiler_solidity::Failure::IO.wrap(error))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::path::PathBuf;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+
+    use structopt::StructOpt;
+
+    use crate::arguments::Arguments;
+
+    const SOLC_VERSION: &str = "#!/bin/sh\necho 'solc, the solidity compiler commandline interface'\necho 'Version: 0.8.17+commit.8df45f5f.Linux.g++'\n";
+
+    const YUL_VALID: &str = r#"
+object "Valid" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Valid_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+"#;
+
+    const YUL_UNDECLARED_FUNCTION: &str = r#"
+object "Broken" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Broken_deployed" {
+        code {
+            {
+                undeclared_function()
+                return(0, 0)
+            }
+        }
+    }
+}
+"#;
+
+    #[cfg(unix)]
+    fn write_solc(directory: &Path, script: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let solc = directory.join("solc");
+        std::fs::write(&solc, script).expect("Always valid");
+        std::fs::set_permissions(&solc, std::fs::Permissions::from_mode(0o755))
+            .expect("Always valid");
+        solc
+    }
+
+    fn run_with_stderr(solc: &Path, arguments: &[&str]) -> (anyhow::Result<()>, String) {
+        let arguments = Arguments::from_iter(
+            ["zksolc", "--solc", solc.to_string_lossy().as_ref()]
+                .into_iter()
+                .chain(arguments.iter().copied()),
+        );
+        let mut stderr = Vec::new();
+        let result = super::main_inner(arguments, Arc::new(AtomicBool::new(false)), &mut stderr);
+        (result, String::from_utf8(stderr).expect("Always valid"))
+    }
+
+    fn run(solc: &Path, arguments: &[&str]) -> anyhow::Result<()> {
+        let arguments: Vec<&str> = std::iter::once("--quiet")
+            .chain(arguments.iter().copied())
+            .collect();
+        run_with_stderr(solc, arguments.as_slice()).0
+    }
+
+    ///
+    /// Writes the Yul file with a valid object and an object failing to compile with
+    /// `Failure::LLVM`, returning the canonical paths of the file and the failing object.
+    ///
+    fn write_partially_broken(directory: &Path) -> (PathBuf, String) {
+        let path = directory.join("partially_broken.yul");
+        std::fs::write(
+            &path,
+            format!(
+                "{}{}",
+                YUL_VALID,
+                YUL_VALID.replace("Valid", "Broken").replace(
+                    "            return(0, 0)\n        }\n    }\n    object",
+                    "            mstore(0, dataoffset(\"Unknown\"))\n            return(0, 0)\n        }\n    }\n    object",
+                )
+            ),
+        )
+        .expect("Always valid");
+        let path = path.canonicalize().expect("Always valid");
+        let broken = format!("{}:Broken", path.to_string_lossy());
+        (path, broken)
+    }
+
+    fn exit_code(result: anyhow::Result<()>) -> Option<i32> {
+        result
+            .err()
+            .as_ref()
+            .and_then(compiler_solidity::Failure::of)
+            .map(|failure| failure.exit_code())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn check() {
+        let directory = std::env::temp_dir().join(format!("zksolc-check-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("Always valid");
+        let solc = write_solc(&directory, SOLC_VERSION);
+
+        let valid = directory.join("valid.yul");
+        std::fs::write(&valid, YUL_VALID).expect("Always valid");
+        let broken = directory.join("broken.yul");
+        std::fs::write(&broken, YUL_UNDECLARED_FUNCTION).expect("Always valid");
+
+        for (path, is_valid) in [(&valid, true), (&broken, false)] {
+            let result = run(
+                &solc,
+                &["--yul", "--check", path.to_string_lossy().as_ref()],
+            );
+            assert_eq!(result.is_ok(), is_valid);
+        }
+
+        let mut files: Vec<String> = std::fs::read_dir(&directory)
+            .expect("Always valid")
+            .map(|entry| {
+                entry
+                    .expect("Always valid")
+                    .file_name()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        files.sort();
+        assert_eq!(files, vec!["broken.yul", "solc", "valid.yul"]);
+
+        std::fs::remove_dir_all(&directory).expect("Always valid");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn exit_codes() {
+        let directory =
+            std::env::temp_dir().join(format!("zksolc-exit-codes-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("Always valid");
+        let solc = write_solc(&directory, SOLC_VERSION);
+
+        let syntax_error = directory.join("syntax_error.yul");
+        std::fs::write(&syntax_error, "object \"Broken\" { code { return(0, }")
+            .expect("Always valid");
+        let undeclared_function = directory.join("undeclared_function.yul");
+        std::fs::write(&undeclared_function, YUL_UNDECLARED_FUNCTION).expect("Always valid");
+        let unknown_object = directory.join("unknown_object.yul");
+        std::fs::write(
+            &unknown_object,
+            YUL_VALID.replace(
+                "            return(0, 0)\n        }\n    }\n    object",
+                "            mstore(0, dataoffset(\"Unknown\"))\n            return(0, 0)\n        }\n    }\n    object",
+            ),
+        )
+        .expect("Always valid");
+        let missing = directory.join("missing.yul");
+
+        for (path, exit_code) in [
+            (&syntax_error, compiler_solidity::Failure::EXIT_CODE_PARSING),
+            (
+                &undeclared_function,
+                compiler_solidity::Failure::EXIT_CODE_PARSING,
+            ),
+            (&unknown_object, compiler_solidity::Failure::EXIT_CODE_LLVM),
+            (&missing, compiler_solidity::Failure::EXIT_CODE_IO),
+        ] {
+            let result = run(&solc, &["--yul", path.to_string_lossy().as_ref()]);
+            assert_eq!(exit_code(result), Some(exit_code), "{:?}", path);
+        }
+
+        let source = directory.join("test.sol");
+        std::fs::write(&source, "contract Test {}").expect("Always valid");
+        let solc = write_solc(
+            &directory,
+            format!("{}[ \"$1\" = \"--version\" ] || exit 1\n", SOLC_VERSION).as_str(),
+        );
+        let result = run(&solc, &[source.to_string_lossy().as_ref()]);
+        assert_eq!(
+            exit_code(result),
+            Some(compiler_solidity::Failure::EXIT_CODE_SOLC)
+        );
+
+        std::fs::remove_dir_all(&directory).expect("Always valid");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn verify_with_failed_contracts() {
+        let directory = std::env::temp_dir().join(format!("zksolc-verify-{}", std::process::id()));
+        let output_directory = directory.join("output");
+        std::fs::create_dir_all(&directory).expect("Always valid");
+        let solc = write_solc(&directory, SOLC_VERSION);
+
+        let valid = directory.join("valid.yul");
+        std::fs::write(&valid, YUL_VALID).expect("Always valid");
+        let valid = valid.to_string_lossy().to_string();
+        let (path, _broken) = write_partially_broken(&directory);
+        let path = path.to_string_lossy().to_string();
+
+        run(
+            &solc,
+            &[
+                "--yul",
+                "--bin",
+                "--output-dir",
+                output_directory.to_string_lossy().as_ref(),
+                valid.as_str(),
+            ],
+        )
+        .expect("Always valid");
+        let bytecode = std::fs::read_dir(&output_directory)
+            .expect("Always valid")
+            .next()
+            .expect("Always exists")
+            .expect("Always valid")
+            .path();
+        let bytecode = bytecode.to_string_lossy();
+
+        let result = run(
+            &solc,
+            &[
+                "--yul",
+                "--verify",
+                format!("valid.yul={}", bytecode).as_str(),
+                valid.as_str(),
+            ],
+        );
+        assert!(result.is_ok());
+
+        // The verified contract matches, but the other one in the same file fails to compile.
+        let result = run(
+            &solc,
+            &[
+                "--yul",
+                "--verify",
+                format!("partially_broken.yul:Valid={}", bytecode).as_str(),
+                path.as_str(),
+            ],
+        );
+        assert_eq!(
+            exit_code(result),
+            Some(compiler_solidity::Failure::EXIT_CODE_LLVM)
+        );
+
+        std::fs::remove_dir_all(&directory).expect("Always valid");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn quiet() {
+        let directory = std::env::temp_dir().join(format!("zksolc-quiet-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("Always valid");
+        let solc = write_solc(&directory, SOLC_VERSION);
+
+        let valid = directory.join("valid.yul");
+        std::fs::write(&valid, YUL_VALID).expect("Always valid");
+        let valid = valid.to_string_lossy().to_string();
+        let (path, broken) = write_partially_broken(&directory);
+        let path = path.to_string_lossy().to_string();
+
+        let (result, stderr) = run_with_stderr(&solc, &["--yul", valid.as_str()]);
+        assert!(result.is_ok());
+        assert_eq!(
+            stderr,
+            "Compiler run successful. No output requested. Use --asm and --bin flags.\n"
+        );
+
+        let (result, stderr) = run_with_stderr(&solc, &["--yul", "--quiet", valid.as_str()]);
+        assert!(result.is_ok());
+        assert_eq!(stderr, "");
+
+        // The errors are printed regardless of `--quiet`, unlike the success message.
+        let (result, stderr) = run_with_stderr(&solc, &["--yul", "--quiet", path.as_str()]);
+        assert!(result.is_err());
+        assert!(!stderr.contains("Compiler run successful"));
+        assert!(stderr.ends_with(format!("\n--> {}\n\n", broken).as_str()));
+
+        std::fs::remove_dir_all(&directory).expect("Always valid");
+    }
+
+    #[test]
+    fn print_error_json() {
+        let mut error = compiler_solidity::SolcStandardJsonOutputError::error_compilation(
+            "Something went wrong".to_owned(),
+        );
+        error.push_contract_path("test.sol:Test");
+
+        let mut stderr = Vec::new();
+        super::print_error(&mut stderr, &error, true).expect("Always valid");
+        let stderr = String::from_utf8(stderr).expect("Always valid");
+        assert_eq!(stderr.lines().count(), 1);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(stderr.as_str()).expect("Always valid"),
+            serde_json::json!({
+                "component": "general",
+                "errorCode": null,
+                "formattedMessage": "Something went wrong\n--> test.sol:Test\n",
+                "message": "Something went wrong",
+                "severity": "error",
+                "sourceLocation": null,
+                "type": "CompilerError",
+            })
+        );
+
+        let mut stderr = Vec::new();
+        super::print_error(&mut stderr, &error, false).expect("Always valid");
+        assert_eq!(
+            String::from_utf8(stderr).expect("Always valid"),
+            "Something went wrong\n--> test.sol:Test\n\n"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn json_errors() {
+        let directory =
+            std::env::temp_dir().join(format!("zksolc-json-errors-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("Always valid");
+        let solc = write_solc(&directory, SOLC_VERSION);
+        let (path, broken) = write_partially_broken(&directory);
+
+        let (result, stderr) = run_with_stderr(
+            &solc,
+            &[
+                "--yul",
+                "--quiet",
+                "--json-errors",
+                path.to_string_lossy().as_ref(),
+            ],
+        );
+        assert!(result.is_err());
+        let errors: Vec<serde_json::Value> = stderr
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("Always valid"))
+            .collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0]["severity"], "error");
+        assert_eq!(errors[0]["type"], "CompilerError");
+        assert!(errors[0]["formattedMessage"]
+            .as_str()
+            .expect("Always exists")
+            .ends_with(format!("\n--> {}\n", broken).as_str()));
+
+        std::fs::remove_dir_all(&directory).expect("Always valid");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn combined_json_with_abi_and_hashes() {
+        let directory =
+            std::env::temp_dir().join(format!("zksolc-combined-json-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).expect("Always valid");
+        let directory = directory.canonicalize().expect("Always valid");
+        let source = directory.join("test.sol");
+        std::fs::write(&source, "contract Test { function f() public {} }").expect("Always valid");
+        let source = source.to_string_lossy().to_string();
+        let log = directory.join("solc.log");
+
+        let abi = r#"[{"inputs":[],"name":"f","outputs":[],"stateMutability":"nonpayable","type":"function"}]"#;
+        let standard_json = r#"{"contracts":{"PATH":{"Test":{"abi":ABI,"irOptimized":"object \"Test_1\" { code { { return(0, 0) } } object \"Test_1_deployed\" { code { { return(0, 0) } } } }"}}}}"#
+            .replace("PATH", source.as_str())
+            .replace("ABI", abi);
+        let combined_json = r#"{"contracts":{"PATH:Test":{"abi":ABI,"hashes":{"f()":"26121ff0"}}},"version":"0.8.17+commit.8df45f5f.Linux.g++"}"#
+            .replace("PATH", source.as_str())
+            .replace("ABI", abi);
+        let solc = write_solc(
+            &directory,
+            format!(
+                "#!/bin/sh\necho \"$@\" >> '{}'\ncase \"$1\" in\n--version)\n{}    ;;\n--standard-json)\n    cat > /dev/null\n    cat <<'EOF'\n{}\nEOF\n    ;;\n*)\n    cat <<'EOF'\n{}\nEOF\n    ;;\nesac\n",
+                log.to_string_lossy(),
+                SOLC_VERSION.trim_start_matches("#!/bin/sh\n"),
+                standard_json,
+                combined_json
+            )
+            .as_str(),
+        );
+        let output_directory = directory.join("output");
+        let output_directory = output_directory.to_string_lossy().to_string();
+
+        for output in [vec!["--output-dir", output_directory.as_str()], vec![]] {
+            let arguments: Vec<&str> = ["--combined-json", "abi", "--abi", "--hashes"]
+                .into_iter()
+                .chain(output)
+                .chain(std::iter::once(source.as_str()))
+                .collect();
+            run(&solc, arguments.as_slice()).expect("Always valid");
+
+            // The metadata is queried once with the merged selectors, without `--abi` and
+            // `--hashes` being passed to `solc` separately.
+            let invocations = std::fs::read_to_string(&log).expect("Always valid");
+            let metadata_invocations: Vec<&str> = invocations
+                .lines()
+                .filter(|line| {
+                    !line.starts_with("--version") && !line.starts_with("--standard-json")
+                })
+                .collect();
+            assert_eq!(
+                metadata_invocations,
+                vec![format!("{} --combined-json abi,hashes", source).as_str()]
+            );
+            std::fs::remove_file(&log).expect("Always valid");
+        }
+
+        let combined_json: serde_json::Value = serde_json::from_slice(
+            std::fs::read(std::path::Path::new(output_directory.as_str()).join("combined.json"))
+                .expect("Always valid")
+                .as_slice(),
+        )
+        .expect("Always valid");
+        let contract = &combined_json["contracts"][format!("{}:Test", source).as_str()];
+        assert_eq!(
+            contract["abi"],
+            serde_json::from_str::<serde_json::Value>(abi).expect("Always valid")
+        );
+        assert_eq!(contract["hashes"], serde_json::json!({ "f()": "26121ff0" }));
+        assert!(!contract["bin"].as_str().expect("Always exists").is_empty());
+
+        std::fs::remove_dir_all(&directory).expect("Always valid");
+    }
+}
@@ -13,6 +13,12 @@ pub struct Arguments {
     /// The LLVM framework tag.
     #[structopt(long = "tag")]
     pub tag: Option<String>,
+
+    /// Build LLVM with the assertions enabled, which makes the compiler linked against it
+    /// abort on the invalid IR instead of miscompiling it. Intended for debugging only, as
+    /// the assertions slow the compilation down.
+    #[structopt(long = "enable-assertions")]
+    pub enable_assertions: bool,
 }
 
 impl Arguments {
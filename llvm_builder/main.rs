@@ -63,16 +63,16 @@ fn main_wrapper() -> anyhow::Result<()> {
     if cfg!(target_arch = "x86_64") {
         if cfg!(target_os = "linux") {
             if cfg!(target_env = "gnu") {
-                x86_64_linux_gnu::build()?;
+                x86_64_linux_gnu::build(arguments.enable_assertions)?;
             } else if cfg!(target_env = "musl") {
-                x86_64_linux_musl::build()?;
+                x86_64_linux_musl::build(arguments.enable_assertions)?;
             }
         } else if cfg!(target_os = "macos") {
-            x86_64_macos::build()?;
+            x86_64_macos::build(arguments.enable_assertions)?;
         }
     } else if cfg!(target_arch = "aarch64") {
         if cfg!(target_os = "macos") {
-            aarch64_macos::build()?;
+            aarch64_macos::build(arguments.enable_assertions)?;
         }
     } else {
         anyhow::bail!("Unsupported on your machine");
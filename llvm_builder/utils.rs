@@ -43,3 +43,14 @@ pub fn check_presence(name: &str) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+///
+/// Returns the `cmake` option enabling or disabling the LLVM assertions.
+///
+pub fn assertions_option(enable_assertions: bool) -> &'static str {
+    if enable_assertions {
+        "-DLLVM_ENABLE_ASSERTIONS='On'"
+    } else {
+        "-DLLVM_ENABLE_ASSERTIONS='Off'"
+    }
+}
@@ -7,7 +7,7 @@ use std::process::Command;
 ///
 /// The building sequence.
 ///
-pub fn build() -> anyhow::Result<()> {
+pub fn build(enable_assertions: bool) -> anyhow::Result<()> {
     crate::utils::check_presence("cmake")?;
     crate::utils::check_presence("clang")?;
     crate::utils::check_presence("clang++")?;
@@ -42,7 +42,7 @@ pub fn build() -> anyhow::Result<()> {
             "-DLLVM_BUILD_DOCS='Off'",
             "-DLLVM_INCLUDE_DOCS='Off'",
             "-DLLVM_INCLUDE_TESTS='Off'",
-            "-DLLVM_ENABLE_ASSERTIONS='Off'",
+            crate::utils::assertions_option(enable_assertions),
             "-DLLVM_ENABLE_TERMINFO='Off'",
             "-DLLVM_ENABLE_DOXYGEN='Off'",
             "-DLLVM_ENABLE_SPHINX='Off'",
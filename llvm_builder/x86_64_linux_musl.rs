@@ -8,7 +8,7 @@ use std::process::Command;
 ///
 /// The building sequence.
 ///
-pub fn build() -> anyhow::Result<()> {
+pub fn build(enable_assertions: bool) -> anyhow::Result<()> {
     crate::utils::check_presence("wget")?;
     crate::utils::check_presence("tar")?;
     crate::utils::check_presence("cmake")?;
@@ -50,6 +50,7 @@ pub fn build() -> anyhow::Result<()> {
         target_install_directory.as_path(),
         musl_install_directory.as_path(),
         host_install_directory.as_path(),
+        enable_assertions,
     )?;
 
     Ok(())
@@ -323,6 +324,7 @@ fn build_target(
     install_directory: &Path,
     musl_install_directory: &Path,
     host_install_directory: &Path,
+    enable_assertions: bool,
 ) -> anyhow::Result<()> {
     let mut clang_path = host_install_directory.to_path_buf();
     clang_path.push("bin/clang");
@@ -358,7 +360,7 @@ fn build_target(
             "-DLLVM_INCLUDE_DOCS='Off'",
             "-DLLVM_INCLUDE_TESTS='Off'",
             "-DLLVM_ENABLE_PROJECTS='llvm'",
-            "-DLLVM_ENABLE_ASSERTIONS='Off'",
+            crate::utils::assertions_option(enable_assertions),
             "-DLLVM_ENABLE_DOXYGEN='Off'",
             "-DLLVM_ENABLE_SPHINX='Off'",
             "-DLLVM_ENABLE_OCAMLDOC='Off'",